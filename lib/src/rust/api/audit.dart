@@ -0,0 +1,128 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `append`, `finish`, `log_file`, `matches`, `new`, `record_sync`, `rotate`, `source`, `target`, `write_entry`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AuditRecord`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// 按条件查询审计日志，新记录在前
+Future<List<AuditEntry>> queryAuditLog({required AuditQuery query}) =>
+    RustLib.instance.api.crateApiAuditQueryAuditLog(query: query);
+
+/// 审计日志中的一条记录
+class AuditEntry {
+  /// 记录时间（Unix 秒）
+  final PlatformInt64 timestamp;
+  final AuditOperation operation;
+  /// 账号别名，为 `None` 时表示整个操作（例如远端无法连接）
+  final String? alias;
+  /// 数据来源：账号文件、游戏凭证路径、远端名称或归档文件
+  final String? source;
+  /// 被写入的位置，含义同 `source`
+  final String? target;
+  final bool success;
+  /// 失败原因
+  final String? error;
+  /// 执行操作的设备名
+  final String device;
+
+  const AuditEntry({
+    required this.timestamp,
+    required this.operation,
+    this.alias,
+    this.source,
+    this.target,
+    required this.success,
+    this.error,
+    required this.device,
+  });
+
+  @override
+  int get hashCode =>
+      timestamp.hashCode ^
+      operation.hashCode ^
+      alias.hashCode ^
+      source.hashCode ^
+      target.hashCode ^
+      success.hashCode ^
+      error.hashCode ^
+      device.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuditEntry &&
+          runtimeType == other.runtimeType &&
+          timestamp == other.timestamp &&
+          operation == other.operation &&
+          alias == other.alias &&
+          source == other.source &&
+          target == other.target &&
+          success == other.success &&
+          error == other.error &&
+          device == other.device;
+}
+
+/// 审计日志记录的操作
+enum AuditOperation {
+  save,
+  switch_,
+  delete,
+  push,
+  pull,
+  import_,
+  /// 恢复远端历史版本
+  restore,
+  /// 回滚中断的拉取
+  rollback,
+}
+
+/// 审计日志的查询条件，均为空时返回全部记录
+class AuditQuery {
+  final String? alias;
+  final AuditOperation? operation;
+  /// 时间范围（Unix 秒，含两端）
+  final PlatformInt64? since;
+  final PlatformInt64? until;
+  final bool failuresOnly;
+  /// 最多返回的记录数
+  final int? limit;
+
+  const AuditQuery({
+    this.alias,
+    this.operation,
+    this.since,
+    this.until,
+    required this.failuresOnly,
+    this.limit,
+  });
+
+  static Future<AuditQuery> default_() =>
+      RustLib.instance.api.crateApiAuditAuditQueryDefault();
+
+  @override
+  int get hashCode =>
+      alias.hashCode ^
+      operation.hashCode ^
+      since.hashCode ^
+      until.hashCode ^
+      failuresOnly.hashCode ^
+      limit.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AuditQuery &&
+          runtimeType == other.runtimeType &&
+          alias == other.alias &&
+          operation == other.operation &&
+          since == other.since &&
+          until == other.until &&
+          failuresOnly == other.failuresOnly &&
+          limit == other.limit;
+}
@@ -0,0 +1,82 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `backoff_secs`, `debounced_run_at`, `notify_local_change`, `record_result`, `run_sync_cycle`, `schedule_at`, `scheduler_loop`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Scheduler`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 按设置启动自动同步调度器，已在运行时不做任何事；设置中未开启时返回 `false`
+Future<bool> startAutoSync() =>
+    RustLib.instance.api.crateApiAutosyncStartAutoSync();
+
+/// 停止自动同步调度器；正在进行的同步会继续完成，之后不再安排同步
+Future<void> stopAutoSync() =>
+    RustLib.instance.api.crateApiAutosyncStopAutoSync();
+
+/// 设置变更后重新加载调度器
+Future<bool> restartAutoSync() =>
+    RustLib.instance.api.crateApiAutosyncRestartAutoSync();
+
+/// 立即执行一次同步（调度器运行时）
+Future<void> triggerAutoSyncNow() =>
+    RustLib.instance.api.crateApiAutosyncTriggerAutoSyncNow();
+
+/// 获取自动同步状态
+Future<AutoSyncStatus> getAutoSyncStatus() =>
+    RustLib.instance.api.crateApiAutosyncGetAutoSyncStatus();
+
+/// 自动同步的运行状态
+class AutoSyncStatus {
+  /// 调度器是否在运行
+  final bool active;
+  /// 当前是否正在同步
+  final bool syncing;
+  final PlatformInt64? lastSuccessAt;
+  final String? lastError;
+  final PlatformInt64? lastErrorAt;
+  /// 下一次计划同步的时间（Unix 秒）
+  final PlatformInt64? nextRunAt;
+  /// 连续失败次数，用于计算退避间隔
+  final int consecutiveFailures;
+
+  const AutoSyncStatus({
+    required this.active,
+    required this.syncing,
+    this.lastSuccessAt,
+    this.lastError,
+    this.lastErrorAt,
+    this.nextRunAt,
+    required this.consecutiveFailures,
+  });
+
+  static Future<AutoSyncStatus> default_() =>
+      RustLib.instance.api.crateApiAutosyncAutoSyncStatusDefault();
+
+  @override
+  int get hashCode =>
+      active.hashCode ^
+      syncing.hashCode ^
+      lastSuccessAt.hashCode ^
+      lastError.hashCode ^
+      lastErrorAt.hashCode ^
+      nextRunAt.hashCode ^
+      consecutiveFailures.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AutoSyncStatus &&
+          runtimeType == other.runtimeType &&
+          active == other.active &&
+          syncing == other.syncing &&
+          lastSuccessAt == other.lastSuccessAt &&
+          lastError == other.lastError &&
+          lastErrorAt == other.lastErrorAt &&
+          nextRunAt == other.nextRunAt &&
+          consecutiveFailures == other.consecutiveFailures;
+}
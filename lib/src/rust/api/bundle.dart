@@ -0,0 +1,113 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `decode_accounts`, `decode_bundle`, `derive_key`, `encode_bundle`, `next_free_alias`, `normalize_passphrase`, `read_header`, `seal`, `unseal`, `write_account`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BundleAccount`, `BundlePayload`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 将指定账号导出为单个归档文件，`aliases` 为空时导出全部账号；返回导出的账号数
+Future<int> exportAccounts({
+  required List<String> aliases,
+  required String path,
+  String? passphrase,
+}) => RustLib.instance.api.crateApiBundleExportAccounts(
+  aliases: aliases,
+  path: path,
+  passphrase: passphrase,
+);
+
+/// 读取归档概要，用于导入前预览
+Future<BundleInfo> inspectBundle({required String path, String? passphrase}) =>
+    RustLib.instance.api.crateApiBundleInspectBundle(
+      path: path,
+      passphrase: passphrase,
+    );
+
+/// 从归档文件导入账号
+Future<List<ImportedAccount>> importAccounts({
+  required String path,
+  String? passphrase,
+  required ImportConflictPolicy conflictPolicy,
+}) => RustLib.instance.api.crateApiBundleImportAccounts(
+  path: path,
+  passphrase: passphrase,
+  conflictPolicy: conflictPolicy,
+);
+
+/// 归档概要，加密归档在未提供口令时 `aliases` 为空
+class BundleInfo {
+  final int version;
+  final bool encrypted;
+  final PlatformInt64? createdAt;
+  final List<String> aliases;
+
+  const BundleInfo({
+    required this.version,
+    required this.encrypted,
+    this.createdAt,
+    required this.aliases,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      encrypted.hashCode ^
+      createdAt.hashCode ^
+      aliases.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is BundleInfo &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          encrypted == other.encrypted &&
+          createdAt == other.createdAt &&
+          aliases == other.aliases;
+}
+
+/// 单个账号的导入结果
+enum ImportAction { created, overwritten, renamed, skipped }
+
+/// 导入时遇到同名账号的处理方式
+enum ImportConflictPolicy {
+  /// 保留本地账号，跳过归档中的同名账号
+  skip,
+  /// 用归档中的账号覆盖本地账号
+  overwrite,
+  /// 仅当归档中的凭证比本地更新时覆盖
+  keepNewer,
+  /// 以新别名导入，例如 `alias (2)`
+  rename,
+}
+
+class ImportedAccount {
+  /// 归档中的别名
+  final String originalAlias;
+  /// 实际写入的别名，跳过时与 `original_alias` 相同
+  final String alias;
+  final ImportAction action;
+
+  const ImportedAccount({
+    required this.originalAlias,
+    required this.alias,
+    required this.action,
+  });
+
+  @override
+  int get hashCode => originalAlias.hashCode ^ alias.hashCode ^ action.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ImportedAccount &&
+          runtimeType == other.runtimeType &&
+          originalAlias == other.originalAlias &&
+          alias == other.alias &&
+          action == other.action;
+}
@@ -0,0 +1,54 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `blocking`, `call`, `forward_events`, `handle_connection`, `handle`, `load_token`, `message`, `new`, `params`, `publish_event`, `response`, `serve`, `to_value`, `token_matches`, `token_path`, `write_token`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AliasParams`, `AuthParams`, `ControlEvent`, `Request`, `RpcError`, `Server`, `Session`, `SyncParams`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`, `from`
+
+/// 按设置（重新）启动控制服务；设置中未开启时停止服务并返回未运行的状态
+Future<ControlServerStatus> startControlServer() =>
+    RustLib.instance.api.crateApiControlStartControlServer();
+
+/// 停止控制服务并断开所有连接
+Future<void> stopControlServer() =>
+    RustLib.instance.api.crateApiControlStopControlServer();
+
+/// 获取控制服务状态
+Future<ControlServerStatus> getControlServerStatus() =>
+    RustLib.instance.api.crateApiControlGetControlServerStatus();
+
+/// 获取客户端认证使用的令牌，首次调用时生成
+Future<String> getControlToken() =>
+    RustLib.instance.api.crateApiControlGetControlToken();
+
+/// 重新生成令牌，之后使用旧令牌的连接无法再认证
+Future<String> regenerateControlToken() =>
+    RustLib.instance.api.crateApiControlRegenerateControlToken();
+
+/// 控制服务的运行状态
+class ControlServerStatus {
+  final bool running;
+  /// 实际监听的端口，设置中为 0 时由系统分配
+  final int? port;
+
+  const ControlServerStatus({required this.running, this.port});
+
+  static Future<ControlServerStatus> default_() =>
+      RustLib.instance.api.crateApiControlControlServerStatusDefault();
+
+  @override
+  int get hashCode => running.hashCode ^ port.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ControlServerStatus &&
+          runtimeType == other.runtimeType &&
+          running == other.running &&
+          port == other.port;
+}
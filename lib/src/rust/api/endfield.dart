@@ -4,10 +4,12 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'health.dart';
+import 'hooks.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_account_cache_file`, `get_accounts_dir`, `get_app_data_dir`, `get_endfield_dir`, `validate_alias`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `allows_pull`, `allows_push`, `backup_game_cache`, `file_updated_at`, `get_account_cache_file`, `get_accounts_dir`, `get_app_data_dir`, `get_endfield_dir`, `remove_account`, `save_account`, `switch_account`, `unix_now`, `validate_alias`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
 
 Future<String> findLoginCachePath() =>
     RustLib.instance.api.crateApiEndfieldFindLoginCachePath();
@@ -16,14 +18,20 @@ Future<String> findLoginCachePath() =>
 Future<List<AccountInfo>> getAccountList() =>
     RustLib.instance.api.crateApiEndfieldGetAccountList();
 
-/// 保存当前登录的账号
-Future<void> saveCurrentAccount({required String alias}) =>
+/// 保存当前登录的账号，返回运行过的钩子
+/// 前置钩子失败时取消保存；后置钩子的失败只体现在返回结果中
+Future<List<HookRun>> saveCurrentAccount({required String alias}) =>
     RustLib.instance.api.crateApiEndfieldSaveCurrentAccount(alias: alias);
 
-/// 切换到指定账号
-Future<void> switchToAccount({required String alias}) =>
+/// 切换到指定账号，返回运行过的钩子
+/// 前置钩子失败时取消切换；后置钩子的失败只体现在返回结果中
+Future<List<HookRun>> switchToAccount({required String alias}) =>
     RustLib.instance.api.crateApiEndfieldSwitchToAccount(alias: alias);
 
+/// 获取游戏当前登录的账号（内容与游戏凭证相同的已保存账号），都不相同时返回 `None`
+Future<String?> getCurrentAccount() =>
+    RustLib.instance.api.crateApiEndfieldGetCurrentAccount();
+
 /// 删除指定账号
 Future<void> deleteAccount({required String alias}) =>
     RustLib.instance.api.crateApiEndfieldDeleteAccount(alias: alias);
@@ -31,11 +39,37 @@ Future<void> deleteAccount({required String alias}) =>
 class AccountInfo {
   final String alias;
   final PlatformInt64 updatedAt;
+  final String? group;
+  final List<String> tags;
+  final String? notes;
+  final PlatformInt64? lastUsedAt;
+  final SyncStatus syncStatus;
+  final AccountSyncPolicy syncPolicy;
+  final HealthStatus health;
 
-  const AccountInfo({required this.alias, required this.updatedAt});
+  const AccountInfo({
+    required this.alias,
+    required this.updatedAt,
+    this.group,
+    required this.tags,
+    this.notes,
+    this.lastUsedAt,
+    required this.syncStatus,
+    required this.syncPolicy,
+    required this.health,
+  });
 
   @override
-  int get hashCode => alias.hashCode ^ updatedAt.hashCode;
+  int get hashCode =>
+      alias.hashCode ^
+      updatedAt.hashCode ^
+      group.hashCode ^
+      tags.hashCode ^
+      notes.hashCode ^
+      lastUsedAt.hashCode ^
+      syncStatus.hashCode ^
+      syncPolicy.hashCode ^
+      health.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -43,5 +77,35 @@ class AccountInfo {
       other is AccountInfo &&
           runtimeType == other.runtimeType &&
           alias == other.alias &&
-          updatedAt == other.updatedAt;
+          updatedAt == other.updatedAt &&
+          group == other.group &&
+          tags == other.tags &&
+          notes == other.notes &&
+          lastUsedAt == other.lastUsedAt &&
+          syncStatus == other.syncStatus &&
+          syncPolicy == other.syncPolicy &&
+          health == other.health;
+}
+
+/// 单个账号的云端同步策略
+enum AccountSyncPolicy {
+  /// 只保存在本地，不参与同步
+  never,
+  /// 只上传，不会被远端内容覆盖
+  pushOnly,
+  /// 上传与下载
+  twoWay;
+
+  static Future<AccountSyncPolicy> default_() =>
+      RustLib.instance.api.crateApiEndfieldAccountSyncPolicyDefault();
+}
+
+/// 账号相对于上一次云端同步的状态
+enum SyncStatus {
+  /// 从未同步过
+  neverSynced,
+  /// 本地内容与上次同步时一致
+  synced,
+  /// 上次同步后本地又有修改
+  modified,
 }
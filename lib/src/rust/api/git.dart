@@ -0,0 +1,48 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `checked`, `command`, `decrypt`, `default_branch`, `encrypt`, `for_planning`, `git_succeeds`, `git`, `key`, `new`, `prepare`, `read_plain`, `run`, `write_plain`
+// These functions are ignored because they have generic arguments: `commit`, `delete`, `ensure_dir`, `get`, `list`, `lock`, `put`, `read`, `stat`, `unlock`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `GitBackend`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// Git 仓库远端，账号保存在仓库的 `EndSwitcherConfig` 目录下，每次推送生成一次提交
+/// 同步前只对远程仓库做快进合并，本地分支已分叉或该目录有未提交的修改时同步失败，需手动处理
+class GitConfig {
+  /// 本地仓库路径，不是仓库时自动初始化
+  final String path;
+  /// 远程仓库地址（也可以是本地的裸仓库路径），未设置时只在本地提交
+  final String? remoteUrl;
+  final String branch;
+  /// 加密仓库中文件的口令，同步同一仓库的设备需使用相同的口令
+  final String passphrase;
+
+  const GitConfig({
+    required this.path,
+    this.remoteUrl,
+    required this.branch,
+    required this.passphrase,
+  });
+
+  @override
+  int get hashCode =>
+      path.hashCode ^
+      remoteUrl.hashCode ^
+      branch.hashCode ^
+      passphrase.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GitConfig &&
+          runtimeType == other.runtimeType &&
+          path == other.path &&
+          remoteUrl == other.remoteUrl &&
+          branch == other.branch &&
+          passphrase == other.passphrase;
+}
@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `assess_cache_file`, `cached_scan`, `classify`, `collect_expiries`, `is_expiry_key`, `jwt_expiry`, `normalize_timestamp`, `scan_content`, `scan_text_for_jwt`, `value_as_timestamp`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CachedScan`, `ContentScan`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`
+
+/// 检查所有已保存账号的凭证健康状态
+Future<List<AccountHealth>> checkAccountsHealth() =>
+    RustLib.instance.api.crateApiHealthCheckAccountsHealth();
+
+/// 检查单个账号的凭证健康状态
+Future<AccountHealth> checkAccountHealth({required String alias}) =>
+    RustLib.instance.api.crateApiHealthCheckAccountHealth(alias: alias);
+
+/// 单个账号的健康检查结果
+class AccountHealth {
+  final String alias;
+  final HealthStatus status;
+  /// 文件大小（字节）
+  final BigInt size;
+  /// 距上次刷新的秒数
+  final PlatformInt64 ageSecs;
+  /// 从凭证内容中解析出的过期时间（Unix 秒）
+  final PlatformInt64? expiresAt;
+  /// 检查过程中发现的问题描述
+  final List<String> issues;
+
+  const AccountHealth({
+    required this.alias,
+    required this.status,
+    required this.size,
+    required this.ageSecs,
+    this.expiresAt,
+    required this.issues,
+  });
+
+  @override
+  int get hashCode =>
+      alias.hashCode ^
+      status.hashCode ^
+      size.hashCode ^
+      ageSecs.hashCode ^
+      expiresAt.hashCode ^
+      issues.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountHealth &&
+          runtimeType == other.runtimeType &&
+          alias == other.alias &&
+          status == other.status &&
+          size == other.size &&
+          ageSecs == other.ageSecs &&
+          expiresAt == other.expiresAt &&
+          issues == other.issues;
+}
+
+/// 已保存凭证的健康状态
+enum HealthStatus {
+  /// 凭证较新，大概率可以直接登录
+  fresh,
+  /// 凭证有一段时间未刷新或即将过期，建议登录后重新保存
+  aging,
+  /// 凭证大概率已过期
+  likelyExpired,
+  /// 文件为空或无法识别
+  invalid,
+}
@@ -0,0 +1,73 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `blob_path`, `current_hash`, `fetch_history`, `history_depth`, `history_etag`, `is_referenced`, `pending_removals`, `record_version`, `restore_version`, `set_current`, `store_history`, `upload_blob`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AccountHistory`, `RemoteHistory`, `VersionEntry`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`
+
+/// 列出账号在远端保存的历史版本（新版本在前），`remote` 为 `None` 时使用第一个远端
+Future<List<RemoteVersion>> listRemoteVersions({
+  String? remote,
+  required String alias,
+}) => RustLib.instance.api.crateApiHistoryListRemoteVersions(
+  remote: remote,
+  alias: alias,
+);
+
+/// 把远端账号恢复到指定历史版本；`apply_locally` 为 `true` 时同时覆盖本地保存的账号
+Future<void> restoreRemoteVersion({
+  String? remote,
+  required String alias,
+  required String hash,
+  required bool applyLocally,
+}) => RustLib.instance.api.crateApiHistoryRestoreRemoteVersion(
+  remote: remote,
+  alias: alias,
+  hash: hash,
+  applyLocally: applyLocally,
+);
+
+/// 远端保存的一个账号版本
+class RemoteVersion {
+  /// 内容的 SHA-256（十六进制）
+  final String hash;
+  final BigInt size;
+  /// 上传时本地凭证的更新时间
+  final PlatformInt64 updatedAt;
+  final PlatformInt64 uploadedAt;
+  /// 是否为远端当前版本
+  final bool current;
+
+  const RemoteVersion({
+    required this.hash,
+    required this.size,
+    required this.updatedAt,
+    required this.uploadedAt,
+    required this.current,
+  });
+
+  @override
+  int get hashCode =>
+      hash.hashCode ^
+      size.hashCode ^
+      updatedAt.hashCode ^
+      uploadedAt.hashCode ^
+      current.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RemoteVersion &&
+          runtimeType == other.runtimeType &&
+          hash == other.hash &&
+          size == other.size &&
+          updatedAt == other.updatedAt &&
+          uploadedAt == other.uploadedAt &&
+          current == other.current;
+}
@@ -0,0 +1,111 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `capture`, `default_enabled`, `default_hook_timeout_secs`, `enabled_hooks`, `failure`, `name`, `new`, `run_hook`, `run_post_hooks`, `run_pre_hooks`, `succeeded`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HookContext`, `HookVeto`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 切换或保存账号时运行的外部命令
+/// 通过环境变量获取上下文：`ENDSWITCHER_EVENT`、`ENDSWITCHER_ALIAS`、`ENDSWITCHER_ACCOUNT_FILE`
+/// （已保存的账号文件）、`ENDSWITCHER_LOGIN_CACHE`（游戏凭证）与 `ENDSWITCHER_DATA_DIR`
+class HookConfig {
+  final HookEvent event;
+  /// 可执行文件或脚本路径
+  final String command;
+  final List<String> args;
+  /// 超过该秒数后终止命令，前置钩子超时视为取消操作
+  final int timeoutSecs;
+  final bool enabled;
+
+  const HookConfig({
+    required this.event,
+    required this.command,
+    required this.args,
+    required this.timeoutSecs,
+    required this.enabled,
+  });
+
+  @override
+  int get hashCode =>
+      event.hashCode ^
+      command.hashCode ^
+      args.hashCode ^
+      timeoutSecs.hashCode ^
+      enabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HookConfig &&
+          runtimeType == other.runtimeType &&
+          event == other.event &&
+          command == other.command &&
+          args == other.args &&
+          timeoutSecs == other.timeoutSecs &&
+          enabled == other.enabled;
+}
+
+/// 钩子触发的时机
+enum HookEvent {
+  /// 切换账号前，退出码非 0 时取消切换
+  preSwitch,
+  postSwitch,
+  /// 保存账号前，退出码非 0 时取消保存
+  preSave,
+  postSave,
+}
+
+/// 一次钩子运行的结果
+class HookRun {
+  final HookEvent event;
+  final String command;
+  /// 退出码，超时、无法启动或被信号终止时为 `None`
+  final int? exitCode;
+  final bool timedOut;
+  final String stdout;
+  final String stderr;
+  /// 无法启动等错误
+  final String? error;
+  final BigInt durationMs;
+
+  const HookRun({
+    required this.event,
+    required this.command,
+    this.exitCode,
+    required this.timedOut,
+    required this.stdout,
+    required this.stderr,
+    this.error,
+    required this.durationMs,
+  });
+
+  @override
+  int get hashCode =>
+      event.hashCode ^
+      command.hashCode ^
+      exitCode.hashCode ^
+      timedOut.hashCode ^
+      stdout.hashCode ^
+      stderr.hashCode ^
+      error.hashCode ^
+      durationMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HookRun &&
+          runtimeType == other.runtimeType &&
+          event == other.event &&
+          command == other.command &&
+          exitCode == other.exitCode &&
+          timedOut == other.timedOut &&
+          stdout == other.stdout &&
+          stderr == other.stderr &&
+          error == other.error &&
+          durationMs == other.durationMs;
+}
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'report.dart';
+
+// These functions are ignored because they are not marked as `pub`: `begin`, `complete`, `downloaded`, `ensure_no_interrupted_sync`, `finish`, `journal_dir`, `load_journal`, `plan`, `remotes`, `reopen`, `rollback`, `save`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `JournalEntry`, `JournalFile`, `PullJournal`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+/// 获取上次中断的拉取，没有时返回 `None`
+Future<InterruptedSync?> getInterruptedSync() =>
+    RustLib.instance.api.crateApiJournalGetInterruptedSync();
+
+/// 继续中断的拉取：重新从原来的远端拉取，已下载完成的账号不会重复下载
+Future<SyncReport> resumeInterruptedSync() =>
+    RustLib.instance.api.crateApiJournalResumeInterruptedSync();
+
+/// 回滚中断的拉取：恢复下载前的本地账号与同步记录，返回被恢复的账号
+Future<List<String>> rollbackInterruptedSync() =>
+    RustLib.instance.api.crateApiJournalRollbackInterruptedSync();
+
+/// 上次未完成的拉取，应用启动时检查并提示继续或回滚
+class InterruptedSync {
+  /// 开始时间（Unix 秒）
+  final PlatformInt64 startedAt;
+  /// 参与拉取的远端，按优先级排列
+  final List<String> remotes;
+  /// 已下载完成的账号
+  final List<String> completed;
+  /// 计划下载但尚未完成的账号
+  final List<String> pending;
+
+  const InterruptedSync({
+    required this.startedAt,
+    required this.remotes,
+    required this.completed,
+    required this.pending,
+  });
+
+  @override
+  int get hashCode =>
+      startedAt.hashCode ^
+      remotes.hashCode ^
+      completed.hashCode ^
+      pending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InterruptedSync &&
+          runtimeType == other.runtimeType &&
+          startedAt == other.startedAt &&
+          remotes == other.remotes &&
+          completed == other.completed &&
+          pending == other.pending;
+}
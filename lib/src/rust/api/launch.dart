@@ -0,0 +1,124 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `build_command`, `process_status`, `spawn_game`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`
+
+Future<void> saveLaunchConfig({required LaunchConfig config}) =>
+    RustLib.instance.api.crateApiLaunchSaveLaunchConfig(config: config);
+
+Future<LaunchConfig> loadLaunchConfig() =>
+    RustLib.instance.api.crateApiLaunchLoadLaunchConfig();
+
+/// 按启动配置启动游戏，返回进程 PID
+Future<int> launchGame() => RustLib.instance.api.crateApiLaunchLaunchGame();
+
+/// 切换到指定账号并启动游戏，返回进程 PID
+Future<int> switchAndLaunch({required String alias}) =>
+    RustLib.instance.api.crateApiLaunchSwitchAndLaunch(alias: alias);
+
+/// 查询由本程序启动的游戏进程状态
+Future<GameProcessStatus> getGameProcessStatus({required int pid}) =>
+    RustLib.instance.api.crateApiLaunchGetGameProcessStatus(pid: pid);
+
+/// 列出本次运行期间启动过的所有游戏进程
+Future<List<GameProcessStatus>> listGameProcesses() =>
+    RustLib.instance.api.crateApiLaunchListGameProcesses();
+
+/// 等待游戏进程退出，返回最终状态
+Future<GameProcessStatus> waitGameExit({required int pid}) =>
+    RustLib.instance.api.crateApiLaunchWaitGameExit(pid: pid);
+
+/// 由本程序启动的游戏进程状态
+class GameProcessStatus {
+  final int pid;
+  final bool running;
+  /// 进程退出码，仍在运行或被信号终止时为 `None`
+  final int? exitCode;
+
+  const GameProcessStatus({
+    required this.pid,
+    required this.running,
+    this.exitCode,
+  });
+
+  @override
+  int get hashCode => pid.hashCode ^ running.hashCode ^ exitCode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GameProcessStatus &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          running == other.running &&
+          exitCode == other.exitCode;
+}
+
+/// 游戏启动配置
+class LaunchConfig {
+  /// 游戏本体或启动器的可执行文件路径
+  final String executable;
+  final List<String> args;
+  /// 工作目录，未设置时使用可执行文件所在目录
+  final String? workingDir;
+  /// Linux 下的包装程序，例如 `wine` 或 Proton 脚本
+  final String? wrapper;
+  /// 传给包装程序的参数，位于可执行文件路径之前，例如 Proton 的 `run`
+  final List<String> wrapperArgs;
+  final List<LaunchEnvVar> env;
+
+  const LaunchConfig({
+    required this.executable,
+    required this.args,
+    this.workingDir,
+    this.wrapper,
+    required this.wrapperArgs,
+    required this.env,
+  });
+
+  @override
+  int get hashCode =>
+      executable.hashCode ^
+      args.hashCode ^
+      workingDir.hashCode ^
+      wrapper.hashCode ^
+      wrapperArgs.hashCode ^
+      env.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LaunchConfig &&
+          runtimeType == other.runtimeType &&
+          executable == other.executable &&
+          args == other.args &&
+          workingDir == other.workingDir &&
+          wrapper == other.wrapper &&
+          wrapperArgs == other.wrapperArgs &&
+          env == other.env;
+}
+
+/// 启动时额外设置的环境变量
+class LaunchEnvVar {
+  final String key;
+  final String value;
+
+  const LaunchEnvVar({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LaunchEnvVar &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
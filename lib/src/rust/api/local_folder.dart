@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `copy_hashed`, `ensure_base`, `modified_before`, `new`, `replace_with`, `resolve`, `temp_path`
+// These functions are ignored because they have generic arguments: `delete`, `ensure_dir`, `get`, `list`, `lock`, `put`, `read`, `stat`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `LocalFolderBackend`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// 本地或挂载文件夹远端，账号保存在 `<path>/EndSwitcherConfig` 下
+class LocalFolderConfig {
+  final String path;
+
+  const LocalFolderConfig({required this.path});
+
+  @override
+  int get hashCode => path.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LocalFolderConfig &&
+          runtimeType == other.runtimeType &&
+          path == other.path;
+}
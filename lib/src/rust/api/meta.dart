@@ -0,0 +1,100 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'endfield.dart';
+import 'health.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `apply_metadata`, `ensure_account_exists`, `forget_account`, `forget_remote`, `get_meta_file_path`, `load_meta`, `mark_synced`, `normalize_tags`, `restore_meta`, `restore_synced_state`, `save_meta`, `synced_state`, `touch_last_used`, `update_meta`, `validate_label`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `AccountMeta`, `MetaStore`, `SyncedState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`, `default`, `default`
+
+/// 获取所有分组（按用户定义的顺序）
+Future<List<String>> listGroups() =>
+    RustLib.instance.api.crateApiMetaListGroups();
+
+/// 新建分组
+Future<void> createGroup({required String name}) =>
+    RustLib.instance.api.crateApiMetaCreateGroup(name: name);
+
+/// 重命名分组，组内账号随之迁移
+Future<void> renameGroup({required String oldName, required String newName}) =>
+    RustLib.instance.api.crateApiMetaRenameGroup(
+      oldName: oldName,
+      newName: newName,
+    );
+
+/// 删除分组，组内账号变为未分组，账号本身不会被删除
+Future<void> deleteGroup({required String name}) =>
+    RustLib.instance.api.crateApiMetaDeleteGroup(name: name);
+
+/// 调整分组顺序，`names` 需包含全部现有分组
+Future<void> reorderGroups({required List<String> names}) =>
+    RustLib.instance.api.crateApiMetaReorderGroups(names: names);
+
+/// 设置账号所属分组，传入 `None` 表示移出分组；不存在的分组会自动创建
+Future<void> setAccountGroup({required String alias, String? group}) =>
+    RustLib.instance.api.crateApiMetaSetAccountGroup(
+      alias: alias,
+      group: group,
+    );
+
+/// 按分组获取账号，分组按用户顺序排列，未分组账号放在最后
+Future<List<AccountGroup>> getAccountsByGroup() =>
+    RustLib.instance.api.crateApiMetaGetAccountsByGroup();
+
+/// 获取所有已使用的标签
+Future<List<String>> listTags() => RustLib.instance.api.crateApiMetaListTags();
+
+/// 覆盖设置账号的标签
+Future<void> setAccountTags({
+  required String alias,
+  required List<String> tags,
+}) => RustLib.instance.api.crateApiMetaSetAccountTags(alias: alias, tags: tags);
+
+/// 获取带有指定标签的账号（不区分大小写）
+Future<List<AccountInfo>> getAccountsByTag({required String tag}) =>
+    RustLib.instance.api.crateApiMetaGetAccountsByTag(tag: tag);
+
+/// 设置账号备注，传入 `None` 或空白内容表示清除
+Future<void> setAccountNotes({required String alias, String? notes}) =>
+    RustLib.instance.api.crateApiMetaSetAccountNotes(
+      alias: alias,
+      notes: notes,
+    );
+
+/// 设置账号的同步策略
+Future<void> setAccountSyncPolicy({
+  required String alias,
+  required AccountSyncPolicy policy,
+}) => RustLib.instance.api.crateApiMetaSetAccountSyncPolicy(
+  alias: alias,
+  policy: policy,
+);
+
+/// 保存账号的手动排序，未包含在 `aliases` 中的账号排在其后
+Future<void> reorderAccounts({required List<String> aliases}) =>
+    RustLib.instance.api.crateApiMetaReorderAccounts(aliases: aliases);
+
+/// 分组视图中的一组账号，`name` 为 `None` 表示未分组
+class AccountGroup {
+  final String? name;
+  final List<AccountInfo> accounts;
+
+  const AccountGroup({this.name, required this.accounts});
+
+  @override
+  int get hashCode => name.hashCode ^ accounts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountGroup &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          accounts == other.accounts;
+}
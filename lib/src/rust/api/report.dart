@@ -0,0 +1,154 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `add`, `append_report`, `ensure_complete`, `fail`, `finish`, `get_history_file_path`, `load_reports`, `new`, `new`, `now_millis`, `record`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 最近的同步报告，新报告在前
+Future<List<SyncReport>> getSyncHistory() =>
+    RustLib.instance.api.crateApiReportGetSyncHistory();
+
+/// 清空同步历史
+Future<void> clearSyncHistory() =>
+    RustLib.instance.api.crateApiReportClearSyncHistory();
+
+/// 预演中的一个条目
+class PlannedEntry {
+  final String remote;
+  /// 账号别名，为 `None` 时表示整个远端
+  final String? name;
+  /// 同步时将执行的动作
+  final SyncAction action;
+  final String? reason;
+
+  const PlannedEntry({
+    required this.remote,
+    this.name,
+    required this.action,
+    this.reason,
+  });
+
+  @override
+  int get hashCode =>
+      remote.hashCode ^ name.hashCode ^ action.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PlannedEntry &&
+          runtimeType == other.runtimeType &&
+          remote == other.remote &&
+          name == other.name &&
+          action == other.action &&
+          reason == other.reason;
+}
+
+/// 单个条目的处理结果
+enum SyncAction {
+  uploaded,
+  downloaded,
+  /// 内容未变化或无需处理
+  skipped,
+  /// 本地与远端都有修改，未覆盖任何一方
+  conflicted,
+  /// 远端删除了过期的历史版本
+  deleted,
+  failed,
+}
+
+/// 同步报告中的一个条目
+class SyncEntry {
+  final String remote;
+  /// 账号别名，为 `None` 时表示整个远端（例如无法连接）
+  final String? name;
+  final SyncAction action;
+  final String? reason;
+  final BigInt durationMs;
+
+  const SyncEntry({
+    required this.remote,
+    this.name,
+    required this.action,
+    this.reason,
+    required this.durationMs,
+  });
+
+  @override
+  int get hashCode =>
+      remote.hashCode ^
+      name.hashCode ^
+      action.hashCode ^
+      reason.hashCode ^
+      durationMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncEntry &&
+          runtimeType == other.runtimeType &&
+          remote == other.remote &&
+          name == other.name &&
+          action == other.action &&
+          reason == other.reason &&
+          durationMs == other.durationMs;
+}
+
+/// 同步操作类型
+enum SyncOperation { push, pull }
+
+/// 同步预演结果
+class SyncPlan {
+  final SyncOperation operation;
+  final List<PlannedEntry> entries;
+
+  const SyncPlan({required this.operation, required this.entries});
+
+  @override
+  int get hashCode => operation.hashCode ^ entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncPlan &&
+          runtimeType == other.runtimeType &&
+          operation == other.operation &&
+          entries == other.entries;
+}
+
+/// 一次同步的详细报告
+class SyncReport {
+  final SyncOperation operation;
+  /// 开始与结束时间（Unix 毫秒）
+  final PlatformInt64 startedAt;
+  final PlatformInt64 finishedAt;
+  final List<SyncEntry> entries;
+
+  const SyncReport({
+    required this.operation,
+    required this.startedAt,
+    required this.finishedAt,
+    required this.entries,
+  });
+
+  @override
+  int get hashCode =>
+      operation.hashCode ^
+      startedAt.hashCode ^
+      finishedAt.hashCode ^
+      entries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncReport &&
+          runtimeType == other.runtimeType &&
+          operation == other.operation &&
+          startedAt == other.startedAt &&
+          finishedAt == other.finishedAt &&
+          entries == other.entries;
+}
@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `amz_timestamp`, `default_region`, `hmac_sha256`, `key`, `list_page`, `new`, `request`, `sha256_hex`, `uri_encode`
+// These functions are ignored because they have generic arguments: `delete`, `ensure_dir`, `get`, `list`, `lock`, `put`, `read`, `stat`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `S3Backend`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// S3 兼容对象存储（MinIO、Cloudflare R2、Backblaze B2、阿里云 OSS 等）
+class S3Config {
+  /// 服务地址，例如 `https://s3.us-east-1.amazonaws.com`
+  final String endpoint;
+  /// 签名使用的区域，R2 为 `auto`
+  final String region;
+  final String bucket;
+  final String accessKeyId;
+  final String secretAccessKey;
+  /// 桶内的路径前缀，账号保存在 `<prefix>/EndSwitcherConfig/` 下
+  final String? prefix;
+  /// 使用 `endpoint/bucket/key` 形式的地址（MinIO 等），否则使用 `bucket.endpoint/key`
+  final bool pathStyle;
+
+  const S3Config({
+    required this.endpoint,
+    required this.region,
+    required this.bucket,
+    required this.accessKeyId,
+    required this.secretAccessKey,
+    this.prefix,
+    required this.pathStyle,
+  });
+
+  @override
+  int get hashCode =>
+      endpoint.hashCode ^
+      region.hashCode ^
+      bucket.hashCode ^
+      accessKeyId.hashCode ^
+      secretAccessKey.hashCode ^
+      prefix.hashCode ^
+      pathStyle.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is S3Config &&
+          runtimeType == other.runtimeType &&
+          endpoint == other.endpoint &&
+          region == other.region &&
+          bucket == other.bucket &&
+          accessKeyId == other.accessKeyId &&
+          secretAccessKey == other.secretAccessKey &&
+          prefix == other.prefix &&
+          pathStyle == other.pathStyle;
+}
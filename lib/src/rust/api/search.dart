@@ -0,0 +1,101 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'endfield.dart';
+import 'health.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `fold`, `fuzzy_score`, `is_stale`, `matches_filters`, `query_accounts`, `substring_score`, `text_score`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+/// 按条件搜索、筛选并排序已保存的账号
+Future<List<AccountInfo>> searchAccounts({required AccountQuery query}) =>
+    RustLib.instance.api.crateApiSearchSearchAccounts(query: query);
+
+/// 返回默认搜索条件（不筛选，按手动顺序）
+AccountQuery defaultAccountQuery() =>
+    RustLib.instance.api.crateApiSearchDefaultAccountQuery();
+
+/// 账号搜索条件，所有已设置的条件需同时满足
+class AccountQuery {
+  /// 在别名和备注中搜索的文本，不区分大小写与全角/半角
+  final String? text;
+  /// 为 `true` 时按子序列模糊匹配，否则按子串匹配
+  final bool fuzzy;
+  /// 账号需带有其中每一个标签
+  final List<String> tags;
+  /// 限定分组
+  final String? group;
+  /// 最近使用时间下限（Unix 秒，含）
+  final PlatformInt64? usedAfter;
+  /// 最近使用时间上限（Unix 秒，含）
+  final PlatformInt64? usedBefore;
+  /// 是否只保留（或排除）凭证可能已失效（`LikelyExpired` 或 `Invalid`）的账号
+  final bool? stale;
+  /// 限定同步状态
+  final SyncStatus? syncStatus;
+  final AccountSortKey sortBy;
+  final bool descending;
+
+  const AccountQuery({
+    this.text,
+    required this.fuzzy,
+    required this.tags,
+    this.group,
+    this.usedAfter,
+    this.usedBefore,
+    this.stale,
+    this.syncStatus,
+    required this.sortBy,
+    required this.descending,
+  });
+
+  static Future<AccountQuery> default_() =>
+      RustLib.instance.api.crateApiSearchAccountQueryDefault();
+
+  @override
+  int get hashCode =>
+      text.hashCode ^
+      fuzzy.hashCode ^
+      tags.hashCode ^
+      group.hashCode ^
+      usedAfter.hashCode ^
+      usedBefore.hashCode ^
+      stale.hashCode ^
+      syncStatus.hashCode ^
+      sortBy.hashCode ^
+      descending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AccountQuery &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          fuzzy == other.fuzzy &&
+          tags == other.tags &&
+          group == other.group &&
+          usedAfter == other.usedAfter &&
+          usedBefore == other.usedBefore &&
+          stale == other.stale &&
+          syncStatus == other.syncStatus &&
+          sortBy == other.sortBy &&
+          descending == other.descending;
+}
+
+/// 搜索结果的排序方式
+enum AccountSortKey {
+  /// 与 `get_account_list` 一致的手动顺序
+  manual,
+  /// 按别名
+  alias,
+  /// 按凭证更新时间
+  updatedAt,
+  /// 按最近使用时间，从未使用过的账号视为最早
+  lastUsedAt,
+  /// 按文本匹配程度，未指定搜索文本时等同于 `Manual`
+  relevance,
+}
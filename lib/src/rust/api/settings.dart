@@ -0,0 +1,289 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'git.dart';
+import 'hooks.dart';
+import 'launch.dart';
+import 'local_folder.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 's3.dart';
+import 'sftp.dart';
+import 'webdav.dart';
+part 'settings.freezed.dart';
+
+// These functions are ignored because they are not marked as `pub`: `allows_pull`, `allows_push`, `default_backup_depth`, `default_lock_timeout_secs`, `default_remote_history_depth`, `default_transfer_concurrency`, `get_legacy_launch_file_path`, `get_settings_file_path`, `load_settings`, `migrate_v0`, `migrate`, `save_settings`, `update_settings_with`, `validate_settings`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+/// 读取应用设置，首次运行时返回默认设置
+Future<AppSettings> getSettings() =>
+    RustLib.instance.api.crateApiSettingsGetSettings();
+
+/// 校验并保存应用设置
+Future<void> updateSettings({required AppSettings settings}) =>
+    RustLib.instance.api.crateApiSettingsUpdateSettings(settings: settings);
+
+/// 恢复默认设置
+Future<void> resetSettings() =>
+    RustLib.instance.api.crateApiSettingsResetSettings();
+
+AppSettings defaultSettings() =>
+    RustLib.instance.api.crateApiSettingsDefaultSettings();
+
+/// 应用的全部设置，保存在 config.json 中
+class AppSettings {
+  final int schemaVersion;
+  final PathSettings paths;
+  final List<SyncRemote> remotes;
+  /// 切换账号前为游戏内当前凭证保留的备份份数，0 表示不备份
+  final int backupDepth;
+  /// 远端为每个账号保留的历史版本数
+  final int remoteHistoryDepth;
+  /// 同步时同时进行的上传/下载数
+  final int transferConcurrency;
+  /// 其他操作（另一个窗口、命令行或自动同步）进行中时的最长等待秒数，0 表示不等待
+  final int lockTimeoutSecs;
+  final LaunchConfig? launch;
+  final AutoSyncSettings autoSync;
+  final ControlServerSettings controlServer;
+  /// 切换、保存账号前后运行的外部命令
+  final List<HookConfig> hooks;
+  final ThemeSettings theme;
+
+  const AppSettings({
+    required this.schemaVersion,
+    required this.paths,
+    required this.remotes,
+    required this.backupDepth,
+    required this.remoteHistoryDepth,
+    required this.transferConcurrency,
+    required this.lockTimeoutSecs,
+    this.launch,
+    required this.autoSync,
+    required this.controlServer,
+    required this.hooks,
+    required this.theme,
+  });
+
+  static Future<AppSettings> default_() =>
+      RustLib.instance.api.crateApiSettingsAppSettingsDefault();
+
+  @override
+  int get hashCode =>
+      schemaVersion.hashCode ^
+      paths.hashCode ^
+      remotes.hashCode ^
+      backupDepth.hashCode ^
+      remoteHistoryDepth.hashCode ^
+      transferConcurrency.hashCode ^
+      lockTimeoutSecs.hashCode ^
+      launch.hashCode ^
+      autoSync.hashCode ^
+      controlServer.hashCode ^
+      hooks.hashCode ^
+      theme.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppSettings &&
+          runtimeType == other.runtimeType &&
+          schemaVersion == other.schemaVersion &&
+          paths == other.paths &&
+          remotes == other.remotes &&
+          backupDepth == other.backupDepth &&
+          remoteHistoryDepth == other.remoteHistoryDepth &&
+          transferConcurrency == other.transferConcurrency &&
+          lockTimeoutSecs == other.lockTimeoutSecs &&
+          launch == other.launch &&
+          autoSync == other.autoSync &&
+          controlServer == other.controlServer &&
+          hooks == other.hooks &&
+          theme == other.theme;
+}
+
+/// 自动同步设置
+class AutoSyncSettings {
+  final bool enabled;
+  /// 定时同步间隔（分钟）
+  final int intervalMinutes;
+  /// 保存、切换、删除账号后同步
+  final bool onChange;
+  /// 启动时同步
+  final bool onStartup;
+
+  const AutoSyncSettings({
+    required this.enabled,
+    required this.intervalMinutes,
+    required this.onChange,
+    required this.onStartup,
+  });
+
+  static Future<AutoSyncSettings> default_() =>
+      RustLib.instance.api.crateApiSettingsAutoSyncSettingsDefault();
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      intervalMinutes.hashCode ^
+      onChange.hashCode ^
+      onStartup.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AutoSyncSettings &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          intervalMinutes == other.intervalMinutes &&
+          onChange == other.onChange &&
+          onStartup == other.onStartup;
+}
+
+/// 本地控制服务设置，供 Stream Deck、脚本等自动化工具调用
+class ControlServerSettings {
+  final bool enabled;
+  /// 监听 127.0.0.1 的端口，0 表示由系统分配
+  final int port;
+
+  const ControlServerSettings({required this.enabled, required this.port});
+
+  static Future<ControlServerSettings> default_() =>
+      RustLib.instance.api.crateApiSettingsControlServerSettingsDefault();
+
+  @override
+  int get hashCode => enabled.hashCode ^ port.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ControlServerSettings &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          port == other.port;
+}
+
+/// 自定义路径
+class PathSettings {
+  /// 游戏数据目录（包含 sdk_data_* 的目录），未设置时使用默认位置
+  final String? gameDataDir;
+
+  const PathSettings({this.gameDataDir});
+
+  static Future<PathSettings> default_() =>
+      RustLib.instance.api.crateApiSettingsPathSettingsDefault();
+
+  @override
+  int get hashCode => gameDataDir.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PathSettings &&
+          runtimeType == other.runtimeType &&
+          gameDataDir == other.gameDataDir;
+}
+
+@freezed
+sealed class RemoteBackend with _$RemoteBackend {
+  const RemoteBackend._();
+
+  const factory RemoteBackend.webDav(WebDavConfig field0) =
+      RemoteBackend_WebDav;
+  /// 本地或挂载的文件夹（Syncthing、NAS 共享、U 盘等）
+  const factory RemoteBackend.folder(LocalFolderConfig field0) =
+      RemoteBackend_Folder;
+  /// S3 兼容对象存储
+  const factory RemoteBackend.s3(S3Config field0) = RemoteBackend_S3;
+  /// Git 仓库，每次推送生成一次提交
+  const factory RemoteBackend.git(GitConfig field0) = RemoteBackend_Git;
+  /// SSH 服务器上的目录
+  const factory RemoteBackend.sftp(SftpConfig field0) = RemoteBackend_Sftp;
+}
+
+/// 远端允许的同步方向
+enum SyncDirection {
+  /// 只上传
+  pushOnly,
+  /// 只下载
+  pullOnly,
+  /// 双向
+  twoWay;
+
+  static Future<SyncDirection> default_() =>
+      RustLib.instance.api.crateApiSettingsSyncDirectionDefault();
+}
+
+/// 一个命名的同步远端，列表中靠前的远端优先级更高
+class SyncRemote {
+  final String name;
+  final SyncDirection direction;
+  final RemoteBackend backend;
+  /// 只同步匹配其中任一规则的账号，为空表示全部
+  /// 规则可以是别名、`tag:<标签>` 或 `group:<分组>`
+  final List<String> include;
+  /// 排除匹配其中任一规则的账号，优先于 `include`
+  final List<String> exclude;
+
+  const SyncRemote({
+    required this.name,
+    required this.direction,
+    required this.backend,
+    required this.include,
+    required this.exclude,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      direction.hashCode ^
+      backend.hashCode ^
+      include.hashCode ^
+      exclude.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncRemote &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          direction == other.direction &&
+          backend == other.backend &&
+          include == other.include &&
+          exclude == other.exclude;
+}
+
+enum ThemeMode {
+  system,
+  light,
+  dark;
+
+  static Future<ThemeMode> default_() =>
+      RustLib.instance.api.crateApiSettingsThemeModeDefault();
+}
+
+/// 界面外观偏好，仅由 Flutter 端读取
+class ThemeSettings {
+  final ThemeMode mode;
+  /// 强调色，形如 `#RRGGBB`
+  final String? accentColor;
+
+  const ThemeSettings({required this.mode, this.accentColor});
+
+  static Future<ThemeSettings> default_() =>
+      RustLib.instance.api.crateApiSettingsThemeSettingsDefault();
+
+  @override
+  int get hashCode => mode.hashCode ^ accentColor.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ThemeSettings &&
+          runtimeType == other.runtimeType &&
+          mode == other.mode &&
+          accentColor == other.accentColor;
+}
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// These functions are ignored because they are not marked as `pub`: `authenticate`, `blocking`, `conn`, `connect`, `copy_hashed`, `create_lock`, `default_port`, `is_not_found`, `key_type_name`, `known_hosts_path`, `new`, `resolve`, `upload`, `verify_host_key`, `with_sftp`
+// These functions are ignored because they have generic arguments: `delete`, `ensure_dir`, `get`, `list`, `lock`, `put`, `read`, `stat`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Connection`, `SftpBackend`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+/// SFTP 远端，账号保存在 `<path>/EndSwitcherConfig` 下
+class SftpConfig {
+  final String host;
+  final int port;
+  final String username;
+  /// 密码认证，设置了私钥时不使用
+  final String? password;
+  /// OpenSSH 格式的私钥文件路径
+  final String? privateKeyPath;
+  final String? passphrase;
+  /// 服务器上的目录，相对路径从登录用户的主目录开始，未设置时使用主目录
+  final String? path;
+  /// known_hosts 文件路径，未设置时使用 `~/.ssh/known_hosts`
+  final String? knownHostsPath;
+  /// 首次连接时信任服务器的主机密钥并写入 known_hosts
+  final bool acceptNewHost;
+
+  const SftpConfig({
+    required this.host,
+    required this.port,
+    required this.username,
+    this.password,
+    this.privateKeyPath,
+    this.passphrase,
+    this.path,
+    this.knownHostsPath,
+    required this.acceptNewHost,
+  });
+
+  @override
+  int get hashCode =>
+      host.hashCode ^
+      port.hashCode ^
+      username.hashCode ^
+      password.hashCode ^
+      privateKeyPath.hashCode ^
+      passphrase.hashCode ^
+      path.hashCode ^
+      knownHostsPath.hashCode ^
+      acceptNewHost.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SftpConfig &&
+          runtimeType == other.runtimeType &&
+          host == other.host &&
+          port == other.port &&
+          username == other.username &&
+          password == other.password &&
+          privateKeyPath == other.privateKeyPath &&
+          passphrase == other.passphrase &&
+          path == other.path &&
+          knownHostsPath == other.knownHostsPath &&
+          acceptNewHost == other.acceptNewHost;
+}
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'git.dart';
+import 'local_folder.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'report.dart';
+import 's3.dart';
+import 'settings.dart';
+import 'sftp.dart';
+import 'webdav.dart';
+
+// These functions are ignored because they are not marked as `pub`: `accounts_to_push`, `decide_pull`, `decide_push`, `downloaded_outcome`, `elapsed_ms`, `find_remote`, `list_pull_candidates`, `outcome_entry`, `plan_pull`, `plan_push`, `pull_account`, `pull_from_remote`, `pull_remotes`, `pull_selected`, `push_account`, `push_all`, `push_manifest`, `push_selected`, `push_to_remote`, `remote_accepts`, `resume_pull`, `rule_matches`, `select_remotes`, `synced_hashes`, `transfer_concurrency`, `validate_remote_entry_name`, `with_remote_lock`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EntryOutcome`
+
+/// 获取所有同步远端（按优先级排列）
+Future<List<SyncRemote>> listRemotes() =>
+    RustLib.instance.api.crateApiSyncListRemotes();
+
+/// 新增或更新（按名称匹配）一个同步远端
+Future<void> saveRemote({required SyncRemote remote}) =>
+    RustLib.instance.api.crateApiSyncSaveRemote(remote: remote);
+
+/// 删除指定的同步远端
+Future<void> removeRemote({required String name}) =>
+    RustLib.instance.api.crateApiSyncRemoveRemote(name: name);
+
+/// 推送到指定远端，`remote` 为 `None` 时推送到所有允许推送的远端
+Future<SyncReport> syncPush({String? remote}) =>
+    RustLib.instance.api.crateApiSyncSyncPush(remote: remote);
+
+/// 推送账号及 manifest 到指定远端，`remote` 为 `None` 时推送到所有允许推送的远端
+Future<SyncReport> syncPushWithManifest({String? remote}) =>
+    RustLib.instance.api.crateApiSyncSyncPushWithManifest(remote: remote);
+
+/// 从指定远端拉取，`remote` 为 `None` 时按顺序从所有允许拉取的远端拉取，同名账号以靠前的远端为准
+Future<SyncReport> syncPull({String? remote}) =>
+    RustLib.instance.api.crateApiSyncSyncPull(remote: remote);
+
+/// 预演推送或拉取，列出每个账号将执行的操作及原因，不修改本地或远端的任何内容
+/// `remote` 为 `None` 时包含所有允许该方向的远端
+Future<SyncPlan> planSync({required SyncOperation operation, String? remote}) =>
+    RustLib.instance.api.crateApiSyncPlanSync(
+      operation: operation,
+      remote: remote,
+    );
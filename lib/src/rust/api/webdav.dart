@@ -5,23 +5,28 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'report.dart';
 
-// These functions are ignored because they are not marked as `pub`: `build_base_url`, `get_accounts_dir`, `get_config_file_path`, `validate_remote_entry_name`
+// These functions are ignored because they are not marked as `pub`: `acquire_dav_lock`, `acquire_lock_file`, `build_base_url`, `dir_url`, `new`, `send_lock`, `send_unlock`, `url`, `webdav_remotes`, `with_auth`
+// These functions are ignored because they have generic arguments: `delete`, `ensure_dir`, `get`, `list`, `lock`, `put`, `read`, `stat`, `unlock`, `write`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `WebDavBackend`
 // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
 
+/// 保存 WebDAV 配置（写入第一个 WebDAV 远端，没有时新建名为 default 的远端）
 Future<void> saveWebdavConfig({required WebDavConfig config}) =>
     RustLib.instance.api.crateApiWebdavSaveWebdavConfig(config: config);
 
+/// 读取 WebDAV 配置（第一个 WebDAV 远端）
 Future<WebDavConfig> loadWebdavConfig() =>
     RustLib.instance.api.crateApiWebdavLoadWebdavConfig();
 
-Future<void> syncToWebdav() =>
+Future<SyncReport> syncToWebdav() =>
     RustLib.instance.api.crateApiWebdavSyncToWebdav();
 
-Future<void> syncToWebdavWithManifest() =>
+Future<SyncReport> syncToWebdavWithManifest() =>
     RustLib.instance.api.crateApiWebdavSyncToWebdavWithManifest();
 
-Future<void> syncFromWebdav() =>
+Future<SyncReport> syncFromWebdav() =>
     RustLib.instance.api.crateApiWebdavSyncFromWebdav();
 
 class WebDavConfig {
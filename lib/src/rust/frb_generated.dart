@@ -3,8 +3,26 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audit.dart';
+import 'api/autosync.dart';
+import 'api/bundle.dart';
+import 'api/control.dart';
 import 'api/endfield.dart';
+import 'api/git.dart';
+import 'api/health.dart';
+import 'api/history.dart';
+import 'api/hooks.dart';
+import 'api/journal.dart';
+import 'api/launch.dart';
+import 'api/local_folder.dart';
+import 'api/meta.dart';
+import 'api/report.dart';
+import 'api/s3.dart';
+import 'api/search.dart';
+import 'api/settings.dart';
+import 'api/sftp.dart';
 import 'api/simple.dart';
+import 'api/sync.dart';
 import 'api/webdav.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -68,7 +86,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -106721413;
+  int get rustContentHash => 1852700311;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,29 +97,216 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<AccountQuery> crateApiSearchAccountQueryDefault();
+
+  Future<AccountSyncPolicy> crateApiEndfieldAccountSyncPolicyDefault();
+
+  Future<AppSettings> crateApiSettingsAppSettingsDefault();
+
+  Future<AuditQuery> crateApiAuditAuditQueryDefault();
+
+  Future<AutoSyncSettings> crateApiSettingsAutoSyncSettingsDefault();
+
+  Future<AutoSyncStatus> crateApiAutosyncAutoSyncStatusDefault();
+
+  Future<AccountHealth> crateApiHealthCheckAccountHealth({
+    required String alias,
+  });
+
+  Future<List<AccountHealth>> crateApiHealthCheckAccountsHealth();
+
+  Future<void> crateApiReportClearSyncHistory();
+
+  Future<ControlServerSettings> crateApiSettingsControlServerSettingsDefault();
+
+  Future<ControlServerStatus> crateApiControlControlServerStatusDefault();
+
+  Future<void> crateApiMetaCreateGroup({required String name});
+
+  AccountQuery crateApiSearchDefaultAccountQuery();
+
+  AppSettings crateApiSettingsDefaultSettings();
+
   Future<void> crateApiEndfieldDeleteAccount({required String alias});
 
+  Future<void> crateApiMetaDeleteGroup({required String name});
+
+  Future<int> crateApiBundleExportAccounts({
+    required List<String> aliases,
+    required String path,
+    String? passphrase,
+  });
+
   Future<String> crateApiEndfieldFindLoginCachePath();
 
   Future<List<AccountInfo>> crateApiEndfieldGetAccountList();
 
+  Future<List<AccountGroup>> crateApiMetaGetAccountsByGroup();
+
+  Future<List<AccountInfo>> crateApiMetaGetAccountsByTag({required String tag});
+
+  Future<AutoSyncStatus> crateApiAutosyncGetAutoSyncStatus();
+
+  Future<ControlServerStatus> crateApiControlGetControlServerStatus();
+
+  Future<String> crateApiControlGetControlToken();
+
+  Future<String?> crateApiEndfieldGetCurrentAccount();
+
+  Future<GameProcessStatus> crateApiLaunchGetGameProcessStatus({
+    required int pid,
+  });
+
+  Future<InterruptedSync?> crateApiJournalGetInterruptedSync();
+
+  Future<AppSettings> crateApiSettingsGetSettings();
+
+  Future<List<SyncReport>> crateApiReportGetSyncHistory();
+
   String crateApiSimpleGreet({required String name});
 
+  Future<List<ImportedAccount>> crateApiBundleImportAccounts({
+    required String path,
+    String? passphrase,
+    required ImportConflictPolicy conflictPolicy,
+  });
+
   Future<void> crateApiSimpleInitApp();
 
+  Future<BundleInfo> crateApiBundleInspectBundle({
+    required String path,
+    String? passphrase,
+  });
+
+  Future<int> crateApiLaunchLaunchGame();
+
+  Future<List<GameProcessStatus>> crateApiLaunchListGameProcesses();
+
+  Future<List<String>> crateApiMetaListGroups();
+
+  Future<List<RemoteVersion>> crateApiHistoryListRemoteVersions({
+    String? remote,
+    required String alias,
+  });
+
+  Future<List<SyncRemote>> crateApiSyncListRemotes();
+
+  Future<List<String>> crateApiMetaListTags();
+
+  Future<LaunchConfig> crateApiLaunchLoadLaunchConfig();
+
   Future<WebDavConfig> crateApiWebdavLoadWebdavConfig();
 
-  Future<void> crateApiEndfieldSaveCurrentAccount({required String alias});
+  Future<PathSettings> crateApiSettingsPathSettingsDefault();
+
+  Future<SyncPlan> crateApiSyncPlanSync({
+    required SyncOperation operation,
+    String? remote,
+  });
+
+  Future<List<AuditEntry>> crateApiAuditQueryAuditLog({
+    required AuditQuery query,
+  });
+
+  Future<String> crateApiControlRegenerateControlToken();
+
+  Future<void> crateApiSyncRemoveRemote({required String name});
+
+  Future<void> crateApiMetaRenameGroup({
+    required String oldName,
+    required String newName,
+  });
+
+  Future<void> crateApiMetaReorderAccounts({required List<String> aliases});
+
+  Future<void> crateApiMetaReorderGroups({required List<String> names});
+
+  Future<void> crateApiSettingsResetSettings();
+
+  Future<bool> crateApiAutosyncRestartAutoSync();
+
+  Future<void> crateApiHistoryRestoreRemoteVersion({
+    String? remote,
+    required String alias,
+    required String hash,
+    required bool applyLocally,
+  });
+
+  Future<SyncReport> crateApiJournalResumeInterruptedSync();
+
+  Future<List<String>> crateApiJournalRollbackInterruptedSync();
+
+  Future<List<HookRun>> crateApiEndfieldSaveCurrentAccount({
+    required String alias,
+  });
+
+  Future<void> crateApiLaunchSaveLaunchConfig({required LaunchConfig config});
+
+  Future<void> crateApiSyncSaveRemote({required SyncRemote remote});
 
   Future<void> crateApiWebdavSaveWebdavConfig({required WebDavConfig config});
 
-  Future<void> crateApiEndfieldSwitchToAccount({required String alias});
+  Future<List<AccountInfo>> crateApiSearchSearchAccounts({
+    required AccountQuery query,
+  });
+
+  Future<void> crateApiMetaSetAccountGroup({
+    required String alias,
+    String? group,
+  });
+
+  Future<void> crateApiMetaSetAccountNotes({
+    required String alias,
+    String? notes,
+  });
+
+  Future<void> crateApiMetaSetAccountSyncPolicy({
+    required String alias,
+    required AccountSyncPolicy policy,
+  });
+
+  Future<void> crateApiMetaSetAccountTags({
+    required String alias,
+    required List<String> tags,
+  });
+
+  Future<bool> crateApiAutosyncStartAutoSync();
+
+  Future<ControlServerStatus> crateApiControlStartControlServer();
+
+  Future<void> crateApiAutosyncStopAutoSync();
+
+  Future<void> crateApiControlStopControlServer();
+
+  Future<int> crateApiLaunchSwitchAndLaunch({required String alias});
+
+  Future<List<HookRun>> crateApiEndfieldSwitchToAccount({
+    required String alias,
+  });
+
+  Future<SyncDirection> crateApiSettingsSyncDirectionDefault();
+
+  Future<SyncReport> crateApiWebdavSyncFromWebdav();
+
+  Future<SyncReport> crateApiSyncSyncPull({String? remote});
 
-  Future<void> crateApiWebdavSyncFromWebdav();
+  Future<SyncReport> crateApiSyncSyncPush({String? remote});
 
-  Future<void> crateApiWebdavSyncToWebdav();
+  Future<SyncReport> crateApiSyncSyncPushWithManifest({String? remote});
 
-  Future<void> crateApiWebdavSyncToWebdavWithManifest();
+  Future<SyncReport> crateApiWebdavSyncToWebdav();
+
+  Future<SyncReport> crateApiWebdavSyncToWebdavWithManifest();
+
+  Future<ThemeMode> crateApiSettingsThemeModeDefault();
+
+  Future<ThemeSettings> crateApiSettingsThemeSettingsDefault();
+
+  Future<void> crateApiAutosyncTriggerAutoSyncNow();
+
+  Future<void> crateApiSettingsUpdateSettings({required AppSettings settings});
+
+  Future<GameProcessStatus> crateApiLaunchWaitGameExit({required int pid});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -113,12 +318,11 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> crateApiEndfieldDeleteAccount({required String alias}) {
+  Future<AccountQuery> crateApiSearchAccountQueryDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(alias, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -127,21 +331,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_account_query,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiEndfieldDeleteAccountConstMeta,
-        argValues: [alias],
+        constMeta: kCrateApiSearchAccountQueryDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEndfieldDeleteAccountConstMeta =>
-      const TaskConstMeta(debugName: "delete_account", argNames: ["alias"]);
+  TaskConstMeta get kCrateApiSearchAccountQueryDefaultConstMeta =>
+      const TaskConstMeta(debugName: "account_query_default", argNames: []);
 
   @override
-  Future<String> crateApiEndfieldFindLoginCachePath() {
+  Future<AccountSyncPolicy> crateApiEndfieldAccountSyncPolicyDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -154,21 +358,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_account_sync_policy,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiEndfieldFindLoginCachePathConstMeta,
+        constMeta: kCrateApiEndfieldAccountSyncPolicyDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEndfieldFindLoginCachePathConstMeta =>
-      const TaskConstMeta(debugName: "find_login_cache_path", argNames: []);
+  TaskConstMeta get kCrateApiEndfieldAccountSyncPolicyDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "account_sync_policy_default",
+        argNames: [],
+      );
 
   @override
-  Future<List<AccountInfo>> crateApiEndfieldGetAccountList() {
+  Future<AppSettings> crateApiSettingsAppSettingsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -181,44 +388,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_account_info,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_app_settings,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiEndfieldGetAccountListConstMeta,
+        constMeta: kCrateApiSettingsAppSettingsDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEndfieldGetAccountListConstMeta =>
-      const TaskConstMeta(debugName: "get_account_list", argNames: []);
+  TaskConstMeta get kCrateApiSettingsAppSettingsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "app_settings_default", argNames: []);
 
   @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
+  Future<AuditQuery> crateApiAuditAuditQueryDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 4,
+            port: port_,
+          );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
+          decodeSuccessData: sse_decode_audit_query,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiAuditAuditQueryDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiAuditAuditQueryDefaultConstMeta =>
+      const TaskConstMeta(debugName: "audit_query_default", argNames: []);
 
   @override
-  Future<void> crateApiSimpleInitApp() {
+  Future<AutoSyncSettings> crateApiSettingsAutoSyncSettingsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -231,21 +442,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_auto_sync_settings,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
+        constMeta: kCrateApiSettingsAutoSyncSettingsDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiSettingsAutoSyncSettingsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "auto_sync_settings_default",
+        argNames: [],
+      );
 
   @override
-  Future<WebDavConfig> crateApiWebdavLoadWebdavConfig() {
+  Future<AutoSyncStatus> crateApiAutosyncAutoSyncStatusDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -258,21 +472,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_web_dav_config,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_auto_sync_status,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiWebdavLoadWebdavConfigConstMeta,
+        constMeta: kCrateApiAutosyncAutoSyncStatusDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebdavLoadWebdavConfigConstMeta =>
-      const TaskConstMeta(debugName: "load_webdav_config", argNames: []);
+  TaskConstMeta get kCrateApiAutosyncAutoSyncStatusDefaultConstMeta =>
+      const TaskConstMeta(debugName: "auto_sync_status_default", argNames: []);
 
   @override
-  Future<void> crateApiEndfieldSaveCurrentAccount({required String alias}) {
+  Future<AccountHealth> crateApiHealthCheckAccountHealth({
+    required String alias,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -286,29 +502,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_account_health,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiEndfieldSaveCurrentAccountConstMeta,
+        constMeta: kCrateApiHealthCheckAccountHealthConstMeta,
         argValues: [alias],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEndfieldSaveCurrentAccountConstMeta =>
+  TaskConstMeta get kCrateApiHealthCheckAccountHealthConstMeta =>
       const TaskConstMeta(
-        debugName: "save_current_account",
+        debugName: "check_account_health",
         argNames: ["alias"],
       );
 
   @override
-  Future<void> crateApiWebdavSaveWebdavConfig({required WebDavConfig config}) {
+  Future<List<AccountHealth>> crateApiHealthCheckAccountsHealth() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_web_dav_config(config, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -317,29 +532,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_account_health,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebdavSaveWebdavConfigConstMeta,
-        argValues: [config],
+        constMeta: kCrateApiHealthCheckAccountsHealthConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebdavSaveWebdavConfigConstMeta =>
-      const TaskConstMeta(
-        debugName: "save_webdav_config",
-        argNames: ["config"],
-      );
+  TaskConstMeta get kCrateApiHealthCheckAccountsHealthConstMeta =>
+      const TaskConstMeta(debugName: "check_accounts_health", argNames: []);
 
   @override
-  Future<void> crateApiEndfieldSwitchToAccount({required String alias}) {
+  Future<void> crateApiReportClearSyncHistory() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(alias, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -351,18 +562,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiEndfieldSwitchToAccountConstMeta,
-        argValues: [alias],
+        constMeta: kCrateApiReportClearSyncHistoryConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiEndfieldSwitchToAccountConstMeta =>
-      const TaskConstMeta(debugName: "switch_to_account", argNames: ["alias"]);
+  TaskConstMeta get kCrateApiReportClearSyncHistoryConstMeta =>
+      const TaskConstMeta(debugName: "clear_sync_history", argNames: []);
 
   @override
-  Future<void> crateApiWebdavSyncFromWebdav() {
+  Future<ControlServerSettings> crateApiSettingsControlServerSettingsDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -375,21 +586,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_control_server_settings,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiWebdavSyncFromWebdavConstMeta,
+        constMeta: kCrateApiSettingsControlServerSettingsDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebdavSyncFromWebdavConstMeta =>
-      const TaskConstMeta(debugName: "sync_from_webdav", argNames: []);
+  TaskConstMeta get kCrateApiSettingsControlServerSettingsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "control_server_settings_default",
+        argNames: [],
+      );
 
   @override
-  Future<void> crateApiWebdavSyncToWebdav() {
+  Future<ControlServerStatus> crateApiControlControlServerStatusDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -402,25 +616,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_AnyhowException,
+          decodeSuccessData: sse_decode_control_server_status,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiWebdavSyncToWebdavConstMeta,
+        constMeta: kCrateApiControlControlServerStatusDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebdavSyncToWebdavConstMeta =>
-      const TaskConstMeta(debugName: "sync_to_webdav", argNames: []);
+  TaskConstMeta get kCrateApiControlControlServerStatusDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "control_server_status_default",
+        argNames: [],
+      );
 
   @override
-  Future<void> crateApiWebdavSyncToWebdavWithManifest() {
+  Future<void> crateApiMetaCreateGroup({required String name}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -432,301 +650,4995 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiWebdavSyncToWebdavWithManifestConstMeta,
-        argValues: [],
+        constMeta: kCrateApiMetaCreateGroupConstMeta,
+        argValues: [name],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiWebdavSyncToWebdavWithManifestConstMeta =>
-      const TaskConstMeta(
-        debugName: "sync_to_webdav_with_manifest",
-        argNames: [],
-      );
-
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
+  TaskConstMeta get kCrateApiMetaCreateGroupConstMeta =>
+      const TaskConstMeta(debugName: "create_group", argNames: ["name"]);
 
-  @protected
-  AccountInfo dco_decode_account_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return AccountInfo(
-      alias: dco_decode_String(arr[0]),
-      updatedAt: dco_decode_i_64(arr[1]),
+  @override
+  AccountQuery crateApiSearchDefaultAccountQuery() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_account_query,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSearchDefaultAccountQueryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  WebDavConfig dco_decode_box_autoadd_web_dav_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_web_dav_config(raw);
-  }
+  TaskConstMeta get kCrateApiSearchDefaultAccountQueryConstMeta =>
+      const TaskConstMeta(debugName: "default_account_query", argNames: []);
 
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  @override
+  AppSettings crateApiSettingsDefaultSettings() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsDefaultSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  List<AccountInfo> dco_decode_list_account_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_account_info).toList();
-  }
+  TaskConstMeta get kCrateApiSettingsDefaultSettingsConstMeta =>
+      const TaskConstMeta(debugName: "default_settings", argNames: []);
 
-  @protected
+  @override
+  Future<void> crateApiEndfieldDeleteAccount({required String alias}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiEndfieldDeleteAccountConstMeta,
+        argValues: [alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndfieldDeleteAccountConstMeta =>
+      const TaskConstMeta(debugName: "delete_account", argNames: ["alias"]);
+
+  @override
+  Future<void> crateApiMetaDeleteGroup({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaDeleteGroupConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaDeleteGroupConstMeta =>
+      const TaskConstMeta(debugName: "delete_group", argNames: ["name"]);
+
+  @override
+  Future<int> crateApiBundleExportAccounts({
+    required List<String> aliases,
+    required String path,
+    String? passphrase,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(aliases, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(passphrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiBundleExportAccountsConstMeta,
+        argValues: [aliases, path, passphrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBundleExportAccountsConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_accounts",
+        argNames: ["aliases", "path", "passphrase"],
+      );
+
+  @override
+  Future<String> crateApiEndfieldFindLoginCachePath() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiEndfieldFindLoginCachePathConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndfieldFindLoginCachePathConstMeta =>
+      const TaskConstMeta(debugName: "find_login_cache_path", argNames: []);
+
+  @override
+  Future<List<AccountInfo>> crateApiEndfieldGetAccountList() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiEndfieldGetAccountListConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndfieldGetAccountListConstMeta =>
+      const TaskConstMeta(debugName: "get_account_list", argNames: []);
+
+  @override
+  Future<List<AccountGroup>> crateApiMetaGetAccountsByGroup() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account_group,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaGetAccountsByGroupConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaGetAccountsByGroupConstMeta =>
+      const TaskConstMeta(debugName: "get_accounts_by_group", argNames: []);
+
+  @override
+  Future<List<AccountInfo>> crateApiMetaGetAccountsByTag({
+    required String tag,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(tag, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaGetAccountsByTagConstMeta,
+        argValues: [tag],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaGetAccountsByTagConstMeta =>
+      const TaskConstMeta(debugName: "get_accounts_by_tag", argNames: ["tag"]);
+
+  @override
+  Future<AutoSyncStatus> crateApiAutosyncGetAutoSyncStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_auto_sync_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAutosyncGetAutoSyncStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAutosyncGetAutoSyncStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_auto_sync_status", argNames: []);
+
+  @override
+  Future<ControlServerStatus> crateApiControlGetControlServerStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_control_server_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiControlGetControlServerStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiControlGetControlServerStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_control_server_status", argNames: []);
+
+  @override
+  Future<String> crateApiControlGetControlToken() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiControlGetControlTokenConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiControlGetControlTokenConstMeta =>
+      const TaskConstMeta(debugName: "get_control_token", argNames: []);
+
+  @override
+  Future<String?> crateApiEndfieldGetCurrentAccount() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiEndfieldGetCurrentAccountConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndfieldGetCurrentAccountConstMeta =>
+      const TaskConstMeta(debugName: "get_current_account", argNames: []);
+
+  @override
+  Future<GameProcessStatus> crateApiLaunchGetGameProcessStatus({
+    required int pid,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_game_process_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchGetGameProcessStatusConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchGetGameProcessStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_game_process_status",
+        argNames: ["pid"],
+      );
+
+  @override
+  Future<InterruptedSync?> crateApiJournalGetInterruptedSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_interrupted_sync,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiJournalGetInterruptedSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJournalGetInterruptedSyncConstMeta =>
+      const TaskConstMeta(debugName: "get_interrupted_sync", argNames: []);
+
+  @override
+  Future<AppSettings> crateApiSettingsGetSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_settings,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsGetSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsGetSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_settings", argNames: []);
+
+  @override
+  Future<List<SyncReport>> crateApiReportGetSyncHistory() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiReportGetSyncHistoryConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiReportGetSyncHistoryConstMeta =>
+      const TaskConstMeta(debugName: "get_sync_history", argNames: []);
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<List<ImportedAccount>> crateApiBundleImportAccounts({
+    required String path,
+    String? passphrase,
+    required ImportConflictPolicy conflictPolicy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(passphrase, serializer);
+          sse_encode_import_conflict_policy(conflictPolicy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_imported_account,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiBundleImportAccountsConstMeta,
+        argValues: [path, passphrase, conflictPolicy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBundleImportAccountsConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_accounts",
+        argNames: ["path", "passphrase", "conflictPolicy"],
+      );
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Future<BundleInfo> crateApiBundleInspectBundle({
+    required String path,
+    String? passphrase,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          sse_encode_opt_String(passphrase, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bundle_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiBundleInspectBundleConstMeta,
+        argValues: [path, passphrase],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBundleInspectBundleConstMeta =>
+      const TaskConstMeta(
+        debugName: "inspect_bundle",
+        argNames: ["path", "passphrase"],
+      );
+
+  @override
+  Future<int> crateApiLaunchLaunchGame() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchLaunchGameConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchLaunchGameConstMeta =>
+      const TaskConstMeta(debugName: "launch_game", argNames: []);
+
+  @override
+  Future<List<GameProcessStatus>> crateApiLaunchListGameProcesses() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_game_process_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchListGameProcessesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchListGameProcessesConstMeta =>
+      const TaskConstMeta(debugName: "list_game_processes", argNames: []);
+
+  @override
+  Future<List<String>> crateApiMetaListGroups() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaListGroupsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaListGroupsConstMeta =>
+      const TaskConstMeta(debugName: "list_groups", argNames: []);
+
+  @override
+  Future<List<RemoteVersion>> crateApiHistoryListRemoteVersions({
+    String? remote,
+    required String alias,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(remote, serializer);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_remote_version,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHistoryListRemoteVersionsConstMeta,
+        argValues: [remote, alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHistoryListRemoteVersionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_remote_versions",
+        argNames: ["remote", "alias"],
+      );
+
+  @override
+  Future<List<SyncRemote>> crateApiSyncListRemotes() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_sync_remote,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncListRemotesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncListRemotesConstMeta =>
+      const TaskConstMeta(debugName: "list_remotes", argNames: []);
+
+  @override
+  Future<List<String>> crateApiMetaListTags() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaListTagsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaListTagsConstMeta =>
+      const TaskConstMeta(debugName: "list_tags", argNames: []);
+
+  @override
+  Future<LaunchConfig> crateApiLaunchLoadLaunchConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_launch_config,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchLoadLaunchConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchLoadLaunchConfigConstMeta =>
+      const TaskConstMeta(debugName: "load_launch_config", argNames: []);
+
+  @override
+  Future<WebDavConfig> crateApiWebdavLoadWebdavConfig() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_dav_config,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebdavLoadWebdavConfigConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavLoadWebdavConfigConstMeta =>
+      const TaskConstMeta(debugName: "load_webdav_config", argNames: []);
+
+  @override
+  Future<PathSettings> crateApiSettingsPathSettingsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_path_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsPathSettingsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsPathSettingsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "path_settings_default", argNames: []);
+
+  @override
+  Future<SyncPlan> crateApiSyncPlanSync({
+    required SyncOperation operation,
+    String? remote,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_sync_operation(operation, serializer);
+          sse_encode_opt_String(remote, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_plan,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncPlanSyncConstMeta,
+        argValues: [operation, remote],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncPlanSyncConstMeta => const TaskConstMeta(
+    debugName: "plan_sync",
+    argNames: ["operation", "remote"],
+  );
+
+  @override
+  Future<List<AuditEntry>> crateApiAuditQueryAuditLog({
+    required AuditQuery query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_audit_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_audit_entry,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAuditQueryAuditLogConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAuditQueryAuditLogConstMeta =>
+      const TaskConstMeta(debugName: "query_audit_log", argNames: ["query"]);
+
+  @override
+  Future<String> crateApiControlRegenerateControlToken() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiControlRegenerateControlTokenConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiControlRegenerateControlTokenConstMeta =>
+      const TaskConstMeta(debugName: "regenerate_control_token", argNames: []);
+
+  @override
+  Future<void> crateApiSyncRemoveRemote({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncRemoveRemoteConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncRemoveRemoteConstMeta =>
+      const TaskConstMeta(debugName: "remove_remote", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiMetaRenameGroup({
+    required String oldName,
+    required String newName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(oldName, serializer);
+          sse_encode_String(newName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaRenameGroupConstMeta,
+        argValues: [oldName, newName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaRenameGroupConstMeta => const TaskConstMeta(
+    debugName: "rename_group",
+    argNames: ["oldName", "newName"],
+  );
+
+  @override
+  Future<void> crateApiMetaReorderAccounts({required List<String> aliases}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(aliases, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaReorderAccountsConstMeta,
+        argValues: [aliases],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaReorderAccountsConstMeta =>
+      const TaskConstMeta(debugName: "reorder_accounts", argNames: ["aliases"]);
+
+  @override
+  Future<void> crateApiMetaReorderGroups({required List<String> names}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(names, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaReorderGroupsConstMeta,
+        argValues: [names],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaReorderGroupsConstMeta =>
+      const TaskConstMeta(debugName: "reorder_groups", argNames: ["names"]);
+
+  @override
+  Future<void> crateApiSettingsResetSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsResetSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsResetSettingsConstMeta =>
+      const TaskConstMeta(debugName: "reset_settings", argNames: []);
+
+  @override
+  Future<bool> crateApiAutosyncRestartAutoSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAutosyncRestartAutoSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAutosyncRestartAutoSyncConstMeta =>
+      const TaskConstMeta(debugName: "restart_auto_sync", argNames: []);
+
+  @override
+  Future<void> crateApiHistoryRestoreRemoteVersion({
+    String? remote,
+    required String alias,
+    required String hash,
+    required bool applyLocally,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(remote, serializer);
+          sse_encode_String(alias, serializer);
+          sse_encode_String(hash, serializer);
+          sse_encode_bool(applyLocally, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiHistoryRestoreRemoteVersionConstMeta,
+        argValues: [remote, alias, hash, applyLocally],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiHistoryRestoreRemoteVersionConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_remote_version",
+        argNames: ["remote", "alias", "hash", "applyLocally"],
+      );
+
+  @override
+  Future<SyncReport> crateApiJournalResumeInterruptedSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiJournalResumeInterruptedSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJournalResumeInterruptedSyncConstMeta =>
+      const TaskConstMeta(debugName: "resume_interrupted_sync", argNames: []);
+
+  @override
+  Future<List<String>> crateApiJournalRollbackInterruptedSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiJournalRollbackInterruptedSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiJournalRollbackInterruptedSyncConstMeta =>
+      const TaskConstMeta(debugName: "rollback_interrupted_sync", argNames: []);
+
+  @override
+  Future<List<HookRun>> crateApiEndfieldSaveCurrentAccount({
+    required String alias,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_hook_run,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiEndfieldSaveCurrentAccountConstMeta,
+        argValues: [alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndfieldSaveCurrentAccountConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_current_account",
+        argNames: ["alias"],
+      );
+
+  @override
+  Future<void> crateApiLaunchSaveLaunchConfig({required LaunchConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_launch_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchSaveLaunchConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchSaveLaunchConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_launch_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<void> crateApiSyncSaveRemote({required SyncRemote remote}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_sync_remote(remote, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncSaveRemoteConstMeta,
+        argValues: [remote],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncSaveRemoteConstMeta =>
+      const TaskConstMeta(debugName: "save_remote", argNames: ["remote"]);
+
+  @override
+  Future<void> crateApiWebdavSaveWebdavConfig({required WebDavConfig config}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_web_dav_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebdavSaveWebdavConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavSaveWebdavConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_webdav_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<List<AccountInfo>> crateApiSearchSearchAccounts({
+    required AccountQuery query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_account_query(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_account_info,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSearchSearchAccountsConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSearchSearchAccountsConstMeta =>
+      const TaskConstMeta(debugName: "search_accounts", argNames: ["query"]);
+
+  @override
+  Future<void> crateApiMetaSetAccountGroup({
+    required String alias,
+    String? group,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          sse_encode_opt_String(group, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaSetAccountGroupConstMeta,
+        argValues: [alias, group],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaSetAccountGroupConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_account_group",
+        argNames: ["alias", "group"],
+      );
+
+  @override
+  Future<void> crateApiMetaSetAccountNotes({
+    required String alias,
+    String? notes,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          sse_encode_opt_String(notes, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaSetAccountNotesConstMeta,
+        argValues: [alias, notes],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaSetAccountNotesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_account_notes",
+        argNames: ["alias", "notes"],
+      );
+
+  @override
+  Future<void> crateApiMetaSetAccountSyncPolicy({
+    required String alias,
+    required AccountSyncPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          sse_encode_account_sync_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaSetAccountSyncPolicyConstMeta,
+        argValues: [alias, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaSetAccountSyncPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_account_sync_policy",
+        argNames: ["alias", "policy"],
+      );
+
+  @override
+  Future<void> crateApiMetaSetAccountTags({
+    required String alias,
+    required List<String> tags,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          sse_encode_list_String(tags, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiMetaSetAccountTagsConstMeta,
+        argValues: [alias, tags],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiMetaSetAccountTagsConstMeta => const TaskConstMeta(
+    debugName: "set_account_tags",
+    argNames: ["alias", "tags"],
+  );
+
+  @override
+  Future<bool> crateApiAutosyncStartAutoSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAutosyncStartAutoSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAutosyncStartAutoSyncConstMeta =>
+      const TaskConstMeta(debugName: "start_auto_sync", argNames: []);
+
+  @override
+  Future<ControlServerStatus> crateApiControlStartControlServer() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_control_server_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiControlStartControlServerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiControlStartControlServerConstMeta =>
+      const TaskConstMeta(debugName: "start_control_server", argNames: []);
+
+  @override
+  Future<void> crateApiAutosyncStopAutoSync() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAutosyncStopAutoSyncConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAutosyncStopAutoSyncConstMeta =>
+      const TaskConstMeta(debugName: "stop_auto_sync", argNames: []);
+
+  @override
+  Future<void> crateApiControlStopControlServer() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiControlStopControlServerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiControlStopControlServerConstMeta =>
+      const TaskConstMeta(debugName: "stop_control_server", argNames: []);
+
+  @override
+  Future<int> crateApiLaunchSwitchAndLaunch({required String alias}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchSwitchAndLaunchConstMeta,
+        argValues: [alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchSwitchAndLaunchConstMeta =>
+      const TaskConstMeta(debugName: "switch_and_launch", argNames: ["alias"]);
+
+  @override
+  Future<List<HookRun>> crateApiEndfieldSwitchToAccount({
+    required String alias,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(alias, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_hook_run,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiEndfieldSwitchToAccountConstMeta,
+        argValues: [alias],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiEndfieldSwitchToAccountConstMeta =>
+      const TaskConstMeta(debugName: "switch_to_account", argNames: ["alias"]);
+
+  @override
+  Future<SyncDirection> crateApiSettingsSyncDirectionDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_direction,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsSyncDirectionDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsSyncDirectionDefaultConstMeta =>
+      const TaskConstMeta(debugName: "sync_direction_default", argNames: []);
+
+  @override
+  Future<SyncReport> crateApiWebdavSyncFromWebdav() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebdavSyncFromWebdavConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavSyncFromWebdavConstMeta =>
+      const TaskConstMeta(debugName: "sync_from_webdav", argNames: []);
+
+  @override
+  Future<SyncReport> crateApiSyncSyncPull({String? remote}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(remote, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncSyncPullConstMeta,
+        argValues: [remote],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncSyncPullConstMeta =>
+      const TaskConstMeta(debugName: "sync_pull", argNames: ["remote"]);
+
+  @override
+  Future<SyncReport> crateApiSyncSyncPush({String? remote}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(remote, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncSyncPushConstMeta,
+        argValues: [remote],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncSyncPushConstMeta =>
+      const TaskConstMeta(debugName: "sync_push", argNames: ["remote"]);
+
+  @override
+  Future<SyncReport> crateApiSyncSyncPushWithManifest({String? remote}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(remote, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSyncSyncPushWithManifestConstMeta,
+        argValues: [remote],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSyncSyncPushWithManifestConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_push_with_manifest",
+        argNames: ["remote"],
+      );
+
+  @override
+  Future<SyncReport> crateApiWebdavSyncToWebdav() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebdavSyncToWebdavConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavSyncToWebdavConstMeta =>
+      const TaskConstMeta(debugName: "sync_to_webdav", argNames: []);
+
+  @override
+  Future<SyncReport> crateApiWebdavSyncToWebdavWithManifest() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiWebdavSyncToWebdavWithManifestConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiWebdavSyncToWebdavWithManifestConstMeta =>
+      const TaskConstMeta(
+        debugName: "sync_to_webdav_with_manifest",
+        argNames: [],
+      );
+
+  @override
+  Future<ThemeMode> crateApiSettingsThemeModeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_theme_mode,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsThemeModeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsThemeModeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "theme_mode_default", argNames: []);
+
+  @override
+  Future<ThemeSettings> crateApiSettingsThemeSettingsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_theme_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSettingsThemeSettingsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsThemeSettingsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "theme_settings_default", argNames: []);
+
+  @override
+  Future<void> crateApiAutosyncTriggerAutoSyncNow() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiAutosyncTriggerAutoSyncNowConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiAutosyncTriggerAutoSyncNowConstMeta =>
+      const TaskConstMeta(debugName: "trigger_auto_sync_now", argNames: []);
+
+  @override
+  Future<void> crateApiSettingsUpdateSettings({required AppSettings settings}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_app_settings(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSettingsUpdateSettingsConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSettingsUpdateSettingsConstMeta =>
+      const TaskConstMeta(debugName: "update_settings", argNames: ["settings"]);
+
+  @override
+  Future<GameProcessStatus> crateApiLaunchWaitGameExit({required int pid}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(pid, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_game_process_status,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiLaunchWaitGameExitConstMeta,
+        argValues: [pid],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiLaunchWaitGameExitConstMeta =>
+      const TaskConstMeta(debugName: "wait_game_exit", argNames: ["pid"]);
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  AccountGroup dco_decode_account_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AccountGroup(
+      name: dco_decode_opt_String(arr[0]),
+      accounts: dco_decode_list_account_info(arr[1]),
+    );
+  }
+
+  @protected
+  AccountHealth dco_decode_account_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AccountHealth(
+      alias: dco_decode_String(arr[0]),
+      status: dco_decode_health_status(arr[1]),
+      size: dco_decode_u_64(arr[2]),
+      ageSecs: dco_decode_i_64(arr[3]),
+      expiresAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      issues: dco_decode_list_String(arr[5]),
+    );
+  }
+
+  @protected
+  AccountInfo dco_decode_account_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return AccountInfo(
+      alias: dco_decode_String(arr[0]),
+      updatedAt: dco_decode_i_64(arr[1]),
+      group: dco_decode_opt_String(arr[2]),
+      tags: dco_decode_list_String(arr[3]),
+      notes: dco_decode_opt_String(arr[4]),
+      lastUsedAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      syncStatus: dco_decode_sync_status(arr[6]),
+      syncPolicy: dco_decode_account_sync_policy(arr[7]),
+      health: dco_decode_health_status(arr[8]),
+    );
+  }
+
+  @protected
+  AccountQuery dco_decode_account_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return AccountQuery(
+      text: dco_decode_opt_String(arr[0]),
+      fuzzy: dco_decode_bool(arr[1]),
+      tags: dco_decode_list_String(arr[2]),
+      group: dco_decode_opt_String(arr[3]),
+      usedAfter: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      usedBefore: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      stale: dco_decode_opt_box_autoadd_bool(arr[6]),
+      syncStatus: dco_decode_opt_box_autoadd_sync_status(arr[7]),
+      sortBy: dco_decode_account_sort_key(arr[8]),
+      descending: dco_decode_bool(arr[9]),
+    );
+  }
+
+  @protected
+  AccountSortKey dco_decode_account_sort_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AccountSortKey.values[raw as int];
+  }
+
+  @protected
+  AccountSyncPolicy dco_decode_account_sync_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AccountSyncPolicy.values[raw as int];
+  }
+
+  @protected
+  AppSettings dco_decode_app_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return AppSettings(
+      schemaVersion: dco_decode_u_32(arr[0]),
+      paths: dco_decode_path_settings(arr[1]),
+      remotes: dco_decode_list_sync_remote(arr[2]),
+      backupDepth: dco_decode_u_32(arr[3]),
+      remoteHistoryDepth: dco_decode_u_32(arr[4]),
+      transferConcurrency: dco_decode_u_32(arr[5]),
+      lockTimeoutSecs: dco_decode_u_32(arr[6]),
+      launch: dco_decode_opt_box_autoadd_launch_config(arr[7]),
+      autoSync: dco_decode_auto_sync_settings(arr[8]),
+      controlServer: dco_decode_control_server_settings(arr[9]),
+      hooks: dco_decode_list_hook_config(arr[10]),
+      theme: dco_decode_theme_settings(arr[11]),
+    );
+  }
+
+  @protected
+  AuditEntry dco_decode_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return AuditEntry(
+      timestamp: dco_decode_i_64(arr[0]),
+      operation: dco_decode_audit_operation(arr[1]),
+      alias: dco_decode_opt_String(arr[2]),
+      source: dco_decode_opt_String(arr[3]),
+      target: dco_decode_opt_String(arr[4]),
+      success: dco_decode_bool(arr[5]),
+      error: dco_decode_opt_String(arr[6]),
+      device: dco_decode_String(arr[7]),
+    );
+  }
+
+  @protected
+  AuditOperation dco_decode_audit_operation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AuditOperation.values[raw as int];
+  }
+
+  @protected
+  AuditQuery dco_decode_audit_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return AuditQuery(
+      alias: dco_decode_opt_String(arr[0]),
+      operation: dco_decode_opt_box_autoadd_audit_operation(arr[1]),
+      since: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      until: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      failuresOnly: dco_decode_bool(arr[4]),
+      limit: dco_decode_opt_box_autoadd_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  AutoSyncSettings dco_decode_auto_sync_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AutoSyncSettings(
+      enabled: dco_decode_bool(arr[0]),
+      intervalMinutes: dco_decode_u_32(arr[1]),
+      onChange: dco_decode_bool(arr[2]),
+      onStartup: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  AutoSyncStatus dco_decode_auto_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return AutoSyncStatus(
+      active: dco_decode_bool(arr[0]),
+      syncing: dco_decode_bool(arr[1]),
+      lastSuccessAt: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      lastError: dco_decode_opt_String(arr[3]),
+      lastErrorAt: dco_decode_opt_box_autoadd_i_64(arr[4]),
+      nextRunAt: dco_decode_opt_box_autoadd_i_64(arr[5]),
+      consecutiveFailures: dco_decode_u_32(arr[6]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AccountQuery dco_decode_box_autoadd_account_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_account_query(raw);
+  }
+
+  @protected
+  AppSettings dco_decode_box_autoadd_app_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_app_settings(raw);
+  }
+
+  @protected
+  AuditOperation dco_decode_box_autoadd_audit_operation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audit_operation(raw);
+  }
+
+  @protected
+  AuditQuery dco_decode_box_autoadd_audit_query(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_audit_query(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  GitConfig dco_decode_box_autoadd_git_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_git_config(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  InterruptedSync dco_decode_box_autoadd_interrupted_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_interrupted_sync(raw);
+  }
+
+  @protected
+  LaunchConfig dco_decode_box_autoadd_launch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_launch_config(raw);
+  }
+
+  @protected
+  LocalFolderConfig dco_decode_box_autoadd_local_folder_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_local_folder_config(raw);
+  }
+
+  @protected
+  S3Config dco_decode_box_autoadd_s_3_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_s_3_config(raw);
+  }
+
+  @protected
+  SftpConfig dco_decode_box_autoadd_sftp_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sftp_config(raw);
+  }
+
+  @protected
+  SyncRemote dco_decode_box_autoadd_sync_remote(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_remote(raw);
+  }
+
+  @protected
+  SyncStatus dco_decode_box_autoadd_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_status(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  WebDavConfig dco_decode_box_autoadd_web_dav_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_web_dav_config(raw);
+  }
+
+  @protected
+  BundleInfo dco_decode_bundle_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return BundleInfo(
+      version: dco_decode_u_8(arr[0]),
+      encrypted: dco_decode_bool(arr[1]),
+      createdAt: dco_decode_opt_box_autoadd_i_64(arr[2]),
+      aliases: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  ControlServerSettings dco_decode_control_server_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ControlServerSettings(
+      enabled: dco_decode_bool(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+    );
+  }
+
+  @protected
+  ControlServerStatus dco_decode_control_server_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ControlServerStatus(
+      running: dco_decode_bool(arr[0]),
+      port: dco_decode_opt_box_autoadd_u_16(arr[1]),
+    );
+  }
+
+  @protected
+  GameProcessStatus dco_decode_game_process_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GameProcessStatus(
+      pid: dco_decode_u_32(arr[0]),
+      running: dco_decode_bool(arr[1]),
+      exitCode: dco_decode_opt_box_autoadd_i_32(arr[2]),
+    );
+  }
+
+  @protected
+  GitConfig dco_decode_git_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return GitConfig(
+      path: dco_decode_String(arr[0]),
+      remoteUrl: dco_decode_opt_String(arr[1]),
+      branch: dco_decode_String(arr[2]),
+      passphrase: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  HealthStatus dco_decode_health_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HealthStatus.values[raw as int];
+  }
+
+  @protected
+  HookConfig dco_decode_hook_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return HookConfig(
+      event: dco_decode_hook_event(arr[0]),
+      command: dco_decode_String(arr[1]),
+      args: dco_decode_list_String(arr[2]),
+      timeoutSecs: dco_decode_u_32(arr[3]),
+      enabled: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  HookEvent dco_decode_hook_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return HookEvent.values[raw as int];
+  }
+
+  @protected
+  HookRun dco_decode_hook_run(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return HookRun(
+      event: dco_decode_hook_event(arr[0]),
+      command: dco_decode_String(arr[1]),
+      exitCode: dco_decode_opt_box_autoadd_i_32(arr[2]),
+      timedOut: dco_decode_bool(arr[3]),
+      stdout: dco_decode_String(arr[4]),
+      stderr: dco_decode_String(arr[5]),
+      error: dco_decode_opt_String(arr[6]),
+      durationMs: dco_decode_u_64(arr[7]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  ImportAction dco_decode_import_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportAction.values[raw as int];
+  }
+
+  @protected
+  ImportConflictPolicy dco_decode_import_conflict_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ImportConflictPolicy.values[raw as int];
+  }
+
+  @protected
+  ImportedAccount dco_decode_imported_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return ImportedAccount(
+      originalAlias: dco_decode_String(arr[0]),
+      alias: dco_decode_String(arr[1]),
+      action: dco_decode_import_action(arr[2]),
+    );
+  }
+
+  @protected
+  InterruptedSync dco_decode_interrupted_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return InterruptedSync(
+      startedAt: dco_decode_i_64(arr[0]),
+      remotes: dco_decode_list_String(arr[1]),
+      completed: dco_decode_list_String(arr[2]),
+      pending: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  LaunchConfig dco_decode_launch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return LaunchConfig(
+      executable: dco_decode_String(arr[0]),
+      args: dco_decode_list_String(arr[1]),
+      workingDir: dco_decode_opt_String(arr[2]),
+      wrapper: dco_decode_opt_String(arr[3]),
+      wrapperArgs: dco_decode_list_String(arr[4]),
+      env: dco_decode_list_launch_env_var(arr[5]),
+    );
+  }
+
+  @protected
+  LaunchEnvVar dco_decode_launch_env_var(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return LaunchEnvVar(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AccountGroup> dco_decode_list_account_group(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_account_group).toList();
+  }
+
+  @protected
+  List<AccountHealth> dco_decode_list_account_health(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_account_health).toList();
+  }
+
+  @protected
+  List<AccountInfo> dco_decode_list_account_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_account_info).toList();
+  }
+
+  @protected
+  List<AuditEntry> dco_decode_list_audit_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_audit_entry).toList();
+  }
+
+  @protected
+  List<GameProcessStatus> dco_decode_list_game_process_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_game_process_status).toList();
+  }
+
+  @protected
+  List<HookConfig> dco_decode_list_hook_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_hook_config).toList();
+  }
+
+  @protected
+  List<HookRun> dco_decode_list_hook_run(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_hook_run).toList();
+  }
+
+  @protected
+  List<ImportedAccount> dco_decode_list_imported_account(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_imported_account).toList();
+  }
+
+  @protected
+  List<LaunchEnvVar> dco_decode_list_launch_env_var(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_launch_env_var).toList();
+  }
+
+  @protected
+  List<PlannedEntry> dco_decode_list_planned_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_planned_entry).toList();
+  }
+
+  @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<RemoteVersion> dco_decode_list_remote_version(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_remote_version).toList();
+  }
+
+  @protected
+  List<SyncEntry> dco_decode_list_sync_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sync_entry).toList();
+  }
+
+  @protected
+  List<SyncRemote> dco_decode_list_sync_remote(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sync_remote).toList();
+  }
+
+  @protected
+  List<SyncReport> dco_decode_list_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_sync_report).toList();
+  }
+
+  @protected
+  LocalFolderConfig dco_decode_local_folder_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return LocalFolderConfig(path: dco_decode_String(arr[0]));
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AuditOperation? dco_decode_opt_box_autoadd_audit_operation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_audit_operation(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_32(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  InterruptedSync? dco_decode_opt_box_autoadd_interrupted_sync(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_interrupted_sync(raw);
+  }
+
+  @protected
+  LaunchConfig? dco_decode_opt_box_autoadd_launch_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_launch_config(raw);
+  }
+
+  @protected
+  SyncStatus? dco_decode_opt_box_autoadd_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_sync_status(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_16(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  PathSettings dco_decode_path_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return PathSettings(gameDataDir: dco_decode_opt_String(arr[0]));
+  }
+
+  @protected
+  PlannedEntry dco_decode_planned_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PlannedEntry(
+      remote: dco_decode_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      action: dco_decode_sync_action(arr[2]),
+      reason: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  RemoteBackend dco_decode_remote_backend(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return RemoteBackend_WebDav(
+          dco_decode_box_autoadd_web_dav_config(raw[1]),
+        );
+      case 1:
+        return RemoteBackend_Folder(
+          dco_decode_box_autoadd_local_folder_config(raw[1]),
+        );
+      case 2:
+        return RemoteBackend_S3(dco_decode_box_autoadd_s_3_config(raw[1]));
+      case 3:
+        return RemoteBackend_Git(dco_decode_box_autoadd_git_config(raw[1]));
+      case 4:
+        return RemoteBackend_Sftp(dco_decode_box_autoadd_sftp_config(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  RemoteVersion dco_decode_remote_version(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RemoteVersion(
+      hash: dco_decode_String(arr[0]),
+      size: dco_decode_u_64(arr[1]),
+      updatedAt: dco_decode_i_64(arr[2]),
+      uploadedAt: dco_decode_i_64(arr[3]),
+      current: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  S3Config dco_decode_s_3_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return S3Config(
+      endpoint: dco_decode_String(arr[0]),
+      region: dco_decode_String(arr[1]),
+      bucket: dco_decode_String(arr[2]),
+      accessKeyId: dco_decode_String(arr[3]),
+      secretAccessKey: dco_decode_String(arr[4]),
+      prefix: dco_decode_opt_String(arr[5]),
+      pathStyle: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  SftpConfig dco_decode_sftp_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SftpConfig(
+      host: dco_decode_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+      username: dco_decode_String(arr[2]),
+      password: dco_decode_opt_String(arr[3]),
+      privateKeyPath: dco_decode_opt_String(arr[4]),
+      passphrase: dco_decode_opt_String(arr[5]),
+      path: dco_decode_opt_String(arr[6]),
+      knownHostsPath: dco_decode_opt_String(arr[7]),
+      acceptNewHost: dco_decode_bool(arr[8]),
+    );
+  }
+
+  @protected
+  SyncAction dco_decode_sync_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncAction.values[raw as int];
+  }
+
+  @protected
+  SyncDirection dco_decode_sync_direction(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncDirection.values[raw as int];
+  }
+
+  @protected
+  SyncEntry dco_decode_sync_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SyncEntry(
+      remote: dco_decode_String(arr[0]),
+      name: dco_decode_opt_String(arr[1]),
+      action: dco_decode_sync_action(arr[2]),
+      reason: dco_decode_opt_String(arr[3]),
+      durationMs: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  SyncOperation dco_decode_sync_operation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncOperation.values[raw as int];
+  }
+
+  @protected
+  SyncPlan dco_decode_sync_plan(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SyncPlan(
+      operation: dco_decode_sync_operation(arr[0]),
+      entries: dco_decode_list_planned_entry(arr[1]),
+    );
+  }
+
+  @protected
+  SyncRemote dco_decode_sync_remote(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SyncRemote(
+      name: dco_decode_String(arr[0]),
+      direction: dco_decode_sync_direction(arr[1]),
+      backend: dco_decode_remote_backend(arr[2]),
+      include: dco_decode_list_String(arr[3]),
+      exclude: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  SyncReport dco_decode_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SyncReport(
+      operation: dco_decode_sync_operation(arr[0]),
+      startedAt: dco_decode_i_64(arr[1]),
+      finishedAt: dco_decode_i_64(arr[2]),
+      entries: dco_decode_list_sync_entry(arr[3]),
+    );
+  }
+
+  @protected
+  SyncStatus dco_decode_sync_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncStatus.values[raw as int];
+  }
+
+  @protected
+  ThemeMode dco_decode_theme_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ThemeMode.values[raw as int];
+  }
+
+  @protected
+  ThemeSettings dco_decode_theme_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ThemeSettings(
+      mode: dco_decode_theme_mode(arr[0]),
+      accentColor: dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  WebDavConfig dco_decode_web_dav_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WebDavConfig(
+      url: dco_decode_String(arr[0]),
+      username: dco_decode_String(arr[1]),
+      password: dco_decode_opt_String(arr[2]),
+      path: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  AccountGroup sse_decode_account_group(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_accounts = sse_decode_list_account_info(deserializer);
+    return AccountGroup(name: var_name, accounts: var_accounts);
+  }
+
+  @protected
+  AccountHealth sse_decode_account_health(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_alias = sse_decode_String(deserializer);
+    var var_status = sse_decode_health_status(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_ageSecs = sse_decode_i_64(deserializer);
+    var var_expiresAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_issues = sse_decode_list_String(deserializer);
+    return AccountHealth(
+      alias: var_alias,
+      status: var_status,
+      size: var_size,
+      ageSecs: var_ageSecs,
+      expiresAt: var_expiresAt,
+      issues: var_issues,
+    );
+  }
+
+  @protected
+  AccountInfo sse_decode_account_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_alias = sse_decode_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_group = sse_decode_opt_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_notes = sse_decode_opt_String(deserializer);
+    var var_lastUsedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_syncStatus = sse_decode_sync_status(deserializer);
+    var var_syncPolicy = sse_decode_account_sync_policy(deserializer);
+    var var_health = sse_decode_health_status(deserializer);
+    return AccountInfo(
+      alias: var_alias,
+      updatedAt: var_updatedAt,
+      group: var_group,
+      tags: var_tags,
+      notes: var_notes,
+      lastUsedAt: var_lastUsedAt,
+      syncStatus: var_syncStatus,
+      syncPolicy: var_syncPolicy,
+      health: var_health,
+    );
+  }
+
+  @protected
+  AccountQuery sse_decode_account_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_opt_String(deserializer);
+    var var_fuzzy = sse_decode_bool(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_group = sse_decode_opt_String(deserializer);
+    var var_usedAfter = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_usedBefore = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_stale = sse_decode_opt_box_autoadd_bool(deserializer);
+    var var_syncStatus = sse_decode_opt_box_autoadd_sync_status(deserializer);
+    var var_sortBy = sse_decode_account_sort_key(deserializer);
+    var var_descending = sse_decode_bool(deserializer);
+    return AccountQuery(
+      text: var_text,
+      fuzzy: var_fuzzy,
+      tags: var_tags,
+      group: var_group,
+      usedAfter: var_usedAfter,
+      usedBefore: var_usedBefore,
+      stale: var_stale,
+      syncStatus: var_syncStatus,
+      sortBy: var_sortBy,
+      descending: var_descending,
+    );
+  }
+
+  @protected
+  AccountSortKey sse_decode_account_sort_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AccountSortKey.values[inner];
+  }
+
+  @protected
+  AccountSyncPolicy sse_decode_account_sync_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AccountSyncPolicy.values[inner];
+  }
+
+  @protected
+  AppSettings sse_decode_app_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_schemaVersion = sse_decode_u_32(deserializer);
+    var var_paths = sse_decode_path_settings(deserializer);
+    var var_remotes = sse_decode_list_sync_remote(deserializer);
+    var var_backupDepth = sse_decode_u_32(deserializer);
+    var var_remoteHistoryDepth = sse_decode_u_32(deserializer);
+    var var_transferConcurrency = sse_decode_u_32(deserializer);
+    var var_lockTimeoutSecs = sse_decode_u_32(deserializer);
+    var var_launch = sse_decode_opt_box_autoadd_launch_config(deserializer);
+    var var_autoSync = sse_decode_auto_sync_settings(deserializer);
+    var var_controlServer = sse_decode_control_server_settings(deserializer);
+    var var_hooks = sse_decode_list_hook_config(deserializer);
+    var var_theme = sse_decode_theme_settings(deserializer);
+    return AppSettings(
+      schemaVersion: var_schemaVersion,
+      paths: var_paths,
+      remotes: var_remotes,
+      backupDepth: var_backupDepth,
+      remoteHistoryDepth: var_remoteHistoryDepth,
+      transferConcurrency: var_transferConcurrency,
+      lockTimeoutSecs: var_lockTimeoutSecs,
+      launch: var_launch,
+      autoSync: var_autoSync,
+      controlServer: var_controlServer,
+      hooks: var_hooks,
+      theme: var_theme,
+    );
+  }
+
+  @protected
+  AuditEntry sse_decode_audit_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_operation = sse_decode_audit_operation(deserializer);
+    var var_alias = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    var var_target = sse_decode_opt_String(deserializer);
+    var var_success = sse_decode_bool(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_device = sse_decode_String(deserializer);
+    return AuditEntry(
+      timestamp: var_timestamp,
+      operation: var_operation,
+      alias: var_alias,
+      source: var_source,
+      target: var_target,
+      success: var_success,
+      error: var_error,
+      device: var_device,
+    );
+  }
+
+  @protected
+  AuditOperation sse_decode_audit_operation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AuditOperation.values[inner];
+  }
+
+  @protected
+  AuditQuery sse_decode_audit_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_alias = sse_decode_opt_String(deserializer);
+    var var_operation = sse_decode_opt_box_autoadd_audit_operation(
+      deserializer,
+    );
+    var var_since = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_until = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_failuresOnly = sse_decode_bool(deserializer);
+    var var_limit = sse_decode_opt_box_autoadd_u_32(deserializer);
+    return AuditQuery(
+      alias: var_alias,
+      operation: var_operation,
+      since: var_since,
+      until: var_until,
+      failuresOnly: var_failuresOnly,
+      limit: var_limit,
+    );
+  }
+
+  @protected
+  AutoSyncSettings sse_decode_auto_sync_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_intervalMinutes = sse_decode_u_32(deserializer);
+    var var_onChange = sse_decode_bool(deserializer);
+    var var_onStartup = sse_decode_bool(deserializer);
+    return AutoSyncSettings(
+      enabled: var_enabled,
+      intervalMinutes: var_intervalMinutes,
+      onChange: var_onChange,
+      onStartup: var_onStartup,
+    );
+  }
+
+  @protected
+  AutoSyncStatus sse_decode_auto_sync_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_active = sse_decode_bool(deserializer);
+    var var_syncing = sse_decode_bool(deserializer);
+    var var_lastSuccessAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    var var_lastErrorAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_nextRunAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_consecutiveFailures = sse_decode_u_32(deserializer);
+    return AutoSyncStatus(
+      active: var_active,
+      syncing: var_syncing,
+      lastSuccessAt: var_lastSuccessAt,
+      lastError: var_lastError,
+      lastErrorAt: var_lastErrorAt,
+      nextRunAt: var_nextRunAt,
+      consecutiveFailures: var_consecutiveFailures,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AccountQuery sse_decode_box_autoadd_account_query(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_account_query(deserializer));
+  }
+
+  @protected
+  AppSettings sse_decode_box_autoadd_app_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_app_settings(deserializer));
+  }
+
+  @protected
+  AuditOperation sse_decode_box_autoadd_audit_operation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audit_operation(deserializer));
+  }
+
+  @protected
+  AuditQuery sse_decode_box_autoadd_audit_query(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_audit_query(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  GitConfig sse_decode_box_autoadd_git_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_git_config(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_32(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  InterruptedSync sse_decode_box_autoadd_interrupted_sync(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_interrupted_sync(deserializer));
+  }
+
+  @protected
+  LaunchConfig sse_decode_box_autoadd_launch_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_launch_config(deserializer));
+  }
+
+  @protected
+  LocalFolderConfig sse_decode_box_autoadd_local_folder_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_local_folder_config(deserializer));
+  }
+
+  @protected
+  S3Config sse_decode_box_autoadd_s_3_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_s_3_config(deserializer));
+  }
+
+  @protected
+  SftpConfig sse_decode_box_autoadd_sftp_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sftp_config(deserializer));
+  }
+
+  @protected
+  SyncRemote sse_decode_box_autoadd_sync_remote(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_remote(deserializer));
+  }
+
+  @protected
+  SyncStatus sse_decode_box_autoadd_sync_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_status(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_16(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  WebDavConfig sse_decode_box_autoadd_web_dav_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_web_dav_config(deserializer));
+  }
+
+  @protected
+  BundleInfo sse_decode_bundle_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_version = sse_decode_u_8(deserializer);
+    var var_encrypted = sse_decode_bool(deserializer);
+    var var_createdAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_aliases = sse_decode_list_String(deserializer);
+    return BundleInfo(
+      version: var_version,
+      encrypted: var_encrypted,
+      createdAt: var_createdAt,
+      aliases: var_aliases,
+    );
+  }
+
+  @protected
+  ControlServerSettings sse_decode_control_server_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    return ControlServerSettings(enabled: var_enabled, port: var_port);
+  }
+
+  @protected
+  ControlServerStatus sse_decode_control_server_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_running = sse_decode_bool(deserializer);
+    var var_port = sse_decode_opt_box_autoadd_u_16(deserializer);
+    return ControlServerStatus(running: var_running, port: var_port);
+  }
+
+  @protected
+  GameProcessStatus sse_decode_game_process_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_running = sse_decode_bool(deserializer);
+    var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+    return GameProcessStatus(
+      pid: var_pid,
+      running: var_running,
+      exitCode: var_exitCode,
+    );
+  }
+
+  @protected
+  GitConfig sse_decode_git_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    var var_remoteUrl = sse_decode_opt_String(deserializer);
+    var var_branch = sse_decode_String(deserializer);
+    var var_passphrase = sse_decode_String(deserializer);
+    return GitConfig(
+      path: var_path,
+      remoteUrl: var_remoteUrl,
+      branch: var_branch,
+      passphrase: var_passphrase,
+    );
+  }
+
+  @protected
+  HealthStatus sse_decode_health_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HealthStatus.values[inner];
+  }
+
+  @protected
+  HookConfig sse_decode_hook_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_event = sse_decode_hook_event(deserializer);
+    var var_command = sse_decode_String(deserializer);
+    var var_args = sse_decode_list_String(deserializer);
+    var var_timeoutSecs = sse_decode_u_32(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    return HookConfig(
+      event: var_event,
+      command: var_command,
+      args: var_args,
+      timeoutSecs: var_timeoutSecs,
+      enabled: var_enabled,
+    );
+  }
+
+  @protected
+  HookEvent sse_decode_hook_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return HookEvent.values[inner];
+  }
+
+  @protected
+  HookRun sse_decode_hook_run(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_event = sse_decode_hook_event(deserializer);
+    var var_command = sse_decode_String(deserializer);
+    var var_exitCode = sse_decode_opt_box_autoadd_i_32(deserializer);
+    var var_timedOut = sse_decode_bool(deserializer);
+    var var_stdout = sse_decode_String(deserializer);
+    var var_stderr = sse_decode_String(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    return HookRun(
+      event: var_event,
+      command: var_command,
+      exitCode: var_exitCode,
+      timedOut: var_timedOut,
+      stdout: var_stdout,
+      stderr: var_stderr,
+      error: var_error,
+      durationMs: var_durationMs,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  ImportAction sse_decode_import_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportAction.values[inner];
+  }
+
+  @protected
+  ImportConflictPolicy sse_decode_import_conflict_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ImportConflictPolicy.values[inner];
+  }
+
+  @protected
+  ImportedAccount sse_decode_imported_account(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_originalAlias = sse_decode_String(deserializer);
+    var var_alias = sse_decode_String(deserializer);
+    var var_action = sse_decode_import_action(deserializer);
+    return ImportedAccount(
+      originalAlias: var_originalAlias,
+      alias: var_alias,
+      action: var_action,
+    );
+  }
+
+  @protected
+  InterruptedSync sse_decode_interrupted_sync(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_remotes = sse_decode_list_String(deserializer);
+    var var_completed = sse_decode_list_String(deserializer);
+    var var_pending = sse_decode_list_String(deserializer);
+    return InterruptedSync(
+      startedAt: var_startedAt,
+      remotes: var_remotes,
+      completed: var_completed,
+      pending: var_pending,
+    );
+  }
+
+  @protected
+  LaunchConfig sse_decode_launch_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_executable = sse_decode_String(deserializer);
+    var var_args = sse_decode_list_String(deserializer);
+    var var_workingDir = sse_decode_opt_String(deserializer);
+    var var_wrapper = sse_decode_opt_String(deserializer);
+    var var_wrapperArgs = sse_decode_list_String(deserializer);
+    var var_env = sse_decode_list_launch_env_var(deserializer);
+    return LaunchConfig(
+      executable: var_executable,
+      args: var_args,
+      workingDir: var_workingDir,
+      wrapper: var_wrapper,
+      wrapperArgs: var_wrapperArgs,
+      env: var_env,
+    );
+  }
+
+  @protected
+  LaunchEnvVar sse_decode_launch_env_var(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return LaunchEnvVar(key: var_key, value: var_value);
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccountGroup> sse_decode_list_account_group(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountGroup>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account_group(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccountHealth> sse_decode_list_account_health(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountHealth>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account_health(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AccountInfo> sse_decode_list_account_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AccountInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_account_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AuditEntry> sse_decode_list_audit_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AuditEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_audit_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GameProcessStatus> sse_decode_list_game_process_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GameProcessStatus>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_game_process_status(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HookConfig> sse_decode_list_hook_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HookConfig>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_hook_config(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<HookRun> sse_decode_list_hook_run(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <HookRun>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_hook_run(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ImportedAccount> sse_decode_list_imported_account(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ImportedAccount>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_imported_account(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LaunchEnvVar> sse_decode_list_launch_env_var(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LaunchEnvVar>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_launch_env_var(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PlannedEntry> sse_decode_list_planned_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PlannedEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_planned_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<RemoteVersion> sse_decode_list_remote_version(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RemoteVersion>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_remote_version(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SyncEntry> sse_decode_list_sync_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SyncEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sync_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SyncRemote> sse_decode_list_sync_remote(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SyncRemote>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sync_remote(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SyncReport> sse_decode_list_sync_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SyncReport>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_sync_report(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LocalFolderConfig sse_decode_local_folder_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_String(deserializer);
+    return LocalFolderConfig(path: var_path);
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AuditOperation? sse_decode_opt_box_autoadd_audit_operation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_audit_operation(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  InterruptedSync? sse_decode_opt_box_autoadd_interrupted_sync(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_interrupted_sync(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LaunchConfig? sse_decode_opt_box_autoadd_launch_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_launch_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SyncStatus? sse_decode_opt_box_autoadd_sync_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_sync_status(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_16(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PathSettings sse_decode_path_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_gameDataDir = sse_decode_opt_String(deserializer);
+    return PathSettings(gameDataDir: var_gameDataDir);
+  }
+
+  @protected
+  PlannedEntry sse_decode_planned_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_remote = sse_decode_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_action = sse_decode_sync_action(deserializer);
+    var var_reason = sse_decode_opt_String(deserializer);
+    return PlannedEntry(
+      remote: var_remote,
+      name: var_name,
+      action: var_action,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  RemoteBackend sse_decode_remote_backend(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_web_dav_config(deserializer);
+        return RemoteBackend_WebDav(var_field0);
+      case 1:
+        var var_field0 = sse_decode_box_autoadd_local_folder_config(
+          deserializer,
+        );
+        return RemoteBackend_Folder(var_field0);
+      case 2:
+        var var_field0 = sse_decode_box_autoadd_s_3_config(deserializer);
+        return RemoteBackend_S3(var_field0);
+      case 3:
+        var var_field0 = sse_decode_box_autoadd_git_config(deserializer);
+        return RemoteBackend_Git(var_field0);
+      case 4:
+        var var_field0 = sse_decode_box_autoadd_sftp_config(deserializer);
+        return RemoteBackend_Sftp(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  RemoteVersion sse_decode_remote_version(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_hash = sse_decode_String(deserializer);
+    var var_size = sse_decode_u_64(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_uploadedAt = sse_decode_i_64(deserializer);
+    var var_current = sse_decode_bool(deserializer);
+    return RemoteVersion(
+      hash: var_hash,
+      size: var_size,
+      updatedAt: var_updatedAt,
+      uploadedAt: var_uploadedAt,
+      current: var_current,
+    );
+  }
+
+  @protected
+  S3Config sse_decode_s_3_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_endpoint = sse_decode_String(deserializer);
+    var var_region = sse_decode_String(deserializer);
+    var var_bucket = sse_decode_String(deserializer);
+    var var_accessKeyId = sse_decode_String(deserializer);
+    var var_secretAccessKey = sse_decode_String(deserializer);
+    var var_prefix = sse_decode_opt_String(deserializer);
+    var var_pathStyle = sse_decode_bool(deserializer);
+    return S3Config(
+      endpoint: var_endpoint,
+      region: var_region,
+      bucket: var_bucket,
+      accessKeyId: var_accessKeyId,
+      secretAccessKey: var_secretAccessKey,
+      prefix: var_prefix,
+      pathStyle: var_pathStyle,
+    );
+  }
+
+  @protected
+  SftpConfig sse_decode_sftp_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_host = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_opt_String(deserializer);
+    var var_privateKeyPath = sse_decode_opt_String(deserializer);
+    var var_passphrase = sse_decode_opt_String(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_knownHostsPath = sse_decode_opt_String(deserializer);
+    var var_acceptNewHost = sse_decode_bool(deserializer);
+    return SftpConfig(
+      host: var_host,
+      port: var_port,
+      username: var_username,
+      password: var_password,
+      privateKeyPath: var_privateKeyPath,
+      passphrase: var_passphrase,
+      path: var_path,
+      knownHostsPath: var_knownHostsPath,
+      acceptNewHost: var_acceptNewHost,
+    );
+  }
+
+  @protected
+  SyncAction sse_decode_sync_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncAction.values[inner];
+  }
+
+  @protected
+  SyncDirection sse_decode_sync_direction(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncDirection.values[inner];
+  }
+
+  @protected
+  SyncEntry sse_decode_sync_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_remote = sse_decode_String(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_action = sse_decode_sync_action(deserializer);
+    var var_reason = sse_decode_opt_String(deserializer);
+    var var_durationMs = sse_decode_u_64(deserializer);
+    return SyncEntry(
+      remote: var_remote,
+      name: var_name,
+      action: var_action,
+      reason: var_reason,
+      durationMs: var_durationMs,
+    );
+  }
+
+  @protected
+  SyncOperation sse_decode_sync_operation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncOperation.values[inner];
+  }
+
+  @protected
+  SyncPlan sse_decode_sync_plan(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_operation = sse_decode_sync_operation(deserializer);
+    var var_entries = sse_decode_list_planned_entry(deserializer);
+    return SyncPlan(operation: var_operation, entries: var_entries);
+  }
+
+  @protected
+  SyncRemote sse_decode_sync_remote(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_direction = sse_decode_sync_direction(deserializer);
+    var var_backend = sse_decode_remote_backend(deserializer);
+    var var_include = sse_decode_list_String(deserializer);
+    var var_exclude = sse_decode_list_String(deserializer);
+    return SyncRemote(
+      name: var_name,
+      direction: var_direction,
+      backend: var_backend,
+      include: var_include,
+      exclude: var_exclude,
+    );
+  }
+
+  @protected
+  SyncReport sse_decode_sync_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_operation = sse_decode_sync_operation(deserializer);
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_finishedAt = sse_decode_i_64(deserializer);
+    var var_entries = sse_decode_list_sync_entry(deserializer);
+    return SyncReport(
+      operation: var_operation,
+      startedAt: var_startedAt,
+      finishedAt: var_finishedAt,
+      entries: var_entries,
+    );
+  }
+
+  @protected
+  SyncStatus sse_decode_sync_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncStatus.values[inner];
+  }
+
+  @protected
+  ThemeMode sse_decode_theme_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ThemeMode.values[inner];
+  }
+
+  @protected
+  ThemeSettings sse_decode_theme_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_mode = sse_decode_theme_mode(deserializer);
+    var var_accentColor = sse_decode_opt_String(deserializer);
+    return ThemeSettings(mode: var_mode, accentColor: var_accentColor);
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  WebDavConfig sse_decode_web_dav_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_opt_String(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    return WebDavConfig(
+      url: var_url,
+      username: var_username,
+      password: var_password,
+      path: var_path,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_account_group(AccountGroup self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_list_account_info(self.accounts, serializer);
+  }
+
+  @protected
+  void sse_encode_account_health(AccountHealth self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.alias, serializer);
+    sse_encode_health_status(self.status, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_i_64(self.ageSecs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.expiresAt, serializer);
+    sse_encode_list_String(self.issues, serializer);
+  }
+
+  @protected
+  void sse_encode_account_info(AccountInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.alias, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_opt_String(self.group, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.notes, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastUsedAt, serializer);
+    sse_encode_sync_status(self.syncStatus, serializer);
+    sse_encode_account_sync_policy(self.syncPolicy, serializer);
+    sse_encode_health_status(self.health, serializer);
+  }
+
+  @protected
+  void sse_encode_account_query(AccountQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.text, serializer);
+    sse_encode_bool(self.fuzzy, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.group, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.usedAfter, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.usedBefore, serializer);
+    sse_encode_opt_box_autoadd_bool(self.stale, serializer);
+    sse_encode_opt_box_autoadd_sync_status(self.syncStatus, serializer);
+    sse_encode_account_sort_key(self.sortBy, serializer);
+    sse_encode_bool(self.descending, serializer);
+  }
+
+  @protected
+  void sse_encode_account_sort_key(
+    AccountSortKey self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_account_sync_policy(
+    AccountSyncPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_app_settings(AppSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.schemaVersion, serializer);
+    sse_encode_path_settings(self.paths, serializer);
+    sse_encode_list_sync_remote(self.remotes, serializer);
+    sse_encode_u_32(self.backupDepth, serializer);
+    sse_encode_u_32(self.remoteHistoryDepth, serializer);
+    sse_encode_u_32(self.transferConcurrency, serializer);
+    sse_encode_u_32(self.lockTimeoutSecs, serializer);
+    sse_encode_opt_box_autoadd_launch_config(self.launch, serializer);
+    sse_encode_auto_sync_settings(self.autoSync, serializer);
+    sse_encode_control_server_settings(self.controlServer, serializer);
+    sse_encode_list_hook_config(self.hooks, serializer);
+    sse_encode_theme_settings(self.theme, serializer);
+  }
+
+  @protected
+  void sse_encode_audit_entry(AuditEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_audit_operation(self.operation, serializer);
+    sse_encode_opt_String(self.alias, serializer);
+    sse_encode_opt_String(self.source, serializer);
+    sse_encode_opt_String(self.target, serializer);
+    sse_encode_bool(self.success, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_String(self.device, serializer);
+  }
+
+  @protected
+  void sse_encode_audit_operation(
+    AuditOperation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_audit_query(AuditQuery self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.alias, serializer);
+    sse_encode_opt_box_autoadd_audit_operation(self.operation, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.since, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.until, serializer);
+    sse_encode_bool(self.failuresOnly, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.limit, serializer);
+  }
+
+  @protected
+  void sse_encode_auto_sync_settings(
+    AutoSyncSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.intervalMinutes, serializer);
+    sse_encode_bool(self.onChange, serializer);
+    sse_encode_bool(self.onStartup, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_auto_sync_status(
+    AutoSyncStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.active, serializer);
+    sse_encode_bool(self.syncing, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastSuccessAt, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastErrorAt, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.nextRunAt, serializer);
+    sse_encode_u_32(self.consecutiveFailures, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_box_autoadd_account_query(
+    AccountQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_account_query(self, serializer);
   }
 
   @protected
-  WebDavConfig dco_decode_web_dav_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return WebDavConfig(
-      url: dco_decode_String(arr[0]),
-      username: dco_decode_String(arr[1]),
-      password: dco_decode_opt_String(arr[2]),
-      path: dco_decode_opt_String(arr[3]),
-    );
+  void sse_encode_box_autoadd_app_settings(
+    AppSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audit_operation(
+    AuditOperation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audit_operation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_audit_query(
+    AuditQuery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_audit_query(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_git_config(
+    GitConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_git_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_interrupted_sync(
+    InterruptedSync self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_interrupted_sync(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_launch_config(
+    LaunchConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_launch_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_local_folder_config(
+    LocalFolderConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_local_folder_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_s_3_config(
+    S3Config self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_s_3_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sftp_config(
+    SftpConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sftp_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_remote(
+    SyncRemote self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_remote(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_status(
+    SyncStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_status(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_16(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_web_dav_config(
+    WebDavConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_web_dav_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_bundle_info(BundleInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_8(self.version, serializer);
+    sse_encode_bool(self.encrypted, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.createdAt, serializer);
+    sse_encode_list_String(self.aliases, serializer);
+  }
+
+  @protected
+  void sse_encode_control_server_settings(
+    ControlServerSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_16(self.port, serializer);
+  }
+
+  @protected
+  void sse_encode_control_server_status(
+    ControlServerStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.running, serializer);
+    sse_encode_opt_box_autoadd_u_16(self.port, serializer);
+  }
+
+  @protected
+  void sse_encode_game_process_status(
+    GameProcessStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_bool(self.running, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+  }
+
+  @protected
+  void sse_encode_git_config(GitConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.remoteUrl, serializer);
+    sse_encode_String(self.branch, serializer);
+    sse_encode_String(self.passphrase, serializer);
+  }
+
+  @protected
+  void sse_encode_health_status(HealthStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_hook_config(HookConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hook_event(self.event, serializer);
+    sse_encode_String(self.command, serializer);
+    sse_encode_list_String(self.args, serializer);
+    sse_encode_u_32(self.timeoutSecs, serializer);
+    sse_encode_bool(self.enabled, serializer);
+  }
+
+  @protected
+  void sse_encode_hook_event(HookEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_hook_run(HookRun self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_hook_event(self.event, serializer);
+    sse_encode_String(self.command, serializer);
+    sse_encode_opt_box_autoadd_i_32(self.exitCode, serializer);
+    sse_encode_bool(self.timedOut, serializer);
+    sse_encode_String(self.stdout, serializer);
+    sse_encode_String(self.stderr, serializer);
+    sse_encode_opt_String(self.error, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_import_action(ImportAction self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_import_conflict_policy(
+    ImportConflictPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_imported_account(
+    ImportedAccount self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.originalAlias, serializer);
+    sse_encode_String(self.alias, serializer);
+    sse_encode_import_action(self.action, serializer);
+  }
+
+  @protected
+  void sse_encode_interrupted_sync(
+    InterruptedSync self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_list_String(self.remotes, serializer);
+    sse_encode_list_String(self.completed, serializer);
+    sse_encode_list_String(self.pending, serializer);
+  }
+
+  @protected
+  void sse_encode_launch_config(LaunchConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.executable, serializer);
+    sse_encode_list_String(self.args, serializer);
+    sse_encode_opt_String(self.workingDir, serializer);
+    sse_encode_opt_String(self.wrapper, serializer);
+    sse_encode_list_String(self.wrapperArgs, serializer);
+    sse_encode_list_launch_env_var(self.env, serializer);
+  }
+
+  @protected
+  void sse_encode_launch_env_var(LaunchEnvVar self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_account_group(
+    List<AccountGroup> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account_group(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_account_health(
+    List<AccountHealth> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account_health(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_account_info(
+    List<AccountInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_account_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_audit_entry(
+    List<AuditEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_audit_entry(item, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_list_game_process_status(
+    List<GameProcessStatus> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_game_process_status(item, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_list_hook_config(
+    List<HookConfig> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_hook_config(item, serializer);
+    }
   }
 
   @protected
-  AccountInfo sse_decode_account_info(SseDeserializer deserializer) {
+  void sse_encode_list_hook_run(List<HookRun> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_alias = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_i_64(deserializer);
-    return AccountInfo(alias: var_alias, updatedAt: var_updatedAt);
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_hook_run(item, serializer);
+    }
   }
 
   @protected
-  WebDavConfig sse_decode_box_autoadd_web_dav_config(
-    SseDeserializer deserializer,
+  void sse_encode_list_imported_account(
+    List<ImportedAccount> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_web_dav_config(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_imported_account(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_list_launch_env_var(
+    List<LaunchEnvVar> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_launch_env_var(item, serializer);
+    }
   }
 
   @protected
-  List<AccountInfo> sse_decode_list_account_info(SseDeserializer deserializer) {
+  void sse_encode_list_planned_entry(
+    List<PlannedEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <AccountInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_account_info(deserializer));
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_planned_entry(item, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_list_remote_version(
+    List<RemoteVersion> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_remote_version(item, serializer);
     }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_list_sync_entry(
+    List<SyncEntry> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sync_entry(item, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_list_sync_remote(
+    List<SyncRemote> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sync_remote(item, serializer);
+    }
   }
 
   @protected
-  WebDavConfig sse_decode_web_dav_config(SseDeserializer deserializer) {
+  void sse_encode_list_sync_report(
+    List<SyncReport> self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_url = sse_decode_String(deserializer);
-    var var_username = sse_decode_String(deserializer);
-    var var_password = sse_decode_opt_String(deserializer);
-    var var_path = sse_decode_opt_String(deserializer);
-    return WebDavConfig(
-      url: var_url,
-      username: var_username,
-      password: var_password,
-      path: var_path,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_sync_report(item, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_local_folder_config(
+    LocalFolderConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    sse_encode_String(self.path, serializer);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_audit_operation(
+    AuditOperation? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_audit_operation(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_account_info(AccountInfo self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.alias, serializer);
-    sse_encode_i_64(self.updatedAt, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_web_dav_config(
-    WebDavConfig self,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_web_dav_config(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_interrupted_sync(
+    InterruptedSync? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_interrupted_sync(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_list_account_info(
-    List<AccountInfo> self,
+  void sse_encode_opt_box_autoadd_launch_config(
+    LaunchConfig? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_account_info(item, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_launch_config(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_opt_box_autoadd_sync_status(
+    SyncStatus? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_sync_status(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
     sse_encode_bool(self != null, serializer);
     if (self != null) {
-      sse_encode_String(self, serializer);
+      sse_encode_box_autoadd_u_16(self, serializer);
     }
   }
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
+  void sse_encode_path_settings(PathSettings self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.gameDataDir, serializer);
   }
 
   @protected
-  void sse_encode_web_dav_config(WebDavConfig self, SseSerializer serializer) {
+  void sse_encode_planned_entry(PlannedEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.remote, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_sync_action(self.action, serializer);
+    sse_encode_opt_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_remote_backend(RemoteBackend self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case RemoteBackend_WebDav(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_box_autoadd_web_dav_config(field0, serializer);
+      case RemoteBackend_Folder(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_box_autoadd_local_folder_config(field0, serializer);
+      case RemoteBackend_S3(field0: final field0):
+        sse_encode_i_32(2, serializer);
+        sse_encode_box_autoadd_s_3_config(field0, serializer);
+      case RemoteBackend_Git(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_box_autoadd_git_config(field0, serializer);
+      case RemoteBackend_Sftp(field0: final field0):
+        sse_encode_i_32(4, serializer);
+        sse_encode_box_autoadd_sftp_config(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_remote_version(RemoteVersion self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.hash, serializer);
+    sse_encode_u_64(self.size, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_i_64(self.uploadedAt, serializer);
+    sse_encode_bool(self.current, serializer);
+  }
+
+  @protected
+  void sse_encode_s_3_config(S3Config self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.endpoint, serializer);
+    sse_encode_String(self.region, serializer);
+    sse_encode_String(self.bucket, serializer);
+    sse_encode_String(self.accessKeyId, serializer);
+    sse_encode_String(self.secretAccessKey, serializer);
+    sse_encode_opt_String(self.prefix, serializer);
+    sse_encode_bool(self.pathStyle, serializer);
+  }
+
+  @protected
+  void sse_encode_sftp_config(SftpConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.host, serializer);
+    sse_encode_u_16(self.port, serializer);
     sse_encode_String(self.username, serializer);
     sse_encode_opt_String(self.password, serializer);
+    sse_encode_opt_String(self.privateKeyPath, serializer);
+    sse_encode_opt_String(self.passphrase, serializer);
     sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.knownHostsPath, serializer);
+    sse_encode_bool(self.acceptNewHost, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_sync_action(SyncAction self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_sync_direction(SyncDirection self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_entry(SyncEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.remote, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_sync_action(self.action, serializer);
+    sse_encode_opt_String(self.reason, serializer);
+    sse_encode_u_64(self.durationMs, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_operation(SyncOperation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_plan(SyncPlan self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_operation(self.operation, serializer);
+    sse_encode_list_planned_entry(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_remote(SyncRemote self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_sync_direction(self.direction, serializer);
+    sse_encode_remote_backend(self.backend, serializer);
+    sse_encode_list_String(self.include, serializer);
+    sse_encode_list_String(self.exclude, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_report(SyncReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_operation(self.operation, serializer);
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_i_64(self.finishedAt, serializer);
+    sse_encode_list_sync_entry(self.entries, serializer);
+  }
+
+  @protected
+  void sse_encode_sync_status(SyncStatus self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_theme_mode(ThemeMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_theme_settings(ThemeSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_theme_mode(self.mode, serializer);
+    sse_encode_opt_String(self.accentColor, serializer);
+  }
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self);
+  }
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  void sse_encode_web_dav_config(WebDavConfig self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_opt_String(self.password, serializer);
+    sse_encode_opt_String(self.path, serializer);
   }
 }
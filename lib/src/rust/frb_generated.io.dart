@@ -3,8 +3,26 @@
 
 // ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/audit.dart';
+import 'api/autosync.dart';
+import 'api/bundle.dart';
+import 'api/control.dart';
 import 'api/endfield.dart';
+import 'api/git.dart';
+import 'api/health.dart';
+import 'api/history.dart';
+import 'api/hooks.dart';
+import 'api/journal.dart';
+import 'api/launch.dart';
+import 'api/local_folder.dart';
+import 'api/meta.dart';
+import 'api/report.dart';
+import 'api/s3.dart';
+import 'api/search.dart';
+import 'api/settings.dart';
+import 'api/sftp.dart';
 import 'api/simple.dart';
+import 'api/sync.dart';
 import 'api/webdav.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -26,24 +44,288 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  AccountGroup dco_decode_account_group(dynamic raw);
+
+  @protected
+  AccountHealth dco_decode_account_health(dynamic raw);
+
   @protected
   AccountInfo dco_decode_account_info(dynamic raw);
 
+  @protected
+  AccountQuery dco_decode_account_query(dynamic raw);
+
+  @protected
+  AccountSortKey dco_decode_account_sort_key(dynamic raw);
+
+  @protected
+  AccountSyncPolicy dco_decode_account_sync_policy(dynamic raw);
+
+  @protected
+  AppSettings dco_decode_app_settings(dynamic raw);
+
+  @protected
+  AuditEntry dco_decode_audit_entry(dynamic raw);
+
+  @protected
+  AuditOperation dco_decode_audit_operation(dynamic raw);
+
+  @protected
+  AuditQuery dco_decode_audit_query(dynamic raw);
+
+  @protected
+  AutoSyncSettings dco_decode_auto_sync_settings(dynamic raw);
+
+  @protected
+  AutoSyncStatus dco_decode_auto_sync_status(dynamic raw);
+
+  @protected
+  bool dco_decode_bool(dynamic raw);
+
+  @protected
+  AccountQuery dco_decode_box_autoadd_account_query(dynamic raw);
+
+  @protected
+  AppSettings dco_decode_box_autoadd_app_settings(dynamic raw);
+
+  @protected
+  AuditOperation dco_decode_box_autoadd_audit_operation(dynamic raw);
+
+  @protected
+  AuditQuery dco_decode_box_autoadd_audit_query(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  GitConfig dco_decode_box_autoadd_git_config(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  InterruptedSync dco_decode_box_autoadd_interrupted_sync(dynamic raw);
+
+  @protected
+  LaunchConfig dco_decode_box_autoadd_launch_config(dynamic raw);
+
+  @protected
+  LocalFolderConfig dco_decode_box_autoadd_local_folder_config(dynamic raw);
+
+  @protected
+  S3Config dco_decode_box_autoadd_s_3_config(dynamic raw);
+
+  @protected
+  SftpConfig dco_decode_box_autoadd_sftp_config(dynamic raw);
+
+  @protected
+  SyncRemote dco_decode_box_autoadd_sync_remote(dynamic raw);
+
+  @protected
+  SyncStatus dco_decode_box_autoadd_sync_status(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
   @protected
   WebDavConfig dco_decode_box_autoadd_web_dav_config(dynamic raw);
 
+  @protected
+  BundleInfo dco_decode_bundle_info(dynamic raw);
+
+  @protected
+  ControlServerSettings dco_decode_control_server_settings(dynamic raw);
+
+  @protected
+  ControlServerStatus dco_decode_control_server_status(dynamic raw);
+
+  @protected
+  GameProcessStatus dco_decode_game_process_status(dynamic raw);
+
+  @protected
+  GitConfig dco_decode_git_config(dynamic raw);
+
+  @protected
+  HealthStatus dco_decode_health_status(dynamic raw);
+
+  @protected
+  HookConfig dco_decode_hook_config(dynamic raw);
+
+  @protected
+  HookEvent dco_decode_hook_event(dynamic raw);
+
+  @protected
+  HookRun dco_decode_hook_run(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
   @protected
   PlatformInt64 dco_decode_i_64(dynamic raw);
 
+  @protected
+  ImportAction dco_decode_import_action(dynamic raw);
+
+  @protected
+  ImportConflictPolicy dco_decode_import_conflict_policy(dynamic raw);
+
+  @protected
+  ImportedAccount dco_decode_imported_account(dynamic raw);
+
+  @protected
+  InterruptedSync dco_decode_interrupted_sync(dynamic raw);
+
+  @protected
+  LaunchConfig dco_decode_launch_config(dynamic raw);
+
+  @protected
+  LaunchEnvVar dco_decode_launch_env_var(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<AccountGroup> dco_decode_list_account_group(dynamic raw);
+
+  @protected
+  List<AccountHealth> dco_decode_list_account_health(dynamic raw);
+
   @protected
   List<AccountInfo> dco_decode_list_account_info(dynamic raw);
 
+  @protected
+  List<AuditEntry> dco_decode_list_audit_entry(dynamic raw);
+
+  @protected
+  List<GameProcessStatus> dco_decode_list_game_process_status(dynamic raw);
+
+  @protected
+  List<HookConfig> dco_decode_list_hook_config(dynamic raw);
+
+  @protected
+  List<HookRun> dco_decode_list_hook_run(dynamic raw);
+
+  @protected
+  List<ImportedAccount> dco_decode_list_imported_account(dynamic raw);
+
+  @protected
+  List<LaunchEnvVar> dco_decode_list_launch_env_var(dynamic raw);
+
+  @protected
+  List<PlannedEntry> dco_decode_list_planned_entry(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<RemoteVersion> dco_decode_list_remote_version(dynamic raw);
+
+  @protected
+  List<SyncEntry> dco_decode_list_sync_entry(dynamic raw);
+
+  @protected
+  List<SyncRemote> dco_decode_list_sync_remote(dynamic raw);
+
+  @protected
+  List<SyncReport> dco_decode_list_sync_report(dynamic raw);
+
+  @protected
+  LocalFolderConfig dco_decode_local_folder_config(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  AuditOperation? dco_decode_opt_box_autoadd_audit_operation(dynamic raw);
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  InterruptedSync? dco_decode_opt_box_autoadd_interrupted_sync(dynamic raw);
+
+  @protected
+  LaunchConfig? dco_decode_opt_box_autoadd_launch_config(dynamic raw);
+
+  @protected
+  SyncStatus? dco_decode_opt_box_autoadd_sync_status(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  PathSettings dco_decode_path_settings(dynamic raw);
+
+  @protected
+  PlannedEntry dco_decode_planned_entry(dynamic raw);
+
+  @protected
+  RemoteBackend dco_decode_remote_backend(dynamic raw);
+
+  @protected
+  RemoteVersion dco_decode_remote_version(dynamic raw);
+
+  @protected
+  S3Config dco_decode_s_3_config(dynamic raw);
+
+  @protected
+  SftpConfig dco_decode_sftp_config(dynamic raw);
+
+  @protected
+  SyncAction dco_decode_sync_action(dynamic raw);
+
+  @protected
+  SyncDirection dco_decode_sync_direction(dynamic raw);
+
+  @protected
+  SyncEntry dco_decode_sync_entry(dynamic raw);
+
+  @protected
+  SyncOperation dco_decode_sync_operation(dynamic raw);
+
+  @protected
+  SyncPlan dco_decode_sync_plan(dynamic raw);
+
+  @protected
+  SyncRemote dco_decode_sync_remote(dynamic raw);
+
+  @protected
+  SyncReport dco_decode_sync_report(dynamic raw);
+
+  @protected
+  SyncStatus dco_decode_sync_status(dynamic raw);
+
+  @protected
+  ThemeMode dco_decode_theme_mode(dynamic raw);
+
+  @protected
+  ThemeSettings dco_decode_theme_settings(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
@@ -59,91 +341,777 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  AccountGroup sse_decode_account_group(SseDeserializer deserializer);
+
+  @protected
+  AccountHealth sse_decode_account_health(SseDeserializer deserializer);
+
   @protected
   AccountInfo sse_decode_account_info(SseDeserializer deserializer);
 
   @protected
-  WebDavConfig sse_decode_box_autoadd_web_dav_config(
+  AccountQuery sse_decode_account_query(SseDeserializer deserializer);
+
+  @protected
+  AccountSortKey sse_decode_account_sort_key(SseDeserializer deserializer);
+
+  @protected
+  AccountSyncPolicy sse_decode_account_sync_policy(
     SseDeserializer deserializer,
   );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  AppSettings sse_decode_app_settings(SseDeserializer deserializer);
 
   @protected
-  List<AccountInfo> sse_decode_list_account_info(SseDeserializer deserializer);
+  AuditEntry sse_decode_audit_entry(SseDeserializer deserializer);
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  AuditOperation sse_decode_audit_operation(SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  AuditQuery sse_decode_audit_query(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  AutoSyncSettings sse_decode_auto_sync_settings(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  AutoSyncStatus sse_decode_auto_sync_status(SseDeserializer deserializer);
 
   @protected
-  WebDavConfig sse_decode_web_dav_config(SseDeserializer deserializer);
+  bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  AccountQuery sse_decode_box_autoadd_account_query(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  AppSettings sse_decode_box_autoadd_app_settings(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
+  AuditOperation sse_decode_box_autoadd_audit_operation(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  AuditQuery sse_decode_box_autoadd_audit_query(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_account_info(AccountInfo self, SseSerializer serializer);
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_box_autoadd_web_dav_config(
-    WebDavConfig self,
-    SseSerializer serializer,
+  GitConfig sse_decode_box_autoadd_git_config(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  InterruptedSync sse_decode_box_autoadd_interrupted_sync(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  LaunchConfig sse_decode_box_autoadd_launch_config(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_list_account_info(
-    List<AccountInfo> self,
-    SseSerializer serializer,
+  LocalFolderConfig sse_decode_box_autoadd_local_folder_config(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
+  S3Config sse_decode_box_autoadd_s_3_config(SseDeserializer deserializer);
+
+  @protected
+  SftpConfig sse_decode_box_autoadd_sftp_config(SseDeserializer deserializer);
+
+  @protected
+  SyncRemote sse_decode_box_autoadd_sync_remote(SseDeserializer deserializer);
+
+  @protected
+  SyncStatus sse_decode_box_autoadd_sync_status(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  WebDavConfig sse_decode_box_autoadd_web_dav_config(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  BundleInfo sse_decode_bundle_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  ControlServerSettings sse_decode_control_server_settings(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  ControlServerStatus sse_decode_control_server_status(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_web_dav_config(WebDavConfig self, SseSerializer serializer);
+  GameProcessStatus sse_decode_game_process_status(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  GitConfig sse_decode_git_config(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  HealthStatus sse_decode_health_status(SseDeserializer deserializer);
+
+  @protected
+  HookConfig sse_decode_hook_config(SseDeserializer deserializer);
+
+  @protected
+  HookEvent sse_decode_hook_event(SseDeserializer deserializer);
+
+  @protected
+  HookRun sse_decode_hook_run(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  ImportAction sse_decode_import_action(SseDeserializer deserializer);
+
+  @protected
+  ImportConflictPolicy sse_decode_import_conflict_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ImportedAccount sse_decode_imported_account(SseDeserializer deserializer);
+
+  @protected
+  InterruptedSync sse_decode_interrupted_sync(SseDeserializer deserializer);
+
+  @protected
+  LaunchConfig sse_decode_launch_config(SseDeserializer deserializer);
+
+  @protected
+  LaunchEnvVar sse_decode_launch_env_var(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AccountGroup> sse_decode_list_account_group(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AccountHealth> sse_decode_list_account_health(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AccountInfo> sse_decode_list_account_info(SseDeserializer deserializer);
+
+  @protected
+  List<AuditEntry> sse_decode_list_audit_entry(SseDeserializer deserializer);
+
+  @protected
+  List<GameProcessStatus> sse_decode_list_game_process_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<HookConfig> sse_decode_list_hook_config(SseDeserializer deserializer);
+
+  @protected
+  List<HookRun> sse_decode_list_hook_run(SseDeserializer deserializer);
+
+  @protected
+  List<ImportedAccount> sse_decode_list_imported_account(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LaunchEnvVar> sse_decode_list_launch_env_var(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<PlannedEntry> sse_decode_list_planned_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<RemoteVersion> sse_decode_list_remote_version(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SyncEntry> sse_decode_list_sync_entry(SseDeserializer deserializer);
+
+  @protected
+  List<SyncRemote> sse_decode_list_sync_remote(SseDeserializer deserializer);
+
+  @protected
+  List<SyncReport> sse_decode_list_sync_report(SseDeserializer deserializer);
+
+  @protected
+  LocalFolderConfig sse_decode_local_folder_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AuditOperation? sse_decode_opt_box_autoadd_audit_operation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  InterruptedSync? sse_decode_opt_box_autoadd_interrupted_sync(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LaunchConfig? sse_decode_opt_box_autoadd_launch_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncStatus? sse_decode_opt_box_autoadd_sync_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  PathSettings sse_decode_path_settings(SseDeserializer deserializer);
+
+  @protected
+  PlannedEntry sse_decode_planned_entry(SseDeserializer deserializer);
+
+  @protected
+  RemoteBackend sse_decode_remote_backend(SseDeserializer deserializer);
+
+  @protected
+  RemoteVersion sse_decode_remote_version(SseDeserializer deserializer);
+
+  @protected
+  S3Config sse_decode_s_3_config(SseDeserializer deserializer);
+
+  @protected
+  SftpConfig sse_decode_sftp_config(SseDeserializer deserializer);
+
+  @protected
+  SyncAction sse_decode_sync_action(SseDeserializer deserializer);
+
+  @protected
+  SyncDirection sse_decode_sync_direction(SseDeserializer deserializer);
+
+  @protected
+  SyncEntry sse_decode_sync_entry(SseDeserializer deserializer);
+
+  @protected
+  SyncOperation sse_decode_sync_operation(SseDeserializer deserializer);
+
+  @protected
+  SyncPlan sse_decode_sync_plan(SseDeserializer deserializer);
+
+  @protected
+  SyncRemote sse_decode_sync_remote(SseDeserializer deserializer);
+
+  @protected
+  SyncReport sse_decode_sync_report(SseDeserializer deserializer);
+
+  @protected
+  SyncStatus sse_decode_sync_status(SseDeserializer deserializer);
+
+  @protected
+  ThemeMode sse_decode_theme_mode(SseDeserializer deserializer);
+
+  @protected
+  ThemeSettings sse_decode_theme_settings(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  WebDavConfig sse_decode_web_dav_config(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_account_group(AccountGroup self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_account_health(AccountHealth self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_account_info(AccountInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_account_query(AccountQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_account_sort_key(
+    AccountSortKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_account_sync_policy(
+    AccountSyncPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_settings(AppSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audit_entry(AuditEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audit_operation(
+    AuditOperation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_audit_query(AuditQuery self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_auto_sync_settings(
+    AutoSyncSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_auto_sync_status(
+    AutoSyncStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_account_query(
+    AccountQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_app_settings(
+    AppSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audit_operation(
+    AuditOperation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_audit_query(
+    AuditQuery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_git_config(
+    GitConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_interrupted_sync(
+    InterruptedSync self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_launch_config(
+    LaunchConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_local_folder_config(
+    LocalFolderConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_s_3_config(
+    S3Config self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sftp_config(
+    SftpConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_remote(
+    SyncRemote self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_status(
+    SyncStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_web_dav_config(
+    WebDavConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_bundle_info(BundleInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_control_server_settings(
+    ControlServerSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_control_server_status(
+    ControlServerStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_game_process_status(
+    GameProcessStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_git_config(GitConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_health_status(HealthStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hook_config(HookConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hook_event(HookEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_hook_run(HookRun self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_action(ImportAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_import_conflict_policy(
+    ImportConflictPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_imported_account(
+    ImportedAccount self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_interrupted_sync(
+    InterruptedSync self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_launch_config(LaunchConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_launch_env_var(LaunchEnvVar self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_account_group(
+    List<AccountGroup> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_account_health(
+    List<AccountHealth> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_account_info(
+    List<AccountInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_audit_entry(
+    List<AuditEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_game_process_status(
+    List<GameProcessStatus> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_hook_config(
+    List<HookConfig> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_hook_run(List<HookRun> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_imported_account(
+    List<ImportedAccount> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_launch_env_var(
+    List<LaunchEnvVar> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_planned_entry(
+    List<PlannedEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_remote_version(
+    List<RemoteVersion> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sync_entry(
+    List<SyncEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sync_remote(
+    List<SyncRemote> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_sync_report(
+    List<SyncReport> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_local_folder_config(
+    LocalFolderConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_audit_operation(
+    AuditOperation? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_interrupted_sync(
+    InterruptedSync? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_launch_config(
+    LaunchConfig? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_sync_status(
+    SyncStatus? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_path_settings(PathSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_planned_entry(PlannedEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_remote_backend(RemoteBackend self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_remote_version(RemoteVersion self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_s_3_config(S3Config self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sftp_config(SftpConfig self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_action(SyncAction self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_direction(SyncDirection self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_entry(SyncEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_operation(SyncOperation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_plan(SyncPlan self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_remote(SyncRemote self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_report(SyncReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_sync_status(SyncStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_theme_mode(ThemeMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_theme_settings(ThemeSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_dav_config(WebDavConfig self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,230 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::api::backend::host_name;
+use crate::api::endfield::{get_app_data_dir, unix_now};
+use crate::api::report::{SyncAction, SyncOperation, SyncReport};
+
+// 审计日志只追加不修改，每行一条 JSON 记录：
+//   audit.jsonl       当前日志
+//   audit.<n>.jsonl   轮转后的旧日志，n 越大越旧
+const LOG_NAME: &str = "audit";
+const MAX_LOG_SIZE: u64 = 1024 * 1024;
+const MAX_ROTATED_FILES: u32 = 3;
+
+// 写入与轮转前对该文件加排他锁，界面、命令行与自动同步可能在不同进程中同时写入
+const LOCK_FILE: &str = "audit.lock";
+
+/// 审计日志记录的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AuditOperation {
+    Save,
+    Switch,
+    Delete,
+    Push,
+    Pull,
+    Import,
+    /// 恢复远端历史版本
+    Restore,
+    /// 回滚中断的拉取
+    Rollback,
+}
+
+/// 审计日志中的一条记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    /// 记录时间（Unix 秒）
+    pub timestamp: i64,
+    pub operation: AuditOperation,
+    /// 账号别名，为 `None` 时表示整个操作（例如远端无法连接）
+    pub alias: Option<String>,
+    /// 数据来源：账号文件、游戏凭证路径、远端名称或归档文件
+    pub source: Option<String>,
+    /// 被写入的位置，含义同 `source`
+    pub target: Option<String>,
+    pub success: bool,
+    /// 失败原因
+    pub error: Option<String>,
+    /// 执行操作的设备名
+    pub device: String,
+}
+
+/// 审计日志的查询条件，均为空时返回全部记录
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuditQuery {
+    pub alias: Option<String>,
+    pub operation: Option<AuditOperation>,
+    /// 时间范围（Unix 秒，含两端）
+    pub since: Option<i64>,
+    pub until: Option<i64>,
+    pub failures_only: bool,
+    /// 最多返回的记录数
+    pub limit: Option<u32>,
+}
+
+impl AuditQuery {
+    fn matches(&self, entry: &AuditEntry) -> bool {
+        self.alias
+            .as_ref()
+            .is_none_or(|a| entry.alias.as_ref() == Some(a))
+            && self.operation.is_none_or(|op| entry.operation == op)
+            && self.since.is_none_or(|t| entry.timestamp >= t)
+            && self.until.is_none_or(|t| entry.timestamp <= t)
+            && !(self.failures_only && entry.success)
+    }
+}
+
+// 一次待写入的操作，执行过程中逐步补充来源与目标
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct AuditRecord {
+    operation: AuditOperation,
+    alias: Option<String>,
+    source: Option<String>,
+    target: Option<String>,
+}
+
+impl AuditRecord {
+    pub(crate) fn new(operation: AuditOperation, alias: Option<&str>) -> Self {
+        Self {
+            operation,
+            alias: alias.map(str::to_string),
+            source: None,
+            target: None,
+        }
+    }
+
+    pub(crate) fn source(&mut self, source: impl AsRef<Path>) -> &mut Self {
+        self.source = Some(source.as_ref().display().to_string());
+        self
+    }
+
+    pub(crate) fn target(&mut self, target: impl AsRef<Path>) -> &mut Self {
+        self.target = Some(target.as_ref().display().to_string());
+        self
+    }
+
+    pub(crate) fn finish<T>(self, result: &Result<T>) {
+        let error = result.as_ref().err().map(|e| format!("{:#}", e));
+        append(AuditEntry {
+            timestamp: unix_now(),
+            operation: self.operation,
+            alias: self.alias,
+            source: self.source,
+            target: self.target,
+            success: error.is_none(),
+            error,
+            device: host_name(),
+        });
+    }
+}
+
+// 记录同步中实际传输或失败的账号，跳过与冲突的条目没有写入任何一方
+pub(crate) fn record_sync(report: &SyncReport) {
+    let operation = match report.operation {
+        SyncOperation::Push => AuditOperation::Push,
+        SyncOperation::Pull => AuditOperation::Pull,
+    };
+    for entry in &report.entries {
+        if !matches!(
+            entry.action,
+            SyncAction::Uploaded | SyncAction::Downloaded | SyncAction::Failed
+        ) {
+            continue;
+        }
+        let (source, target) = match report.operation {
+            SyncOperation::Push => (None, Some(entry.remote.clone())),
+            SyncOperation::Pull => (Some(entry.remote.clone()), None),
+        };
+        let failed = entry.action == SyncAction::Failed;
+        append(AuditEntry {
+            timestamp: unix_now(),
+            operation,
+            alias: entry.name.clone(),
+            source,
+            target,
+            success: !failed,
+            error: if failed { entry.reason.clone() } else { None },
+            device: host_name(),
+        });
+    }
+}
+
+fn log_file(dir: &Path, index: u32) -> PathBuf {
+    if index == 0 {
+        dir.join(format!("{}.jsonl", LOG_NAME))
+    } else {
+        dir.join(format!("{}.{}.jsonl", LOG_NAME, index))
+    }
+}
+
+// 当前日志写满后依次后移，最旧的一份被丢弃
+fn rotate(dir: &Path) -> Result<()> {
+    let _ = fs::remove_file(log_file(dir, MAX_ROTATED_FILES));
+    for index in (0..MAX_ROTATED_FILES).rev() {
+        let from = log_file(dir, index);
+        if from.exists() {
+            fs::rename(from, log_file(dir, index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+fn write_entry(entry: &AuditEntry) -> Result<()> {
+    let dir = get_app_data_dir()?;
+    // 关闭文件时释放锁
+    let lock = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(dir.join(LOCK_FILE))?;
+    lock.lock()?;
+    let mut line = serde_json::to_vec(entry)?;
+    line.push(b'\n');
+    let current = log_file(&dir, 0);
+    let size = fs::metadata(&current).map(|m| m.len()).unwrap_or(0);
+    if size > 0 && size + line.len() as u64 > MAX_LOG_SIZE {
+        rotate(&dir)?;
+    }
+    // 整行一次写入，其他进程同时追加时也不会交错
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(current)?
+        .write_all(&line)?;
+    Ok(())
+}
+
+// 日志写入失败不影响操作本身
+fn append(entry: AuditEntry) {
+    let _ = write_entry(&entry);
+}
+
+// ============== 审计日志 API ==============
+
+/// 按条件查询审计日志，新记录在前
+pub fn query_audit_log(query: AuditQuery) -> anyhow::Result<Vec<AuditEntry>> {
+    let dir = get_app_data_dir()?;
+    let limit = query.limit.map_or(usize::MAX, |l| l as usize);
+    let mut entries = Vec::new();
+    for index in 0..=MAX_ROTATED_FILES {
+        let Ok(data) = fs::read_to_string(log_file(&dir, index)) else {
+            continue;
+        };
+        // 跳过写入中途退出留下的不完整行
+        for entry in data
+            .lines()
+            .rev()
+            .filter_map(|line| serde_json::from_str::<AuditEntry>(line).ok())
+            .filter(|entry| query.matches(entry))
+        {
+            if entries.len() >= limit {
+                return Ok(entries);
+            }
+            entries.push(entry);
+        }
+    }
+    Ok(entries)
+}
@@ -0,0 +1,313 @@
+use serde::{Deserialize, Serialize};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tokio::runtime::Runtime;
+use tokio::sync::{watch, Notify};
+use tokio::task::JoinHandle;
+
+use crate::api::endfield::unix_now;
+use crate::api::settings::load_settings;
+use crate::api::sync::{sync_pull, sync_push_with_manifest};
+
+// 本地账号变化后等待该时长再同步，合并短时间内的多次操作
+const DEBOUNCE_SECS: i64 = 10;
+// 同步失败后的重试间隔从该值开始翻倍，最长不超过设置中的同步间隔
+const BACKOFF_BASE_SECS: i64 = 30;
+
+/// 自动同步的运行状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AutoSyncStatus {
+    /// 调度器是否在运行
+    pub active: bool,
+    /// 当前是否正在同步
+    pub syncing: bool,
+    pub last_success_at: Option<i64>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<i64>,
+    /// 下一次计划同步的时间（Unix 秒）
+    pub next_run_at: Option<i64>,
+    /// 连续失败次数，用于计算退避间隔
+    pub consecutive_failures: u32,
+}
+
+struct Scheduler {
+    status: AutoSyncStatus,
+    task: Option<JoinHandle<()>>,
+    // 停止信号，调度器在等待时立即退出，同步进行中时等本次同步结束再退出
+    stop: Option<watch::Sender<bool>>,
+}
+
+static SCHEDULER: LazyLock<Mutex<Scheduler>> = LazyLock::new(|| {
+    Mutex::new(Scheduler {
+        status: AutoSyncStatus::default(),
+        task: None,
+        stop: None,
+    })
+});
+static WAKE: LazyLock<Notify> = LazyLock::new(Notify::new);
+// 调度器使用独立的运行时，不依赖调用方所在的线程
+static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("endswitcher-autosync")
+        .enable_all()
+        .build()
+        .expect("Failed to create auto-sync runtime")
+});
+
+// 推送到所有允许推送的远端，再从所有允许拉取的远端拉取
+async fn run_sync_cycle() -> anyhow::Result<()> {
+    let remotes = load_settings()?.remotes;
+    if remotes.is_empty() {
+        anyhow::bail!("No sync remote is configured");
+    }
+    if remotes.iter().any(|r| r.direction.allows_push()) {
+        sync_push_with_manifest(None).await?.ensure_complete()?;
+    }
+    if remotes.iter().any(|r| r.direction.allows_pull()) {
+        sync_pull(None).await?.ensure_complete()?;
+    }
+    Ok(())
+}
+
+fn backoff_secs(failures: u32, interval_secs: i64) -> i64 {
+    let exp = failures.saturating_sub(1).min(16);
+    (BACKOFF_BASE_SECS << exp).min(interval_secs)
+}
+
+// 记录一次同步的结果并计算下一次同步时间：成功后按设置的间隔，失败后按退避间隔；
+// 同步期间发生的本地变化已写入更早的 next_run_at 时保留
+fn record_result(
+    status: &mut AutoSyncStatus,
+    result: anyhow::Result<()>,
+    interval_secs: i64,
+    now: i64,
+) {
+    status.syncing = false;
+    let delay = match result {
+        Ok(()) => {
+            status.last_success_at = Some(now);
+            status.consecutive_failures = 0;
+            interval_secs
+        }
+        Err(e) => {
+            status.last_error = Some(e.to_string());
+            status.last_error_at = Some(now);
+            status.consecutive_failures += 1;
+            backoff_secs(status.consecutive_failures, interval_secs)
+        }
+    };
+    let planned = now + delay;
+    status.next_run_at = Some(status.next_run_at.map_or(planned, |t| t.min(planned)));
+}
+
+// 本地变化后的同步时间，已计划的同步更早时不变
+fn debounced_run_at(current: Option<i64>, now: i64) -> Option<i64> {
+    let at = now + DEBOUNCE_SECS;
+    current.is_none_or(|next| next > at).then_some(at)
+}
+
+fn schedule_at(at: i64) {
+    SCHEDULER.lock().unwrap().status.next_run_at = Some(at);
+    WAKE.notify_one();
+}
+
+async fn scheduler_loop(mut stop: watch::Receiver<bool>) {
+    loop {
+        if *stop.borrow() {
+            return;
+        }
+        let settings = match load_settings() {
+            Ok(settings) => settings.auto_sync,
+            Err(_) => {
+                tokio::select! {
+                    _ = tokio::time::sleep(Duration::from_secs(BACKOFF_BASE_SECS as u64)) => {}
+                    _ = stop.changed() => {}
+                }
+                continue;
+            }
+        };
+        if !settings.enabled {
+            break;
+        }
+        let interval_secs = settings.interval_minutes.max(1) as i64 * 60;
+
+        let next_run_at = {
+            let mut scheduler = SCHEDULER.lock().unwrap();
+            *scheduler
+                .status
+                .next_run_at
+                .get_or_insert(unix_now() + interval_secs)
+        };
+        let wait = (next_run_at - unix_now()).max(0) as u64;
+        if wait > 0 {
+            // 被唤醒或停止时重新检查
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_secs(wait)) => {}
+                _ = WAKE.notified() => continue,
+                _ = stop.changed() => continue,
+            }
+        }
+
+        {
+            let mut scheduler = SCHEDULER.lock().unwrap();
+            scheduler.status.syncing = true;
+            // 同步期间发生的本地变化会重新写入 next_run_at
+            scheduler.status.next_run_at = None;
+        }
+        // 同步不响应停止信号：中途放弃的拉取会留下同步日志，阻塞之后的所有同步
+        let result = run_sync_cycle().await;
+        let mut scheduler = SCHEDULER.lock().unwrap();
+        record_result(&mut scheduler.status, result, interval_secs, unix_now());
+        if *stop.borrow() {
+            scheduler.status.next_run_at = None;
+            return;
+        }
+    }
+
+    let mut scheduler = SCHEDULER.lock().unwrap();
+    scheduler.status.active = false;
+    scheduler.status.next_run_at = None;
+    scheduler.stop = None;
+}
+
+// 本地账号发生变化（保存、切换、删除）时调用，调度器未运行或未开启该选项时忽略
+pub(crate) fn notify_local_change() {
+    if !SCHEDULER.lock().unwrap().status.active {
+        return;
+    }
+    if !load_settings().is_ok_and(|s| s.auto_sync.on_change) {
+        return;
+    }
+    let current = SCHEDULER.lock().unwrap().status.next_run_at;
+    if let Some(at) = debounced_run_at(current, unix_now()) {
+        schedule_at(at);
+    }
+}
+
+// ============== 自动同步 API ==============
+
+/// 按设置启动自动同步调度器，已在运行时不做任何事；设置中未开启时返回 `false`
+pub fn start_auto_sync() -> anyhow::Result<bool> {
+    let settings = load_settings()?.auto_sync;
+    if !settings.enabled {
+        return Ok(false);
+    }
+    let mut scheduler = SCHEDULER.lock().unwrap();
+    if scheduler.status.active {
+        return Ok(true);
+    }
+    let interval_secs = settings.interval_minutes.max(1) as i64 * 60;
+    let first_run = if settings.on_startup {
+        unix_now()
+    } else {
+        unix_now() + interval_secs
+    };
+    scheduler.status.active = true;
+    scheduler.status.next_run_at = Some(first_run);
+    let (stop, stopped) = watch::channel(false);
+    scheduler.stop = Some(stop);
+    // 刚停止的调度器可能仍在完成最后一次同步，等它退出后再开始
+    let previous = scheduler.task.take();
+    scheduler.task = Some(RUNTIME.spawn(async move {
+        if let Some(previous) = previous {
+            let _ = previous.await;
+        }
+        scheduler_loop(stopped).await;
+    }));
+    Ok(true)
+}
+
+/// 停止自动同步调度器；正在进行的同步会继续完成，之后不再安排同步
+pub fn stop_auto_sync() -> anyhow::Result<()> {
+    let mut scheduler = SCHEDULER.lock().unwrap();
+    if let Some(stop) = scheduler.stop.take() {
+        let _ = stop.send(true);
+    }
+    scheduler.status.active = false;
+    scheduler.status.next_run_at = None;
+    Ok(())
+}
+
+/// 设置变更后重新加载调度器
+pub fn restart_auto_sync() -> anyhow::Result<bool> {
+    stop_auto_sync()?;
+    start_auto_sync()
+}
+
+/// 立即执行一次同步（调度器运行时）
+pub fn trigger_auto_sync_now() -> anyhow::Result<()> {
+    if !SCHEDULER.lock().unwrap().status.active {
+        anyhow::bail!("Auto-sync is not running");
+    }
+    schedule_at(unix_now());
+    Ok(())
+}
+
+/// 获取自动同步状态
+pub fn get_auto_sync_status() -> anyhow::Result<AutoSyncStatus> {
+    Ok(SCHEDULER.lock().unwrap().status.clone())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = 60 * 60;
+    const NOW: i64 = 1_800_000_000;
+
+    #[test]
+    fn backoff_doubles_up_to_the_interval() {
+        let delays: Vec<_> = (1..=5).map(|n| backoff_secs(n, HOUR)).collect();
+        assert_eq!(delays, [30, 60, 120, 240, 480]);
+        assert_eq!(backoff_secs(8, HOUR), HOUR);
+        assert_eq!(backoff_secs(u32::MAX, HOUR), HOUR);
+        assert_eq!(backoff_secs(1, 20), 20);
+    }
+
+    #[test]
+    fn success_resets_backoff() {
+        let mut status = AutoSyncStatus::default();
+        for _ in 0..3 {
+            record_result(&mut status, Err(anyhow::anyhow!("offline")), HOUR, NOW);
+            status.next_run_at = None;
+        }
+        assert_eq!(status.consecutive_failures, 3);
+        record_result(&mut status, Err(anyhow::anyhow!("offline")), HOUR, NOW);
+        assert_eq!(status.next_run_at, Some(NOW + 240));
+        assert_eq!(status.last_error.as_deref(), Some("offline"));
+
+        status.next_run_at = None;
+        record_result(&mut status, Ok(()), HOUR, NOW);
+        assert_eq!(status.consecutive_failures, 0);
+        assert_eq!(status.last_success_at, Some(NOW));
+        assert_eq!(status.next_run_at, Some(NOW + HOUR));
+
+        status.next_run_at = None;
+        record_result(&mut status, Err(anyhow::anyhow!("offline")), HOUR, NOW);
+        assert_eq!(status.next_run_at, Some(NOW + 30));
+    }
+
+    #[test]
+    fn change_during_sync_keeps_earlier_run() {
+        let mut status = AutoSyncStatus {
+            syncing: true,
+            next_run_at: Some(NOW + DEBOUNCE_SECS),
+            ..AutoSyncStatus::default()
+        };
+        record_result(&mut status, Ok(()), HOUR, NOW);
+        assert!(!status.syncing);
+        assert_eq!(status.next_run_at, Some(NOW + DEBOUNCE_SECS));
+    }
+
+    #[test]
+    fn debounce_only_moves_the_next_run_earlier() {
+        assert_eq!(debounced_run_at(None, NOW), Some(NOW + DEBOUNCE_SECS));
+        assert_eq!(
+            debounced_run_at(Some(NOW + HOUR), NOW),
+            Some(NOW + DEBOUNCE_SECS)
+        );
+        assert_eq!(debounced_run_at(Some(NOW + 3), NOW), None);
+        assert_eq!(debounced_run_at(Some(NOW + DEBOUNCE_SECS), NOW), None);
+    }
+}
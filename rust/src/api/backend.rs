@@ -0,0 +1,143 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::api::endfield::unix_now;
+use crate::api::git::GitBackend;
+use crate::api::local_folder::LocalFolderBackend;
+use crate::api::s3::S3Backend;
+use crate::api::settings::{RemoteBackend, SyncRemote};
+use crate::api::sftp::SftpBackend;
+use crate::api::webdav::WebDavBackend;
+
+// 远端互斥锁文件，持有者崩溃后超过该时长的锁视为失效
+pub(crate) const LOCK_FILE: &str = ".lock";
+pub(crate) const LOCK_TTL_SECS: i64 = 10 * 60;
+
+// 写入中的临时文件名，写完后再替换目标文件
+pub(crate) fn temp_file_name(name: &str) -> String {
+    format!(".{}.part", name)
+}
+
+// 列出目录时跳过写入中的临时文件，其他以 `.` 开头的文件可能是账号
+pub(crate) fn is_temp_file_name(name: &str) -> bool {
+    name.strip_prefix('.')
+        .and_then(|n| n.strip_suffix(".part"))
+        .is_some_and(|n| !n.is_empty())
+}
+
+// 远端文件的基本信息
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone)]
+pub(crate) struct RemoteStat {
+    pub(crate) size: u64,
+    // 内容变化时随之变化的标识（ETag 等），后端不提供时为 `None`
+    pub(crate) etag: Option<String>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct LockInfo {
+    pub(crate) token: String,
+    pub(crate) host: String,
+    pub(crate) acquired_at: i64,
+}
+
+// 本机名称，用于锁文件与提交信息
+pub(crate) fn host_name() -> String {
+    std::env::var("COMPUTERNAME")
+        .or_else(|_| std::env::var("HOSTNAME"))
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+impl LockInfo {
+    pub(crate) fn new() -> Self {
+        Self {
+            token: format!("{:032x}", rand::random::<u128>()),
+            host: host_name(),
+            acquired_at: unix_now(),
+        }
+    }
+
+    pub(crate) fn is_stale(&self) -> bool {
+        unix_now() - self.acquired_at > LOCK_TTL_SECS
+    }
+}
+
+// 同步引擎使用的远端存储
+// 路径相对于远端的 EndSwitcherConfig 目录，以 `/` 分隔，`""` 表示该目录本身
+#[flutter_rust_bridge::frb(ignore)]
+#[async_trait]
+pub(crate) trait SyncBackend: Send + Sync {
+    // 创建目录，已存在时忽略
+    async fn ensure_dir(&self, dir: &str) -> Result<()>;
+
+    // 列出目录下的文件名，不含子目录
+    async fn list(&self, dir: &str) -> Result<Vec<String>>;
+
+    // 文件不存在时返回 `None`
+    async fn stat(&self, path: &str) -> Result<Option<RemoteStat>>;
+
+    // 下载到 `target`，校验通过后才替换目标文件，返回内容的 SHA-256
+    async fn get(&self, path: &str, target: &Path, expected_hash: Option<&str>) -> Result<String>;
+
+    // 读取小文件（历史、锁等），不存在时返回 `None`
+    async fn read(&self, path: &str) -> Result<Option<Vec<u8>>>;
+
+    // 上传本地文件，`hash` 为上传前计算的 SHA-256
+    async fn put(&self, path: &str, source: &Path, hash: &str) -> Result<()>;
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> Result<()>;
+
+    // 文件不存在时视为成功
+    async fn delete(&self, path: &str) -> Result<()>;
+
+    // 获取远端的独占锁，返回释放时使用的令牌
+    // 各后端需要原子地独占创建锁文件，不能先检查再写入
+    async fn lock(&self) -> Result<String>;
+
+    async fn unlock(&self, token: &str) -> Result<()> {
+        release_lock_file(self, token).await
+    }
+
+    // 持有锁期间的修改全部成功后调用，用于需要显式提交的后端
+    async fn commit(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+pub(crate) async fn release_lock_file<B: SyncBackend + ?Sized>(backend: &B, token: &str) -> Result<()> {
+    // 锁已失效并被其他设备接管时不删除
+    match read_lock(backend).await? {
+        Some(holder) if holder.token == token => backend.delete(LOCK_FILE).await,
+        _ => Ok(()),
+    }
+}
+
+// 无法解析的锁文件视为失效
+pub(crate) async fn read_lock<B: SyncBackend + ?Sized>(backend: &B) -> Result<Option<LockInfo>> {
+    Ok(backend
+        .read(LOCK_FILE)
+        .await?
+        .and_then(|data| serde_json::from_slice(&data).ok()))
+}
+
+// 预览同步计划用的存储后端，只读取数据，Git 远端不拉取也不合并远程仓库
+pub(crate) fn open_planning_backend(remote: &SyncRemote) -> Result<Box<dyn SyncBackend>> {
+    match &remote.backend {
+        RemoteBackend::Git(config) => Ok(Box::new(GitBackend::for_planning(config)?)),
+        _ => open_backend(remote),
+    }
+}
+
+// 按远端配置创建对应的存储后端
+pub(crate) fn open_backend(remote: &SyncRemote) -> Result<Box<dyn SyncBackend>> {
+    Ok(match &remote.backend {
+        RemoteBackend::WebDav(config) => Box::new(WebDavBackend::new(config.clone())?),
+        RemoteBackend::Folder(config) => Box::new(LocalFolderBackend::new(config)?),
+        RemoteBackend::S3(config) => Box::new(S3Backend::new(config.clone())?),
+        RemoteBackend::Git(config) => Box::new(GitBackend::new(config)?),
+        RemoteBackend::Sftp(config) => Box::new(SftpBackend::new(config.clone())?),
+    })
+}
@@ -0,0 +1,379 @@
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{anyhow, bail, Context, Result};
+use argon2::Argon2;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+
+use crate::api::audit::{AuditOperation, AuditRecord};
+use crate::api::endfield::{
+    file_updated_at, get_account_cache_file, get_account_list, unix_now, validate_alias,
+};
+use crate::api::instance::lock_instance;
+use crate::api::meta::restore_meta;
+use crate::api::transfer::get_transfer_dir;
+
+// 归档文件结构：魔数 + 格式版本 + 标志位，之后是（可能加密的）JSON 内容
+// 加密时在 JSON 之前依次写入 16 字节盐和 12 字节 nonce，密钥由 Argon2id 从口令派生
+// 头部作为附加认证数据参与加密，被篡改时无法解密
+const BUNDLE_MAGIC: &[u8; 8] = b"ESBUNDLE";
+const BUNDLE_VERSION: u8 = 1;
+const FLAG_ENCRYPTED: u8 = 0b0000_0001;
+pub(crate) const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+const TAG_LEN: usize = 16;
+// `seal` 在明文之外增加的长度
+pub(crate) const SEAL_OVERHEAD: usize = NONCE_LEN + TAG_LEN;
+const HEADER_LEN: usize = BUNDLE_MAGIC.len() + 2;
+
+/// 导入时遇到同名账号的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportConflictPolicy {
+    /// 保留本地账号，跳过归档中的同名账号
+    Skip,
+    /// 用归档中的账号覆盖本地账号
+    Overwrite,
+    /// 仅当归档中的凭证比本地更新时覆盖
+    KeepNewer,
+    /// 以新别名导入，例如 `alias (2)`
+    Rename,
+}
+
+/// 单个账号的导入结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ImportAction {
+    Created,
+    Overwritten,
+    Renamed,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportedAccount {
+    /// 归档中的别名
+    pub original_alias: String,
+    /// 实际写入的别名，跳过时与 `original_alias` 相同
+    pub alias: String,
+    pub action: ImportAction,
+}
+
+/// 归档概要，加密归档在未提供口令时 `aliases` 为空
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BundleInfo {
+    pub version: u8,
+    pub encrypted: bool,
+    pub created_at: Option<i64>,
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundleAccount {
+    alias: String,
+    updated_at: i64,
+    // login_cache 原始内容，Base64 编码
+    data: String,
+    #[serde(default)]
+    group: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    notes: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct BundlePayload {
+    created_at: i64,
+    accounts: Vec<BundleAccount>,
+}
+
+pub(crate) fn derive_key(passphrase: &str, salt: &[u8]) -> Result<[u8; 32]> {
+    let mut key = [0u8; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|e| anyhow!("Failed to derive key: {}", e))?;
+    Ok(key)
+}
+
+// 用 AES-256-GCM 加密，返回随机 nonce 与密文，`aad` 作为附加认证数据
+pub(crate) fn seal(key: &[u8; 32], plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+    let mut nonce = [0u8; NONCE_LEN];
+    rand::thread_rng().fill_bytes(&mut nonce);
+    let cipher = Aes256Gcm::new(key.into());
+    let payload = Payload {
+        msg: plaintext,
+        aad,
+    };
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce), payload)
+        .map_err(|_| anyhow!("Failed to encrypt"))?;
+    let mut out = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+// 解密 `seal` 的输出，口令错误或内容被篡改时返回 `None`
+pub(crate) fn unseal(key: &[u8; 32], sealed: &[u8], aad: &[u8]) -> Option<Vec<u8>> {
+    if sealed.len() < SEAL_OVERHEAD {
+        return None;
+    }
+    let (nonce, ciphertext) = sealed.split_at(NONCE_LEN);
+    let cipher = Aes256Gcm::new(key.into());
+    let payload = Payload {
+        msg: ciphertext,
+        aad,
+    };
+    cipher.decrypt(Nonce::from_slice(nonce), payload).ok()
+}
+
+fn normalize_passphrase(passphrase: Option<String>) -> Option<String> {
+    passphrase.filter(|p| !p.is_empty())
+}
+
+fn encode_bundle(payload: &BundlePayload, passphrase: Option<&str>) -> Result<Vec<u8>> {
+    let json = serde_json::to_vec(payload)?;
+    let mut out = Vec::with_capacity(HEADER_LEN + json.len());
+    out.extend_from_slice(BUNDLE_MAGIC);
+    out.push(BUNDLE_VERSION);
+
+    match passphrase {
+        Some(passphrase) => {
+            let mut salt = [0u8; SALT_LEN];
+            rand::thread_rng().fill_bytes(&mut salt);
+            let key = derive_key(passphrase, &salt)?;
+            out.push(FLAG_ENCRYPTED);
+            let sealed = seal(&key, &json, &out[..HEADER_LEN])?;
+            out.extend_from_slice(&salt);
+            out.extend_from_slice(&sealed);
+        }
+        None => {
+            out.push(0);
+            out.extend_from_slice(&json);
+        }
+    }
+    Ok(out)
+}
+
+// 解析归档头部，返回 (版本, 是否加密, 剩余内容)
+fn read_header(raw: &[u8]) -> Result<(u8, bool, &[u8])> {
+    if raw.len() < HEADER_LEN || &raw[..BUNDLE_MAGIC.len()] != BUNDLE_MAGIC {
+        bail!("Not an EndSwitcher account bundle");
+    }
+    let version = raw[BUNDLE_MAGIC.len()];
+    if version != BUNDLE_VERSION {
+        bail!(
+            "Unsupported bundle version {}, expected {}",
+            version,
+            BUNDLE_VERSION
+        );
+    }
+    let flags = raw[BUNDLE_MAGIC.len() + 1];
+    Ok((version, flags & FLAG_ENCRYPTED != 0, &raw[HEADER_LEN..]))
+}
+
+fn decode_bundle(raw: &[u8], passphrase: Option<&str>) -> Result<BundlePayload> {
+    let (_, encrypted, body) = read_header(raw)?;
+    let json = if encrypted {
+        let passphrase = passphrase.context("Bundle is encrypted, passphrase required")?;
+        if body.len() < SALT_LEN + NONCE_LEN {
+            bail!("Bundle is truncated");
+        }
+        let (salt, sealed) = body.split_at(SALT_LEN);
+        let key = derive_key(passphrase, salt)?;
+        unseal(&key, sealed, &raw[..HEADER_LEN]).ok_or_else(|| {
+            anyhow!("Failed to decrypt bundle: wrong passphrase or corrupted file")
+        })?
+    } else {
+        body.to_vec()
+    };
+    serde_json::from_slice(&json).context("Bundle content is corrupted")
+}
+
+// 为重名账号挑选一个未被占用的新别名
+fn next_free_alias(alias: &str) -> Result<String> {
+    for n in 2.. {
+        let candidate = format!("{} ({})", alias, n);
+        if !get_account_cache_file(&candidate)?.exists() {
+            return Ok(candidate);
+        }
+    }
+    unreachable!()
+}
+
+fn write_account(alias: &str, data: &[u8], updated_at: i64) -> Result<()> {
+    let target = get_account_cache_file(alias)?;
+    // 先写入数据目录下的临时文件再移动到账号目录，避免留下不完整的凭证
+    let temp = get_transfer_dir()?.join(format!("{}.import", validate_alias(alias)?));
+    fs::write(&temp, data)?;
+    // 保留凭证原本的更新时间，便于之后按时间比较新旧
+    let modified = UNIX_EPOCH + Duration::from_secs(updated_at.max(0) as u64);
+    let result = fs::File::options()
+        .write(true)
+        .open(&temp)
+        .and_then(|file| file.set_modified(modified))
+        .and_then(|_| fs::rename(&temp, &target));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp);
+        return Err(e.into());
+    }
+    Ok(())
+}
+
+// ============== 导入导出 API ==============
+
+/// 将指定账号导出为单个归档文件，`aliases` 为空时导出全部账号；返回导出的账号数
+pub fn export_accounts(
+    aliases: Vec<String>,
+    path: String,
+    passphrase: Option<String>,
+) -> anyhow::Result<u32> {
+    let all = get_account_list()?;
+    let selected: Vec<_> = if aliases.is_empty() {
+        all
+    } else {
+        let mut selected = Vec::new();
+        for alias in &aliases {
+            let alias = validate_alias(alias)?;
+            let acc = all
+                .iter()
+                .find(|a| a.alias == alias)
+                .with_context(|| format!("Saved account not found: {}", alias))?;
+            selected.push(acc.clone());
+        }
+        selected
+    };
+    if selected.is_empty() {
+        bail!("No accounts to export");
+    }
+
+    let mut accounts = Vec::with_capacity(selected.len());
+    for acc in &selected {
+        let data = fs::read(get_account_cache_file(&acc.alias)?)?;
+        accounts.push(BundleAccount {
+            alias: acc.alias.clone(),
+            updated_at: acc.updated_at,
+            data: BASE64.encode(data),
+            group: acc.group.clone(),
+            tags: acc.tags.clone(),
+            notes: acc.notes.clone(),
+        });
+    }
+
+    let payload = BundlePayload {
+        created_at: unix_now(),
+        accounts,
+    };
+    let passphrase = normalize_passphrase(passphrase);
+    let raw = encode_bundle(&payload, passphrase.as_deref())?;
+    // 先写入临时文件再替换，失败时不会留下不完整的归档或破坏已有文件
+    let temp = PathBuf::from(format!("{}.tmp", path));
+    fs::write(&temp, raw)?;
+    if let Err(e) = fs::rename(&temp, Path::new(&path)) {
+        let _ = fs::remove_file(&temp);
+        return Err(e.into());
+    }
+    Ok(payload.accounts.len() as u32)
+}
+
+/// 读取归档概要，用于导入前预览
+pub fn inspect_bundle(path: String, passphrase: Option<String>) -> anyhow::Result<BundleInfo> {
+    let raw = fs::read(Path::new(&path))?;
+    let (version, encrypted, _) = read_header(&raw)?;
+    let passphrase = normalize_passphrase(passphrase);
+    if encrypted && passphrase.is_none() {
+        return Ok(BundleInfo {
+            version,
+            encrypted,
+            created_at: None,
+            aliases: Vec::new(),
+        });
+    }
+    let payload = decode_bundle(&raw, passphrase.as_deref())?;
+    Ok(BundleInfo {
+        version,
+        encrypted,
+        created_at: Some(payload.created_at),
+        aliases: payload.accounts.into_iter().map(|a| a.alias).collect(),
+    })
+}
+
+// 读取并完整校验归档中的账号，避免导入到一半才发现坏数据
+fn decode_accounts(
+    path: &str,
+    passphrase: Option<String>,
+) -> Result<Vec<(String, Vec<u8>, BundleAccount)>> {
+    let raw = fs::read(Path::new(path))?;
+    let passphrase = normalize_passphrase(passphrase);
+    let payload = decode_bundle(&raw, passphrase.as_deref())?;
+    let mut decoded = Vec::with_capacity(payload.accounts.len());
+    for acc in payload.accounts {
+        let alias = validate_alias(&acc.alias)?.to_string();
+        let data = BASE64
+            .decode(&acc.data)
+            .with_context(|| format!("Bundle entry {} is corrupted", alias))?;
+        decoded.push((alias, data, acc));
+    }
+    Ok(decoded)
+}
+
+/// 从归档文件导入账号
+pub fn import_accounts(
+    path: String,
+    passphrase: Option<String>,
+    conflict_policy: ImportConflictPolicy,
+) -> anyhow::Result<Vec<ImportedAccount>> {
+    let _lock = lock_instance("importing accounts")?;
+    let decoded = decode_accounts(&path, passphrase);
+    if decoded.is_err() {
+        let mut audit = AuditRecord::new(AuditOperation::Import, None);
+        audit.source(&path);
+        audit.finish(&decoded);
+    }
+    let decoded = decoded?;
+
+    let mut results = Vec::with_capacity(decoded.len());
+    for (original_alias, data, acc) in decoded {
+        let existing = get_account_cache_file(&original_alias)?;
+        let (alias, action) = if !existing.exists() {
+            (original_alias.clone(), ImportAction::Created)
+        } else {
+            match conflict_policy {
+                ImportConflictPolicy::Skip => (original_alias.clone(), ImportAction::Skipped),
+                ImportConflictPolicy::Overwrite => {
+                    (original_alias.clone(), ImportAction::Overwritten)
+                }
+                ImportConflictPolicy::KeepNewer => {
+                    if acc.updated_at > file_updated_at(&existing)? {
+                        (original_alias.clone(), ImportAction::Overwritten)
+                    } else {
+                        (original_alias.clone(), ImportAction::Skipped)
+                    }
+                }
+                ImportConflictPolicy::Rename => {
+                    (next_free_alias(&original_alias)?, ImportAction::Renamed)
+                }
+            }
+        };
+
+        if action != ImportAction::Skipped {
+            let mut audit = AuditRecord::new(AuditOperation::Import, Some(&alias));
+            audit.source(&path).target(get_account_cache_file(&alias)?);
+            let written = write_account(&alias, &data, acc.updated_at)
+                .and_then(|_| restore_meta(&alias, acc.group, acc.tags, acc.notes));
+            audit.finish(&written);
+            written?;
+            crate::api::autosync::notify_local_change();
+        }
+        results.push(ImportedAccount {
+            original_alias,
+            alias,
+            action,
+        });
+    }
+    Ok(results)
+}
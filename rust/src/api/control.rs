@@ -0,0 +1,468 @@
+use anyhow::{Context, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::runtime::Runtime;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, mpsc, watch};
+use tokio::task::JoinHandle;
+
+use crate::api::endfield::{
+    get_account_list, get_app_data_dir, get_current_account, save_current_account,
+    switch_to_account,
+};
+use crate::api::hooks::HookVeto;
+use crate::api::report::{SyncOperation, SyncReport};
+use crate::api::settings::load_settings;
+use crate::api::sync::{sync_pull, sync_push_with_manifest};
+
+// 本地控制服务，只监听 127.0.0.1，协议为按行分隔的 JSON-RPC 2.0
+// 连接后需先调用 `auth {token}`，令牌保存在应用数据目录的 control_token 中，令牌错误时断开连接
+//   list                          已保存的账号列表
+//   current                       游戏当前登录的账号，没有匹配时为 null
+//   switch {alias} / save {alias} 切换到账号 / 保存当前登录的账号，返回运行过的钩子；
+//                                 被前置钩子取消时错误的 data.hooks 为运行过的钩子
+//   sync {operation, remote?}     operation 为 "Push" 或 "Pull"，返回同步报告
+//   subscribe                     之后推送 `event` 通知，params 为 ControlEvent
+const TOKEN_FILE: &str = "control_token";
+// 单个请求的最大长度，超过时断开连接
+const MAX_LINE_LEN: u64 = 64 * 1024;
+// 订阅者来不及接收时最多缓存的事件数，更早的事件被丢弃
+const EVENT_CAPACITY: usize = 64;
+// 接受连接失败后的重试间隔，连续失败时逐步加倍
+const ACCEPT_BACKOFF_MIN: Duration = Duration::from_millis(50);
+const ACCEPT_BACKOFF_MAX: Duration = Duration::from_secs(5);
+
+// JSON-RPC 错误码
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const OPERATION_FAILED: i64 = -32000;
+const UNAUTHORIZED: i64 = -32001;
+
+/// 控制服务的运行状态
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ControlServerStatus {
+    pub running: bool,
+    /// 实际监听的端口，设置中为 0 时由系统分配
+    pub port: Option<u16>,
+}
+
+// 推送给订阅者的通知
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub(crate) enum ControlEvent {
+    Switched { alias: String },
+    Synced { report: SyncReport },
+}
+
+struct Server {
+    port: u16,
+    task: JoinHandle<()>,
+    // 停止时通知所有连接断开
+    shutdown: watch::Sender<bool>,
+}
+
+static SERVER: LazyLock<Mutex<Option<Server>>> = LazyLock::new(|| Mutex::new(None));
+static EVENTS: LazyLock<broadcast::Sender<ControlEvent>> =
+    LazyLock::new(|| broadcast::channel(EVENT_CAPACITY).0);
+// 控制服务使用独立的运行时，不依赖调用方所在的线程
+static RUNTIME: LazyLock<Runtime> = LazyLock::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .thread_name("endswitcher-control")
+        .enable_all()
+        .build()
+        .expect("Failed to create control server runtime")
+});
+
+// 通知订阅者，没有订阅者时直接丢弃
+pub(crate) fn publish_event(event: ControlEvent) {
+    let _ = EVENTS.send(event);
+}
+
+fn token_path() -> Result<PathBuf> {
+    Ok(get_app_data_dir()?.join(TOKEN_FILE))
+}
+
+// 令牌文件只允许当前用户读取，创建时即设置权限，写完后再替换到位
+fn write_token() -> Result<String> {
+    let path = token_path()?;
+    let token = format!(
+        "{:032x}{:032x}",
+        rand::random::<u128>(),
+        rand::random::<u128>()
+    );
+    let temp = path.with_extension("tmp");
+    let _ = fs::remove_file(&temp);
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let result = options
+        .open(&temp)
+        .and_then(|mut file| file.write_all(token.as_bytes()))
+        .and_then(|_| fs::rename(&temp, &path));
+    if let Err(e) = result {
+        let _ = fs::remove_file(&temp);
+        return Err(e.into());
+    }
+    Ok(token)
+}
+
+fn load_token() -> Result<String> {
+    match fs::read_to_string(token_path()?) {
+        Ok(token) if !token.trim().is_empty() => Ok(token.trim().to_string()),
+        _ => write_token(),
+    }
+}
+
+// 比较两者的摘要而不是令牌本身，比较耗时与令牌内容无关
+fn token_matches(given: &str, expected: &str) -> bool {
+    let given = Sha256::digest(given.as_bytes());
+    let expected = Sha256::digest(expected.as_bytes());
+    given
+        .iter()
+        .zip(expected.iter())
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+struct RpcError {
+    code: i64,
+    message: String,
+    data: Option<Value>,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(e: anyhow::Error) -> Self {
+        let mut error = Self::new(OPERATION_FAILED, format!("{:#}", e));
+        // 被前置钩子取消时一并返回运行过的钩子
+        if let Some(veto) = e.downcast_ref::<HookVeto>() {
+            error.data = Some(json!({ "hooks": veto.runs }));
+        }
+        error
+    }
+}
+
+#[derive(Deserialize)]
+struct Request {
+    #[serde(default)]
+    jsonrpc: Option<String>,
+    // 没有 id 的请求是通知，不返回响应
+    #[serde(default)]
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize)]
+struct AuthParams {
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct AliasParams {
+    alias: String,
+}
+
+#[derive(Deserialize)]
+struct SyncParams {
+    operation: SyncOperation,
+    #[serde(default)]
+    remote: Option<String>,
+}
+
+fn params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    // 省略 params 时按空对象处理
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn to_value<T: Serialize>(value: T) -> Result<Value, RpcError> {
+    serde_json::to_value(value).map_err(|e| RpcError::from(anyhow::Error::from(e)))
+}
+
+// 账号操作会读写文件并可能等待应用锁，放到阻塞线程中执行
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> Result<T> + Send + 'static,
+) -> Result<T, RpcError> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| RpcError::from(anyhow::Error::from(e)))?
+        .map_err(RpcError::from)
+}
+
+fn message(value: Value) -> String {
+    format!("{}\n", value)
+}
+
+fn response(id: Value, result: Result<Value, RpcError>) -> String {
+    message(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(e) => {
+            let mut error = json!({ "code": e.code, "message": e.message });
+            if let Some(data) = e.data {
+                error["data"] = data;
+            }
+            json!({ "jsonrpc": "2.0", "id": id, "error": error })
+        }
+    })
+}
+
+struct Session {
+    authorized: bool,
+    rejected: bool,
+    outgoing: mpsc::UnboundedSender<String>,
+    subscription: Option<JoinHandle<()>>,
+}
+
+impl Session {
+    // 返回需要写回的响应，通知没有响应
+    async fn handle(&mut self, line: &str) -> Option<String> {
+        let value: Value = match serde_json::from_str(line) {
+            Ok(value) => value,
+            Err(e) => {
+                let error = RpcError::new(PARSE_ERROR, e.to_string());
+                return Some(response(Value::Null, Err(error)));
+            }
+        };
+        let id = value.get("id").cloned().unwrap_or(Value::Null);
+        let request = match serde_json::from_value::<Request>(value) {
+            Ok(request) if request.jsonrpc.as_deref().is_none_or(|v| v == "2.0") => request,
+            _ => {
+                let error = RpcError::new(INVALID_REQUEST, "Invalid JSON-RPC request");
+                return Some(response(id, Err(error)));
+            }
+        };
+        let result = self.call(&request.method, request.params).await;
+        request.id.map(|id| response(id, result))
+    }
+
+    async fn call(&mut self, method: &str, args: Value) -> Result<Value, RpcError> {
+        if method == "auth" {
+            let args: AuthParams = params(args)?;
+            if !token_matches(&args.token, &load_token()?) {
+                // 令牌错误时回复后断开连接，猜测令牌需要不断重连
+                self.rejected = true;
+                return Err(RpcError::new(UNAUTHORIZED, "Invalid token"));
+            }
+            self.authorized = true;
+            return Ok(Value::Bool(true));
+        }
+        if !self.authorized {
+            return Err(RpcError::new(
+                UNAUTHORIZED,
+                "Call auth with the control token first",
+            ));
+        }
+        match method {
+            "list" => to_value(blocking(get_account_list).await?),
+            "current" => to_value(blocking(get_current_account).await?),
+            "switch" => {
+                let args: AliasParams = params(args)?;
+                to_value(blocking(move || switch_to_account(args.alias)).await?)
+            }
+            "save" => {
+                let args: AliasParams = params(args)?;
+                to_value(blocking(move || save_current_account(args.alias)).await?)
+            }
+            "sync" => {
+                let args: SyncParams = params(args)?;
+                let report = match args.operation {
+                    SyncOperation::Push => sync_push_with_manifest(args.remote).await?,
+                    SyncOperation::Pull => sync_pull(args.remote).await?,
+                };
+                to_value(report)
+            }
+            "subscribe" => {
+                if self.subscription.is_none() {
+                    self.subscription = Some(self.forward_events());
+                }
+                Ok(Value::Bool(true))
+            }
+            _ => Err(RpcError::new(
+                METHOD_NOT_FOUND,
+                format!("Unknown method: {}", method),
+            )),
+        }
+    }
+
+    fn forward_events(&self) -> JoinHandle<()> {
+        let mut events = EVENTS.subscribe();
+        let outgoing = self.outgoing.clone();
+        tokio::spawn(async move {
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => break,
+                };
+                let event = json!({ "jsonrpc": "2.0", "method": "event", "params": event });
+                if outgoing.send(message(event)).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+async fn handle_connection(stream: TcpStream, mut shutdown: watch::Receiver<bool>) {
+    let _ = stream.set_nodelay(true);
+    let (read, mut write) = stream.into_split();
+    // 响应与事件通知都经由同一个写入任务，避免交错
+    let (outgoing, mut queue) = mpsc::unbounded_channel::<String>();
+    let writer = tokio::spawn(async move {
+        while let Some(line) = queue.recv().await {
+            if write.write_all(line.as_bytes()).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut reader = BufReader::new(read);
+    let mut session = Session {
+        authorized: false,
+        rejected: false,
+        outgoing: outgoing.clone(),
+        subscription: None,
+    };
+    loop {
+        let mut line = String::new();
+        let mut limited = (&mut reader).take(MAX_LINE_LEN);
+        let read = tokio::select! {
+            _ = shutdown.changed() => break,
+            read = limited.read_line(&mut line) => read,
+        };
+        match read {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {}
+        }
+        if !line.ends_with('\n') && line.len() as u64 >= MAX_LINE_LEN {
+            let error = RpcError::new(INVALID_REQUEST, "Request is too large");
+            let _ = outgoing.send(response(Value::Null, Err(error)));
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = session.handle(&line).await {
+            let _ = outgoing.send(response);
+        }
+        if session.rejected {
+            break;
+        }
+    }
+
+    if let Some(subscription) = session.subscription.take() {
+        subscription.abort();
+    }
+    drop(session);
+    drop(outgoing);
+    // 写完已排队的响应后关闭连接
+    let _ = writer.await;
+}
+
+async fn serve(listener: std::net::TcpListener, mut shutdown: watch::Receiver<bool>) {
+    let Ok(listener) = TcpListener::from_std(listener) else {
+        return;
+    };
+    let mut backoff = ACCEPT_BACKOFF_MIN;
+    loop {
+        match listener.accept().await {
+            Ok((stream, _)) => {
+                backoff = ACCEPT_BACKOFF_MIN;
+                tokio::spawn(handle_connection(stream, shutdown.clone()));
+            }
+            // 例如文件描述符耗尽，等待一段时间再接受连接，避免空转
+            Err(_) => {
+                tokio::select! {
+                    _ = shutdown.changed() => return,
+                    _ = tokio::time::sleep(backoff) => {}
+                }
+                backoff = (backoff * 2).min(ACCEPT_BACKOFF_MAX);
+            }
+        }
+    }
+}
+
+// ============== 控制服务 API ==============
+
+/// 按设置（重新）启动控制服务；设置中未开启时停止服务并返回未运行的状态
+pub fn start_control_server() -> anyhow::Result<ControlServerStatus> {
+    stop_control_server()?;
+    let settings = load_settings()?.control_server;
+    if !settings.enabled {
+        return Ok(ControlServerStatus::default());
+    }
+    load_token()?;
+    let listener = std::net::TcpListener::bind(("127.0.0.1", settings.port))
+        .with_context(|| format!("Failed to listen on port {}", settings.port))?;
+    listener.set_nonblocking(true)?;
+    let port = listener.local_addr()?.port();
+    let (shutdown, receiver) = watch::channel(false);
+    let task = RUNTIME.spawn(serve(listener, receiver));
+    *SERVER.lock().unwrap() = Some(Server {
+        port,
+        task,
+        shutdown,
+    });
+    Ok(ControlServerStatus {
+        running: true,
+        port: Some(port),
+    })
+}
+
+/// 停止控制服务并断开所有连接
+pub fn stop_control_server() -> anyhow::Result<()> {
+    if let Some(server) = SERVER.lock().unwrap().take() {
+        server.task.abort();
+        let _ = server.shutdown.send(true);
+    }
+    Ok(())
+}
+
+/// 获取控制服务状态
+pub fn get_control_server_status() -> anyhow::Result<ControlServerStatus> {
+    Ok(match SERVER.lock().unwrap().as_ref() {
+        Some(server) => ControlServerStatus {
+            running: true,
+            port: Some(server.port),
+        },
+        None => ControlServerStatus::default(),
+    })
+}
+
+/// 获取客户端认证使用的令牌，首次调用时生成
+pub fn get_control_token() -> anyhow::Result<String> {
+    load_token()
+}
+
+/// 重新生成令牌，之后使用旧令牌的连接无法再认证
+pub fn regenerate_control_token() -> anyhow::Result<String> {
+    write_token()
+}
@@ -2,24 +2,94 @@ use anyhow::{bail, Context, Result};
 use directories::BaseDirs;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use crate::api::audit::{AuditOperation, AuditRecord};
+use crate::api::health::{assess_cache_file, HealthStatus};
+use crate::api::control::ControlEvent;
+use crate::api::hooks::{run_post_hooks, run_pre_hooks, HookContext, HookEvent, HookRun};
+use crate::api::instance::lock_instance;
+use crate::api::settings::load_settings;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountInfo {
     pub alias: String,
     pub updated_at: i64,
+    pub group: Option<String>,
+    pub tags: Vec<String>,
+    pub notes: Option<String>,
+    pub last_used_at: Option<i64>,
+    pub sync_status: SyncStatus,
+    pub sync_policy: AccountSyncPolicy,
+    pub health: HealthStatus,
+}
+
+/// 账号相对于上一次云端同步的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncStatus {
+    /// 从未同步过
+    NeverSynced,
+    /// 本地内容与上次同步时一致
+    Synced,
+    /// 上次同步后本地又有修改
+    Modified,
+}
+
+/// 单个账号的云端同步策略
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountSyncPolicy {
+    /// 只保存在本地，不参与同步
+    Never,
+    /// 只上传，不会被远端内容覆盖
+    PushOnly,
+    /// 上传与下载
+    #[default]
+    TwoWay,
+}
+
+impl AccountSyncPolicy {
+    pub(crate) fn allows_push(self) -> bool {
+        self != AccountSyncPolicy::Never
+    }
+
+    pub(crate) fn allows_pull(self) -> bool {
+        self == AccountSyncPolicy::TwoWay
+    }
+}
+
+pub(crate) fn file_updated_at(path: &Path) -> Result<i64> {
+    let metadata = fs::metadata(path)?;
+    Ok(metadata
+        .modified()
+        .unwrap_or_else(|_| SystemTime::now())
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64)
+}
+
+pub(crate) fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs() as i64
 }
 
-// 帮助函数：获取游戏数据目录 AppData\LocalLow\Hypergryph\Endfield
+// 帮助函数：获取游戏数据目录 AppData\LocalLow\Hypergryph\Endfield，可在设置中自定义
 fn get_endfield_dir() -> Result<PathBuf> {
-    let base_dirs = BaseDirs::new().context("Could not find base directories")?;
-    let path = base_dirs
-        .home_dir()
-        .join("AppData")
-        .join("LocalLow")
-        .join("Hypergryph")
-        .join("Endfield");
+    let custom_dir = load_settings()?.paths.game_data_dir;
+    let path = match custom_dir {
+        Some(dir) => PathBuf::from(dir),
+        None => {
+            let base_dirs = BaseDirs::new().context("Could not find base directories")?;
+            base_dirs
+                .home_dir()
+                .join("AppData")
+                .join("LocalLow")
+                .join("Hypergryph")
+                .join("Endfield")
+        }
+    };
     
     if !path.exists() {
         anyhow::bail!("Endfield directory not found");
@@ -28,7 +98,7 @@ fn get_endfield_dir() -> Result<PathBuf> {
 }
 
 // 帮助函数：获取应用自身数据存储目录 AppData\Roaming\EndSwitcher
-fn get_app_data_dir() -> Result<PathBuf> {
+pub(crate) fn get_app_data_dir() -> Result<PathBuf> {
     let base_dirs = BaseDirs::new().context("Could not find base directories")?;
     let _path = base_dirs.config_dir().join("EndSwitcher");
     // 对 Windows 而言 config_dir 一般就是 AppData\Roaming
@@ -40,7 +110,7 @@ fn get_app_data_dir() -> Result<PathBuf> {
     Ok(path)
 }
 
-fn get_accounts_dir() -> Result<PathBuf> {
+pub(crate) fn get_accounts_dir() -> Result<PathBuf> {
     let path = get_app_data_dir()?.join("accounts");
     if !path.exists() {
         fs::create_dir_all(&path)?;
@@ -48,7 +118,7 @@ fn get_accounts_dir() -> Result<PathBuf> {
     Ok(path)
 }
 
-fn validate_alias(alias: &str) -> Result<&str> {
+pub(crate) fn validate_alias(alias: &str) -> Result<&str> {
     let alias = alias.trim();
     if alias.is_empty() {
         bail!("Alias cannot be empty");
@@ -64,11 +134,44 @@ fn validate_alias(alias: &str) -> Result<&str> {
     Ok(alias)
 }
 
-fn get_account_cache_file(alias: &str) -> Result<PathBuf> {
+pub(crate) fn get_account_cache_file(alias: &str) -> Result<PathBuf> {
     let alias = validate_alias(alias)?;
     Ok(get_accounts_dir()?.join(alias))
 }
 
+// 覆盖游戏内凭证前先备份一份，按设置中的 backup_depth 保留最近若干份
+fn backup_game_cache(game_cache_file: &Path) -> Result<()> {
+    let depth = load_settings()?.backup_depth as usize;
+    if depth == 0 || !game_cache_file.is_file() {
+        return Ok(());
+    }
+    let backups_dir = get_app_data_dir()?.join("backups");
+    fs::create_dir_all(&backups_dir)?;
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    fs::copy(game_cache_file, backups_dir.join(format!("login_cache.{}", now)))?;
+
+    // 文件名中的时间戳定长递增，按名称排序即按时间排序
+    let mut backups: Vec<PathBuf> = fs::read_dir(&backups_dir)?
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.is_file()
+                && p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with("login_cache."))
+        })
+        .collect();
+    backups.sort();
+    let excess = backups.len().saturating_sub(depth);
+    for old in &backups[..excess] {
+        fs::remove_file(old)?;
+    }
+    Ok(())
+}
+
 // 查找当前游戏内的 login_cache 文件所在的路径
 pub fn find_login_cache_path() -> Result<String> {
     let endfield_dir = get_endfield_dir()?;
@@ -101,35 +204,71 @@ pub fn get_account_list() -> anyhow::Result<Vec<AccountInfo>> {
         let path = entry.path();
         if path.is_file() {
             if let Some(alias) = path.file_name().and_then(|n| n.to_str()) {
-                let metadata = fs::metadata(&path)?;
-                let updated_at = metadata.modified()
-                    .unwrap_or_else(|_| SystemTime::now())
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_secs() as i64;
+                let updated_at = file_updated_at(&path)?;
+                let health = assess_cache_file(&path, updated_at)
+                    .map(|h| h.status)
+                    .unwrap_or(HealthStatus::Invalid);
 
                 accounts.push(AccountInfo {
                     alias: alias.to_string(),
                     updated_at,
+                    group: None,
+                    tags: Vec::new(),
+                    notes: None,
+                    last_used_at: None,
+                    sync_status: SyncStatus::NeverSynced,
+                    sync_policy: AccountSyncPolicy::default(),
+                    health,
                 });
             }
         }
     }
     
-    accounts.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+    // 未手动排序的账号按修改时间倒序，随后由元数据补全分组、标签等信息并应用手动顺序
+    accounts.sort_by_key(|a| std::cmp::Reverse(a.updated_at));
+    crate::api::meta::apply_metadata(&mut accounts)?;
     Ok(accounts)
 }
 
-/// 保存当前登录的账号
-pub fn save_current_account(alias: String) -> anyhow::Result<()> {
-    let current_cache = find_login_cache_path()?;
-    let target_file = get_account_cache_file(&alias)?;
+/// 保存当前登录的账号，返回运行过的钩子
+/// 前置钩子失败时取消保存；后置钩子的失败只体现在返回结果中
+pub fn save_current_account(alias: String) -> anyhow::Result<Vec<HookRun>> {
+    let mut audit = AuditRecord::new(AuditOperation::Save, Some(&alias));
+    let result = save_account(&alias, &mut audit);
+    audit.finish(&result);
+    result
+}
+
+fn save_account(alias: &str, audit: &mut AuditRecord) -> Result<Vec<HookRun>> {
+    let lock = lock_instance("saving an account")?;
+    let current_cache = PathBuf::from(find_login_cache_path()?);
+    let target_file = get_account_cache_file(alias)?;
+    audit.source(&current_cache).target(&target_file);
+    let context = HookContext {
+        alias,
+        account_file: &target_file,
+        login_cache: &current_cache,
+    };
+    let mut runs = run_pre_hooks(HookEvent::PreSave, &context)?;
     fs::copy(&current_cache, &target_file)?;
-    Ok(())
+    crate::api::autosync::notify_local_change();
+    // 后置钩子可能较慢，不占用应用锁
+    drop(lock);
+    runs.extend(run_post_hooks(HookEvent::PostSave, &context));
+    Ok(runs)
 }
 
-/// 切换到指定账号
-pub fn switch_to_account(alias: String) -> anyhow::Result<()> {
+/// 切换到指定账号，返回运行过的钩子
+/// 前置钩子失败时取消切换；后置钩子的失败只体现在返回结果中
+pub fn switch_to_account(alias: String) -> anyhow::Result<Vec<HookRun>> {
+    let mut audit = AuditRecord::new(AuditOperation::Switch, Some(&alias));
+    let result = switch_account(&alias, &mut audit);
+    audit.finish(&result);
+    result
+}
+
+fn switch_account(alias: &str, audit: &mut AuditRecord) -> Result<Vec<HookRun>> {
+    let lock = lock_instance("switching accounts")?;
     // 确保有文件存放的路径可用，即使找不到现在的 cache_path 也可以把文件写入第一个 sdk_data_ 目录！
     // 不过，为了安全起见，通常切换时游戏中本来就已经有一个 cache_path
     let endfield_dir = get_endfield_dir()?;
@@ -151,22 +290,60 @@ pub fn switch_to_account(alias: String) -> anyhow::Result<()> {
     let target_game_sdk_dir = target_game_sdk_dir
         .context("No sdk_data_* directory found in game folder. Please start the game at least once.")?;
         
-    let source_cache = get_account_cache_file(&alias)?;
+    let source_cache = get_account_cache_file(alias)?;
     if !(source_cache.exists() && source_cache.is_file()) {
         anyhow::bail!("Saved account not found");
     }
     
     let game_cache_file = target_game_sdk_dir.join("login_cache");
+    audit.source(&source_cache).target(&game_cache_file);
+    let context = HookContext {
+        alias,
+        account_file: &source_cache,
+        login_cache: &game_cache_file,
+    };
+    let mut runs = run_pre_hooks(HookEvent::PreSwitch, &context)?;
+    backup_game_cache(&game_cache_file)?;
     fs::copy(&source_cache, &game_cache_file)?;
+    crate::api::meta::touch_last_used(validate_alias(alias)?)?;
+    crate::api::autosync::notify_local_change();
+    drop(lock);
+    runs.extend(run_post_hooks(HookEvent::PostSwitch, &context));
+    crate::api::control::publish_event(ControlEvent::Switched {
+        alias: alias.to_string(),
+    });
     
-    Ok(())
+    Ok(runs)
+}
+
+/// 获取游戏当前登录的账号（内容与游戏凭证相同的已保存账号），都不相同时返回 `None`
+pub fn get_current_account() -> anyhow::Result<Option<String>> {
+    let current = fs::read(find_login_cache_path()?)?;
+    for acc in get_account_list()? {
+        let saved = fs::read(get_account_cache_file(&acc.alias)?)?;
+        if saved == current {
+            return Ok(Some(acc.alias));
+        }
+    }
+    Ok(None)
 }
 
 /// 删除指定账号
 pub fn delete_account(alias: String) -> anyhow::Result<()> {
-    let target_file = get_account_cache_file(&alias)?;
+    let mut audit = AuditRecord::new(AuditOperation::Delete, Some(&alias));
+    let result = remove_account(&alias, &mut audit);
+    audit.finish(&result);
+    result
+}
+
+fn remove_account(alias: &str, audit: &mut AuditRecord) -> Result<()> {
+    let _lock = lock_instance("deleting an account")?;
+    let target_file = get_account_cache_file(alias)?;
+    audit.source(&target_file);
     if target_file.exists() && target_file.is_file() {
         fs::remove_file(&target_file)?;
     }
+    crate::api::meta::forget_account(validate_alias(alias)?)?;
+    crate::api::autosync::notify_local_change();
     Ok(())
 }
@@ -0,0 +1,388 @@
+use anyhow::{anyhow, bail, Context, Result};
+use async_trait::async_trait;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::process::{Output, Stdio};
+use std::sync::Mutex;
+use tokio::process::Command;
+use tokio::sync::OnceCell;
+
+use crate::api::backend::{host_name, RemoteStat, SyncBackend};
+use crate::api::bundle::{derive_key, seal, unseal, SALT_LEN, SEAL_OVERHEAD};
+use crate::api::local_folder::{LocalFolderBackend, LocalFolderConfig};
+use crate::api::transfer::part_path;
+
+const REMOTE_NAME: &str = "origin";
+// 仓库中由同步引擎管理的目录
+const CONFIG_DIR: &str = "EndSwitcherConfig";
+
+// 仓库中的文件结构：魔数 + 16 字节盐 + `seal` 的输出，密钥由 Argon2id 从口令派生
+// 文件路径作为附加认证数据，文件被互换时无法解密
+const FILE_MAGIC: &[u8; 8] = b"ESCRYPT1";
+const FILE_HEADER_LEN: usize = FILE_MAGIC.len() + SALT_LEN;
+
+/// Git 仓库远端，账号保存在仓库的 `EndSwitcherConfig` 目录下，每次推送生成一次提交
+/// 同步前只对远程仓库做快进合并，本地分支已分叉或该目录有未提交的修改时同步失败，需手动处理
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GitConfig {
+    /// 本地仓库路径，不是仓库时自动初始化
+    pub path: String,
+    /// 远程仓库地址（也可以是本地的裸仓库路径），未设置时只在本地提交
+    #[serde(default)]
+    pub remote_url: Option<String>,
+    #[serde(default = "default_branch")]
+    pub branch: String,
+    /// 加密仓库中文件的口令，同步同一仓库的设备需使用相同的口令
+    #[serde(default)]
+    pub passphrase: String,
+}
+
+fn default_branch() -> String {
+    "main".to_string()
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct GitBackend {
+    repo: PathBuf,
+    remote_url: Option<String>,
+    branch: String,
+    // 工作区中的文件读写与文件夹远端相同
+    files: LocalFolderBackend,
+    // 首次访问时初始化仓库并拉取远程仓库
+    prepared: OnceCell<()>,
+    // 预览同步计划时只读取工作区，不初始化、拉取或合并
+    planning: bool,
+    passphrase: String,
+    // 本次同步写入文件时使用的盐，派生的密钥按盐缓存，避免每个文件都重新派生
+    salt: [u8; SALT_LEN],
+    keys: Mutex<HashMap<[u8; SALT_LEN], [u8; 32]>>,
+}
+
+impl GitBackend {
+    pub(crate) fn new(config: &GitConfig) -> Result<Self> {
+        let path = config.path.trim();
+        if path.is_empty() {
+            bail!("Repository path is required");
+        }
+        let remote_url = config
+            .remote_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string);
+        if config.passphrase.is_empty() {
+            bail!("Passphrase is required to encrypt the repository");
+        }
+        let mut salt = [0u8; SALT_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        Ok(Self {
+            repo: PathBuf::from(path),
+            remote_url,
+            branch: config.branch.trim().to_string(),
+            files: LocalFolderBackend::new(&LocalFolderConfig {
+                path: path.to_string(),
+            })?,
+            prepared: OnceCell::new(),
+            planning: false,
+            passphrase: config.passphrase.clone(),
+            salt,
+            keys: Mutex::new(HashMap::new()),
+        })
+    }
+
+    pub(crate) fn for_planning(config: &GitConfig) -> Result<Self> {
+        Ok(Self {
+            planning: true,
+            ..Self::new(config)?
+        })
+    }
+
+    fn command(&self, args: &[&str]) -> Command {
+        let mut cmd = Command::new("git");
+        cmd.args(args)
+            .current_dir(&self.repo)
+            // 凭据缺失时直接失败，不在后台等待输入
+            .env("GIT_TERMINAL_PROMPT", "0")
+            .stdin(Stdio::null())
+            .kill_on_drop(true);
+        #[cfg(windows)]
+        cmd.creation_flags(0x0800_0000); // CREATE_NO_WINDOW
+        cmd
+    }
+
+    async fn run(mut cmd: Command) -> Result<Output> {
+        cmd.output().await.map_err(|e| {
+            if e.kind() == ErrorKind::NotFound {
+                anyhow::anyhow!("Git is not installed or not in PATH")
+            } else {
+                e.into()
+            }
+        })
+    }
+
+    // 运行 git 命令，失败时返回其错误输出
+    async fn git(&self, args: &[&str]) -> Result<String> {
+        Self::checked(args, Self::run(self.command(args)).await?)
+    }
+
+    fn checked(args: &[&str], output: Output) -> Result<String> {
+        if !output.status.success() {
+            bail!(
+                "git {} failed: {}",
+                args[0],
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    // 只关心命令是否成功，例如检查引用是否存在
+    async fn git_succeeds(&self, args: &[&str]) -> Result<bool> {
+        Ok(Self::run(self.command(args)).await?.status.success())
+    }
+
+    fn key(&self, salt: &[u8; SALT_LEN]) -> Result<[u8; 32]> {
+        let mut keys = self.keys.lock().unwrap();
+        if let Some(key) = keys.get(salt) {
+            return Ok(*key);
+        }
+        let key = derive_key(&self.passphrase, salt)?;
+        keys.insert(*salt, key);
+        Ok(key)
+    }
+
+    fn encrypt(&self, path: &str, data: &[u8]) -> Result<Vec<u8>> {
+        let sealed = seal(&self.key(&self.salt)?, data, path.as_bytes())?;
+        let mut out = Vec::with_capacity(FILE_HEADER_LEN + sealed.len());
+        out.extend_from_slice(FILE_MAGIC);
+        out.extend_from_slice(&self.salt);
+        out.extend_from_slice(&sealed);
+        Ok(out)
+    }
+
+    fn decrypt(&self, path: &str, raw: &[u8]) -> Result<Vec<u8>> {
+        if raw.len() < FILE_HEADER_LEN || &raw[..FILE_MAGIC.len()] != FILE_MAGIC {
+            bail!("{} is not encrypted by EndSwitcher", path);
+        }
+        let salt = raw[FILE_MAGIC.len()..FILE_HEADER_LEN].try_into().unwrap();
+        unseal(&self.key(&salt)?, &raw[FILE_HEADER_LEN..], path.as_bytes()).ok_or_else(|| {
+            anyhow!(
+                "Failed to decrypt {}: wrong passphrase or corrupted file",
+                path
+            )
+        })
+    }
+
+    // 读取并解密工作区中的文件
+    async fn read_plain(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        match self.files.read(path).await? {
+            Some(raw) => self.decrypt(path, &raw).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    // 加密后写入工作区，内容没有变化时保留原文件，避免生成只有密文变化的提交
+    async fn write_plain(&self, path: &str, data: Vec<u8>) -> Result<()> {
+        let existing = self.files.read(path).await?;
+        if existing.is_some_and(|raw| self.decrypt(path, &raw).is_ok_and(|old| old == data)) {
+            return Ok(());
+        }
+        self.files.write(path, self.encrypt(path, &data)?).await
+    }
+
+    async fn prepare(&self) -> Result<()> {
+        if self.planning {
+            return Ok(());
+        }
+        self.prepared
+            .get_or_try_init(|| async {
+                if !self.repo.join(".git").exists() {
+                    tokio::fs::create_dir_all(&self.repo)
+                        .await
+                        .with_context(|| format!("Failed to create {}", self.repo.display()))?;
+                    self.git(&["init", "-q", "-b", &self.branch]).await?;
+                }
+                let Some(url) = &self.remote_url else {
+                    return anyhow::Ok(());
+                };
+                match self.git(&["remote", "get-url", REMOTE_NAME]).await {
+                    Ok(current) if current.trim() == url => {}
+                    Ok(_) => {
+                        self.git(&["remote", "set-url", REMOTE_NAME, url]).await?;
+                    }
+                    Err(_) => {
+                        self.git(&["remote", "add", REMOTE_NAME, url]).await?;
+                    }
+                }
+                self.git(&["fetch", "-q", REMOTE_NAME])
+                    .await
+                    .context("Failed to fetch from remote repository")?;
+                // 远程仓库为空时保留本地分支，首次推送会创建它
+                let tracking = format!("refs/remotes/{}/{}", REMOTE_NAME, self.branch);
+                if self
+                    .git_succeeds(&["rev-parse", "-q", "--verify", &tracking])
+                    .await?
+                {
+                    // 不覆盖本地的修改与提交，只接受快进
+                    let status = self
+                        .git(&["status", "--porcelain", "--", CONFIG_DIR])
+                        .await?;
+                    if !status.trim().is_empty() {
+                        bail!(
+                            "Repository has uncommitted changes in {}, commit or discard them first",
+                            CONFIG_DIR
+                        );
+                    }
+                    self.git(&["merge", "-q", "--ff-only", &tracking])
+                        .await
+                        .with_context(|| {
+                            format!(
+                                "Local branch {} has diverged from the remote repository, merge it manually",
+                                self.branch
+                            )
+                        })?;
+                }
+                Ok(())
+            })
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl SyncBackend for GitBackend {
+    async fn ensure_dir(&self, dir: &str) -> Result<()> {
+        self.prepare().await?;
+        self.files.ensure_dir(dir).await
+    }
+
+    async fn list(&self, dir: &str) -> Result<Vec<String>> {
+        self.prepare().await?;
+        // 预览时仓库可能尚未初始化，视为没有任何文件
+        if self.planning && !self.repo.join(CONFIG_DIR).is_dir() {
+            return Ok(Vec::new());
+        }
+        self.files.list(dir).await
+    }
+
+    // 返回解密后的大小
+    async fn stat(&self, path: &str) -> Result<Option<RemoteStat>> {
+        self.prepare().await?;
+        Ok(self.files.stat(path).await?.map(|stat| RemoteStat {
+            size: stat
+                .size
+                .saturating_sub((FILE_HEADER_LEN + SEAL_OVERHEAD) as u64),
+            ..stat
+        }))
+    }
+
+    async fn get(&self, path: &str, target: &Path, expected_hash: Option<&str>) -> Result<String> {
+        self.prepare().await?;
+        let data = self.read_plain(path).await?.context("File not found")?;
+        let hash = format!("{:x}", Sha256::digest(&data));
+        if expected_hash.is_some_and(|expected| expected != hash) {
+            bail!("Checksum mismatch");
+        }
+        let part = part_path(target)?;
+        let result = async {
+            tokio::fs::write(&part, &data).await?;
+            tokio::fs::rename(&part, target).await
+        }
+        .await;
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(&part).await;
+        }
+        result?;
+        Ok(hash)
+    }
+
+    async fn read(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        self.prepare().await?;
+        self.read_plain(path).await
+    }
+
+    async fn put(&self, path: &str, source: &Path, hash: &str) -> Result<()> {
+        self.prepare().await?;
+        let data = tokio::fs::read(source)
+            .await
+            .with_context(|| format!("Failed to open {}", source.display()))?;
+        if format!("{:x}", Sha256::digest(&data)) != hash {
+            bail!("File changed during upload");
+        }
+        self.write_plain(path, data).await
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> Result<()> {
+        self.prepare().await?;
+        self.write_plain(path, data).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        self.prepare().await?;
+        self.files.delete(path).await
+    }
+
+    // 其他设备的并发推送会被 git push 以非快进更新拒绝，不需要锁文件
+    async fn lock(&self) -> Result<String> {
+        self.prepare().await?;
+        Ok(String::new())
+    }
+
+    async fn unlock(&self, _token: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn commit(&self) -> Result<()> {
+        self.prepare().await?;
+        self.git(&["add", "-A", "--", CONFIG_DIR]).await?;
+        // 没有变化时不生成空提交
+        if self
+            .git_succeeds(&["diff", "--cached", "--quiet", "--", CONFIG_DIR])
+            .await?
+        {
+            return Ok(());
+        }
+
+        let previous = self
+            .git(&["rev-parse", "-q", "--verify", "HEAD"])
+            .await
+            .ok()
+            .map(|head| head.trim().to_string());
+        let message = format!("Sync from {}", host_name());
+        let args = ["commit", "-q", "-m", &message, "--", CONFIG_DIR];
+        let mut cmd = self.command(&args);
+        // 仓库未配置提交者时使用默认身份
+        if !self.git_succeeds(&["config", "user.email"]).await? {
+            let email = "endswitcher@localhost";
+            cmd.env("GIT_AUTHOR_NAME", "EndSwitcher")
+                .env("GIT_AUTHOR_EMAIL", email)
+                .env("GIT_COMMITTER_NAME", "EndSwitcher")
+                .env("GIT_COMMITTER_EMAIL", email);
+        }
+        Self::checked(&args, Self::run(cmd).await?)?;
+
+        if self.remote_url.is_some() {
+            let refspec = format!("HEAD:refs/heads/{}", self.branch);
+            if let Err(e) = self.git(&["push", "-q", REMOTE_NAME, &refspec]).await {
+                // 撤销本次提交，下次同步时可以继续快进到远程仓库的最新提交
+                match &previous {
+                    Some(previous) => {
+                        let _ = self.git(&["reset", "-q", "--keep", previous]).await;
+                    }
+                    // 本次是第一次提交时删除分支并移除提交的文件，回到尚无提交的状态
+                    None => {
+                        if self.git(&["update-ref", "-d", "HEAD"]).await.is_ok() {
+                            let _ = self.git(&["rm", "-r", "-q", "-f", "--", CONFIG_DIR]).await;
+                        }
+                    }
+                }
+                return Err(e.context("Failed to push to remote repository, please sync again"));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,341 @@
+use anyhow::Result;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+use std::time::SystemTime;
+
+use crate::api::endfield::{file_updated_at, get_account_cache_file, get_account_list, unix_now};
+
+// 未能解析出过期时间时，按距上次刷新的时长估计
+const AGING_AFTER_SECS: i64 = 7 * 24 * 60 * 60;
+const EXPIRED_AFTER_SECS: i64 = 30 * 24 * 60 * 60;
+// 解析出的过期时间距现在不足该时长时视为即将过期
+const EXPIRING_SOON_SECS: i64 = 3 * 24 * 60 * 60;
+
+// 合理的时间戳范围（2000 年至 2100 年），用于排除误识别的数字
+const MIN_PLAUSIBLE_TS: i64 = 946_684_800;
+const MAX_PLAUSIBLE_TS: i64 = 4_102_444_800;
+
+/// 已保存凭证的健康状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HealthStatus {
+    /// 凭证较新，大概率可以直接登录
+    Fresh,
+    /// 凭证有一段时间未刷新或即将过期，建议登录后重新保存
+    Aging,
+    /// 凭证大概率已过期
+    LikelyExpired,
+    /// 文件为空或无法识别
+    Invalid,
+}
+
+/// 单个账号的健康检查结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountHealth {
+    pub alias: String,
+    pub status: HealthStatus,
+    /// 文件大小（字节）
+    pub size: u64,
+    /// 距上次刷新的秒数
+    pub age_secs: i64,
+    /// 从凭证内容中解析出的过期时间（Unix 秒）
+    pub expires_at: Option<i64>,
+    /// 检查过程中发现的问题描述
+    pub issues: Vec<String>,
+}
+
+// 毫秒时间戳统一换算为秒，超出合理范围的数字忽略
+fn normalize_timestamp(value: i64) -> Option<i64> {
+    let secs = if value > MAX_PLAUSIBLE_TS * 10 {
+        value / 1000
+    } else {
+        value
+    };
+    (MIN_PLAUSIBLE_TS..=MAX_PLAUSIBLE_TS)
+        .contains(&secs)
+        .then_some(secs)
+}
+
+fn value_as_timestamp(value: &Value) -> Option<i64> {
+    match value {
+        Value::Number(n) => n.as_i64().and_then(normalize_timestamp),
+        Value::String(s) => s.trim().parse::<i64>().ok().and_then(normalize_timestamp),
+        _ => None,
+    }
+}
+
+fn is_expiry_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    // expires_in 之类的相对时长无法换算为绝对时间，跳过
+    key == "exp" || (key.contains("expire") && !key.ends_with("in"))
+}
+
+// 解析 JWT 载荷中的 exp 字段
+fn jwt_expiry(token: &str) -> Option<i64> {
+    let mut parts = token.split('.');
+    let (_, payload, _) = (parts.next()?, parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    let decoded = URL_SAFE_NO_PAD.decode(payload.trim_end_matches('=')).ok()?;
+    let claims: Value = serde_json::from_slice(&decoded).ok()?;
+    claims.get("exp").and_then(value_as_timestamp)
+}
+
+// 在任意文本中查找形如 JWT 的片段
+fn scan_text_for_jwt(text: &str, found: &mut Vec<i64>) {
+    let is_token_char = |c: char| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '=');
+    for candidate in text.split(|c: char| !is_token_char(c)) {
+        if candidate.starts_with("eyJ") && candidate.matches('.').count() == 2 {
+            if let Some(exp) = jwt_expiry(candidate) {
+                found.push(exp);
+            }
+        }
+    }
+}
+
+fn collect_expiries(value: &Value, found: &mut Vec<i64>) {
+    match value {
+        Value::Object(map) => {
+            for (key, v) in map {
+                if is_expiry_key(key) {
+                    if let Some(ts) = value_as_timestamp(v) {
+                        found.push(ts);
+                        continue;
+                    }
+                }
+                collect_expiries(v, found);
+            }
+        }
+        Value::Array(items) => items.iter().for_each(|v| collect_expiries(v, found)),
+        Value::String(s) => {
+            scan_text_for_jwt(s, found);
+            // 有些 SDK 会把 JSON 再序列化成字符串保存
+            if s.trim_start().starts_with('{') {
+                if let Ok(inner) = serde_json::from_str::<Value>(s) {
+                    collect_expiries(&inner, found);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+// 凭证内容的解析结果，只取决于文件内容
+#[derive(Debug, Clone)]
+struct ContentScan {
+    size: u64,
+    empty: bool,
+    expires_at: Option<i64>,
+    issues: Vec<String>,
+}
+
+struct CachedScan {
+    modified: SystemTime,
+    len: u64,
+    scan: ContentScan,
+}
+
+// 账号列表与健康检查都会评估每个凭证，按修改时间和大小缓存解析结果，内容未变时不再重复读取
+static SCAN_CACHE: LazyLock<Mutex<HashMap<PathBuf, CachedScan>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn scan_content(data: &[u8]) -> ContentScan {
+    let mut issues = Vec::new();
+    if data.iter().all(|b| b.is_ascii_whitespace()) {
+        issues.push("Credential file is empty".to_string());
+        return ContentScan {
+            size: data.len() as u64,
+            empty: true,
+            expires_at: None,
+            issues,
+        };
+    }
+
+    let mut expiries = Vec::new();
+    // 二进制或加密内容无法解析，只能依据更新时间判断
+    if let Ok(text) = std::str::from_utf8(data) {
+        match serde_json::from_str::<Value>(text) {
+            Ok(json) => collect_expiries(&json, &mut expiries),
+            Err(_) if text.trim_start().starts_with('{') => {
+                issues.push("Credential looks like JSON but failed to parse".to_string());
+                scan_text_for_jwt(text, &mut expiries);
+            }
+            Err(_) => scan_text_for_jwt(text, &mut expiries),
+        }
+    }
+
+    ContentScan {
+        size: data.len() as u64,
+        empty: false,
+        // 多个令牌时以最晚的过期时间为准（通常是 refresh token）
+        expires_at: expiries.into_iter().max(),
+        issues,
+    }
+}
+
+// 结合距上次刷新的时长得出健康状态
+fn classify(alias: String, scan: ContentScan, age_secs: i64, now: i64) -> AccountHealth {
+    let mut issues = scan.issues;
+    let status = match scan.expires_at {
+        _ if scan.empty => HealthStatus::Invalid,
+        Some(exp) if exp <= now => {
+            issues.push("Embedded token has expired".to_string());
+            HealthStatus::LikelyExpired
+        }
+        Some(exp) if exp - now <= EXPIRING_SOON_SECS => {
+            issues.push("Embedded token expires soon".to_string());
+            HealthStatus::Aging
+        }
+        Some(_) => HealthStatus::Fresh,
+        None if age_secs > EXPIRED_AFTER_SECS => {
+            issues.push("Credential has not been refreshed for a long time".to_string());
+            HealthStatus::LikelyExpired
+        }
+        None if age_secs > AGING_AFTER_SECS => HealthStatus::Aging,
+        None => HealthStatus::Fresh,
+    };
+
+    AccountHealth {
+        alias,
+        status,
+        size: scan.size,
+        age_secs,
+        expires_at: scan.expires_at,
+        issues,
+    }
+}
+
+fn cached_scan(path: &Path) -> Result<ContentScan> {
+    let meta = fs::metadata(path)?;
+    let modified = meta.modified()?;
+    let mut cache = SCAN_CACHE.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(cached) = cache.get(path) {
+        if cached.modified == modified && cached.len == meta.len() {
+            return Ok(cached.scan.clone());
+        }
+    }
+    let scan = scan_content(&fs::read(path)?);
+    cache.insert(
+        path.to_path_buf(),
+        CachedScan {
+            modified,
+            len: meta.len(),
+            scan: scan.clone(),
+        },
+    );
+    Ok(scan)
+}
+
+// 根据文件内容与更新时间评估单个凭证
+pub(crate) fn assess_cache_file(path: &Path, updated_at: i64) -> Result<AccountHealth> {
+    let alias = path
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let scan = cached_scan(path)?;
+    let now = unix_now();
+    Ok(classify(alias, scan, (now - updated_at).max(0), now))
+}
+
+/// 检查所有已保存账号的凭证健康状态
+pub fn check_accounts_health() -> anyhow::Result<Vec<AccountHealth>> {
+    let mut result = Vec::new();
+    for acc in get_account_list()? {
+        let path = get_account_cache_file(&acc.alias)?;
+        result.push(assess_cache_file(&path, acc.updated_at)?);
+    }
+    Ok(result)
+}
+
+/// 检查单个账号的凭证健康状态
+pub fn check_account_health(alias: String) -> anyhow::Result<AccountHealth> {
+    let path = get_account_cache_file(&alias)?;
+    if !path.is_file() {
+        anyhow::bail!("Saved account not found");
+    }
+    let updated_at = file_updated_at(&path)?;
+    assess_cache_file(&path, updated_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_800_000_000;
+    const DAY: i64 = 24 * 60 * 60;
+
+    fn jwt(exp: i64) -> String {
+        let claims = URL_SAFE_NO_PAD.encode(format!(r#"{{"sub":"1","exp":{}}}"#, exp));
+        format!("eyJhbGciOiJIUzI1NiJ9.{}.c2lnbmF0dXJl", claims)
+    }
+
+    fn assess(data: &str, age_secs: i64) -> AccountHealth {
+        classify(
+            "alice".to_string(),
+            scan_content(data.as_bytes()),
+            age_secs,
+            NOW,
+        )
+    }
+
+    #[test]
+    fn empty_file_is_invalid() {
+        let health = assess(" \n", 0);
+        assert_eq!(health.status, HealthStatus::Invalid);
+        assert_eq!(health.issues, ["Credential file is empty"]);
+    }
+
+    #[test]
+    fn embedded_expiry_takes_precedence_over_age() {
+        let fresh = format!(r#"{{"token":"{}"}}"#, jwt(NOW + 30 * DAY));
+        let health = assess(&fresh, 60 * DAY);
+        assert_eq!(health.status, HealthStatus::Fresh);
+        assert_eq!(health.expires_at, Some(NOW + 30 * DAY));
+
+        let soon = format!(r#"{{"token":"{}"}}"#, jwt(NOW + DAY));
+        assert_eq!(assess(&soon, 0).status, HealthStatus::Aging);
+        let expired = format!("Bearer {}", jwt(NOW - 1));
+        assert_eq!(assess(&expired, 0).status, HealthStatus::LikelyExpired);
+    }
+
+    #[test]
+    fn latest_expiry_wins_and_millis_are_normalized() {
+        let data = format!(
+            r#"{{"access":"{}","session":"{{\"expiresAt\":{}}}"}}"#,
+            jwt(NOW - DAY),
+            (NOW + 10 * DAY) * 1000
+        );
+        let health = assess(&data, 0);
+        assert_eq!(health.expires_at, Some(NOW + 10 * DAY));
+        assert_eq!(health.status, HealthStatus::Fresh);
+    }
+
+    #[test]
+    fn relative_and_implausible_values_are_ignored() {
+        let data = r#"{"expires_in":7200,"expire_time":12345,"created":1700000000}"#;
+        let health = assess(data, 0);
+        assert_eq!(health.expires_at, None);
+        assert_eq!(health.status, HealthStatus::Fresh);
+    }
+
+    #[test]
+    fn age_is_used_without_expiry() {
+        assert_eq!(assess("opaque", 6 * DAY).status, HealthStatus::Fresh);
+        assert_eq!(assess("opaque", 8 * DAY).status, HealthStatus::Aging);
+        assert_eq!(
+            assess("opaque", 31 * DAY).status,
+            HealthStatus::LikelyExpired
+        );
+        let broken = assess("{\"token\":", 0);
+        assert_eq!(
+            broken.issues,
+            ["Credential looks like JSON but failed to parse"]
+        );
+    }
+}
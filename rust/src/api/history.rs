@@ -0,0 +1,307 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::api::audit::{AuditOperation, AuditRecord};
+use crate::api::backend::{open_backend, SyncBackend};
+use crate::api::endfield::{file_updated_at, get_account_cache_file, unix_now, validate_alias};
+use crate::api::instance::lock_instance_async;
+use crate::api::meta::mark_synced;
+use crate::api::settings::load_settings;
+use crate::api::sync::{find_remote, with_remote_lock};
+use crate::api::transfer::get_transfer_dir;
+
+// 远端历史结构：
+//   EndSwitcherConfig/blobs/<sha256>  每个版本的内容，按哈希寻址
+//   EndSwitcherConfig/history.json    每个账号的版本列表（新版本在前）及当前版本
+pub(crate) const HISTORY_FILE: &str = "history.json";
+pub(crate) const BLOBS_DIR: &str = "blobs";
+
+/// 远端保存的一个账号版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteVersion {
+    /// 内容的 SHA-256（十六进制）
+    pub hash: String,
+    pub size: u64,
+    /// 上传时本地凭证的更新时间
+    pub updated_at: i64,
+    pub uploaded_at: i64,
+    /// 是否为远端当前版本
+    pub current: bool,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct VersionEntry {
+    hash: String,
+    size: u64,
+    updated_at: i64,
+    uploaded_at: i64,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AccountHistory {
+    current: Option<String>,
+    #[serde(default)]
+    versions: Vec<VersionEntry>,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct RemoteHistory {
+    #[serde(default)]
+    accounts: BTreeMap<String, AccountHistory>,
+    // 本次修改中被移除的版本（别名，哈希），不再被引用时删除其内容
+    #[serde(skip)]
+    orphaned: Vec<(String, String)>,
+    // 读取后是否有修改，未修改时不重新写入
+    #[serde(skip)]
+    changed: bool,
+    // 读取时 history.json 的变化标识，写入前用来发现其他设备的并发修改
+    #[serde(skip)]
+    etag: Option<String>,
+}
+
+fn blob_path(hash: &str) -> String {
+    format!("{}/{}", BLOBS_DIR, hash)
+}
+
+impl RemoteHistory {
+    fn is_referenced(&self, hash: &str) -> bool {
+        self.accounts
+            .values()
+            .any(|h| h.versions.iter().any(|v| v.hash == hash))
+    }
+
+    // 远端记录的当前版本哈希，用于校验下载内容
+    pub(crate) fn current_hash(&self, alias: &str) -> Option<&str> {
+        self.accounts.get(alias)?.current.as_deref()
+    }
+
+    // 把已上传的内容记为当前版本，超出 `depth` 的旧版本被移除
+    pub(crate) fn record_version(
+        &mut self,
+        alias: &str,
+        hash: &str,
+        size: u64,
+        updated_at: i64,
+        depth: u32,
+    ) {
+        let entry = self.accounts.entry(alias.to_string()).or_default();
+        if entry.current.as_deref() == Some(hash) {
+            return;
+        }
+        entry.versions.retain(|v| v.hash != hash);
+        entry.versions.insert(
+            0,
+            VersionEntry {
+                hash: hash.to_string(),
+                size,
+                updated_at,
+                uploaded_at: unix_now(),
+            },
+        );
+        entry.current = Some(hash.to_string());
+        self.changed = true;
+        let keep = depth.max(1) as usize;
+        if entry.versions.len() > keep {
+            let removed = entry.versions.drain(keep..);
+            self.orphaned
+                .extend(removed.map(|v| (alias.to_string(), v.hash)));
+        }
+    }
+
+    // 本次记录版本时被移除、尚未写入远端的旧版本（别名，哈希）
+    pub(crate) fn pending_removals(&self) -> &[(String, String)] {
+        &self.orphaned
+    }
+
+    fn set_current(&mut self, alias: &str, hash: &str) {
+        if let Some(history) = self.accounts.get_mut(alias) {
+            history.current = Some(hash.to_string());
+            self.changed = true;
+        }
+    }
+}
+
+async fn history_etag(backend: &dyn SyncBackend) -> Result<Option<String>> {
+    Ok(backend
+        .stat(HISTORY_FILE)
+        .await
+        .context("Failed to check remote history")?
+        .and_then(|stat| stat.etag))
+}
+
+pub(crate) async fn fetch_history(backend: &dyn SyncBackend) -> Result<RemoteHistory> {
+    let etag = history_etag(backend).await?;
+    let data = backend
+        .read(HISTORY_FILE)
+        .await
+        .context("Failed to download remote history")?;
+    let mut history = match data {
+        Some(data) => {
+            serde_json::from_slice::<RemoteHistory>(&data).context("Remote history is corrupted")?
+        }
+        None => RemoteHistory::default(),
+    };
+    history.etag = etag;
+    Ok(history)
+}
+
+pub(crate) async fn store_history(
+    backend: &dyn SyncBackend,
+    history: &mut RemoteHistory,
+) -> Result<Vec<(String, String)>> {
+    if !history.changed {
+        return Ok(Vec::new());
+    }
+    if let Some(expected) = &history.etag {
+        if history_etag(backend).await?.as_ref() != Some(expected) {
+            bail!("Remote history was changed by another device, please sync again");
+        }
+    }
+    backend
+        .write(HISTORY_FILE, serde_json::to_vec(history)?)
+        .await
+        .context("Failed to upload remote history")?;
+    history.changed = false;
+
+    // 历史写入成功后再删除过期内容，保证历史中引用的内容始终存在
+    // 返回被移除的版本（别名，哈希）
+    let mut removed = Vec::new();
+    for (alias, hash) in std::mem::take(&mut history.orphaned) {
+        if !history.is_referenced(&hash) {
+            let _ = backend.delete(&blob_path(&hash)).await;
+        }
+        removed.push((alias, hash));
+    }
+    Ok(removed)
+}
+
+// 上传一个版本的内容，远端已有相同内容时跳过
+// 上次推送在写入历史前中断时，内容可能已存在但未被历史引用
+pub(crate) async fn upload_blob(
+    backend: &dyn SyncBackend,
+    history: &RemoteHistory,
+    path: &Path,
+    hash: &str,
+    size: u64,
+) -> Result<()> {
+    if history.is_referenced(hash) {
+        return Ok(());
+    }
+    let blob = blob_path(hash);
+    if backend
+        .stat(&blob)
+        .await?
+        .is_some_and(|stat| stat.size == size)
+    {
+        return Ok(());
+    }
+    backend.put(&blob, path, hash).await
+}
+
+// ============== 远端历史 API ==============
+
+/// 列出账号在远端保存的历史版本（新版本在前），`remote` 为 `None` 时使用第一个远端
+pub async fn list_remote_versions(
+    remote: Option<String>,
+    alias: String,
+) -> anyhow::Result<Vec<RemoteVersion>> {
+    let alias = validate_alias(&alias)?.to_string();
+    let remote = find_remote(remote.as_deref())?;
+    let backend = open_backend(&remote)?;
+    let history = fetch_history(backend.as_ref()).await?;
+
+    let Some(entry) = history.accounts.get(&alias) else {
+        return Ok(Vec::new());
+    };
+    Ok(entry
+        .versions
+        .iter()
+        .map(|v| RemoteVersion {
+            hash: v.hash.clone(),
+            size: v.size,
+            updated_at: v.updated_at,
+            uploaded_at: v.uploaded_at,
+            current: entry.current.as_deref() == Some(v.hash.as_str()),
+        })
+        .collect())
+}
+
+/// 把远端账号恢复到指定历史版本；`apply_locally` 为 `true` 时同时覆盖本地保存的账号
+pub async fn restore_remote_version(
+    remote: Option<String>,
+    alias: String,
+    hash: String,
+    apply_locally: bool,
+) -> anyhow::Result<()> {
+    let alias = validate_alias(&alias)?.to_string();
+    let mut audit = AuditRecord::new(AuditOperation::Restore, Some(&alias));
+    audit.source(&hash);
+    let result = async {
+        let _lock = lock_instance_async("restoring a remote version").await?;
+        let remote = find_remote(remote.as_deref())?;
+        audit.target(&remote.name);
+        let backend = open_backend(&remote)?;
+        let backend = backend.as_ref();
+        with_remote_lock(
+            backend,
+            restore_version(backend, &remote.name, &alias, &hash, apply_locally),
+        )
+        .await
+    }
+    .await;
+    audit.finish(&result);
+    result
+}
+
+async fn restore_version(
+    backend: &dyn SyncBackend,
+    remote: &str,
+    alias: &str,
+    hash: &str,
+    apply_locally: bool,
+) -> Result<()> {
+    let mut history = fetch_history(backend).await?;
+    let known = history
+        .accounts
+        .get(alias)
+        .is_some_and(|h| h.versions.iter().any(|v| v.hash == hash));
+    if !known {
+        bail!("Remote version not found");
+    }
+
+    let staged = get_transfer_dir()?.join(hash);
+    let result = async {
+        backend
+            .get(&blob_path(hash), &staged, Some(hash))
+            .await
+            .context("Failed to download remote version")?;
+
+        // 同步更新远端的当前副本，旧版本客户端直接读取 <alias>
+        backend
+            .put(alias, &staged, hash)
+            .await
+            .with_context(|| format!("Failed to upload {}", alias))?;
+        history.set_current(alias, hash);
+        store_history(backend, &mut history).await?;
+
+        if apply_locally {
+            let target = get_account_cache_file(alias)?;
+            fs::copy(&staged, &target)?;
+            mark_synced(alias, remote, file_updated_at(&target)?, hash)?;
+        }
+        anyhow::Ok(())
+    }
+    .await;
+    let _ = fs::remove_file(&staged);
+    result
+}
+
+pub(crate) fn history_depth() -> Result<u32> {
+    Ok(load_settings()?.remote_history_depth)
+}
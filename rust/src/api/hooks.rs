@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+use crate::api::endfield::get_app_data_dir;
+use crate::api::settings::load_settings;
+
+// 每个输出流保留的最大字节数，超出部分丢弃
+const MAX_OUTPUT_LEN: usize = 64 * 1024;
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+// 钩子被终止后等待其输出关闭的时长，孙进程可能仍持有管道
+const OUTPUT_GRACE: Duration = Duration::from_secs(1);
+
+/// 钩子触发的时机
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HookEvent {
+    /// 切换账号前，退出码非 0 时取消切换
+    PreSwitch,
+    PostSwitch,
+    /// 保存账号前，退出码非 0 时取消保存
+    PreSave,
+    PostSave,
+}
+
+impl HookEvent {
+    fn name(self) -> &'static str {
+        match self {
+            HookEvent::PreSwitch => "pre_switch",
+            HookEvent::PostSwitch => "post_switch",
+            HookEvent::PreSave => "pre_save",
+            HookEvent::PostSave => "post_save",
+        }
+    }
+}
+
+/// 切换或保存账号时运行的外部命令
+/// 通过环境变量获取上下文：`ENDSWITCHER_EVENT`、`ENDSWITCHER_ALIAS`、`ENDSWITCHER_ACCOUNT_FILE`
+/// （已保存的账号文件）、`ENDSWITCHER_LOGIN_CACHE`（游戏凭证）与 `ENDSWITCHER_DATA_DIR`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookConfig {
+    pub event: HookEvent,
+    /// 可执行文件或脚本路径
+    pub command: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// 超过该秒数后终止命令，前置钩子超时视为取消操作
+    #[serde(default = "default_hook_timeout_secs")]
+    pub timeout_secs: u32,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_hook_timeout_secs() -> u32 {
+    10
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// 一次钩子运行的结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HookRun {
+    pub event: HookEvent,
+    pub command: String,
+    /// 退出码，超时、无法启动或被信号终止时为 `None`
+    pub exit_code: Option<i32>,
+    pub timed_out: bool,
+    pub stdout: String,
+    pub stderr: String,
+    /// 无法启动等错误
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl HookRun {
+    fn new(event: HookEvent, command: &str) -> Self {
+        Self {
+            event,
+            command: command.to_string(),
+            exit_code: None,
+            timed_out: false,
+            stdout: String::new(),
+            stderr: String::new(),
+            error: None,
+            duration_ms: 0,
+        }
+    }
+
+    pub(crate) fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+
+    // 前置钩子取消操作时的原因
+    fn failure(&self) -> String {
+        if let Some(error) = &self.error {
+            return error.clone();
+        }
+        if self.timed_out {
+            return "timed out".to_string();
+        }
+        let status = match self.exit_code {
+            Some(code) => format!("exit code {}", code),
+            None => "terminated".to_string(),
+        };
+        match self.stderr.trim().lines().last() {
+            Some(line) => format!("{}: {}", status, line),
+            None => status,
+        }
+    }
+}
+
+// 钩子运行时的上下文
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct HookContext<'a> {
+    pub(crate) alias: &'a str,
+    pub(crate) account_file: &'a Path,
+    pub(crate) login_cache: &'a Path,
+}
+
+// 在后台线程读取输出，超过上限的部分读出后丢弃，避免子进程因管道写满而阻塞
+fn capture(stream: Option<impl Read + Send + 'static>) -> mpsc::Receiver<String> {
+    let (tx, rx) = mpsc::channel();
+    if let Some(mut stream) = stream {
+        std::thread::spawn(move || {
+            let mut kept = Vec::new();
+            let mut buf = [0u8; 4096];
+            while let Ok(n) = stream.read(&mut buf) {
+                if n == 0 {
+                    break;
+                }
+                let room = MAX_OUTPUT_LEN.saturating_sub(kept.len());
+                kept.extend_from_slice(&buf[..n.min(room)]);
+            }
+            let _ = tx.send(String::from_utf8_lossy(&kept).into_owned());
+        });
+    }
+    rx
+}
+
+fn run_hook(hook: &HookConfig, context: &HookContext) -> HookRun {
+    let start = Instant::now();
+    let mut run = HookRun::new(hook.event, &hook.command);
+
+    let mut cmd = Command::new(hook.command.trim());
+    cmd.args(&hook.args)
+        .env("ENDSWITCHER_EVENT", hook.event.name())
+        .env("ENDSWITCHER_ALIAS", context.alias)
+        .env("ENDSWITCHER_ACCOUNT_FILE", context.account_file)
+        .env("ENDSWITCHER_LOGIN_CACHE", context.login_cache)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Ok(dir) = get_app_data_dir() {
+        cmd.env("ENDSWITCHER_DATA_DIR", dir);
+    }
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        cmd.creation_flags(0x0800_0000); // CREATE_NO_WINDOW
+    }
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(e) => {
+            run.error = Some(format!("Failed to start {}: {}", hook.command, e));
+            return run;
+        }
+    };
+    let stdout = capture(child.stdout.take());
+    let stderr = capture(child.stderr.take());
+
+    let deadline = start + Duration::from_secs(hook.timeout_secs.into());
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                run.exit_code = status.code();
+                break;
+            }
+            Ok(None) if Instant::now() >= deadline => {
+                let _ = child.kill();
+                let _ = child.wait();
+                run.timed_out = true;
+                break;
+            }
+            Ok(None) => std::thread::sleep(POLL_INTERVAL),
+            Err(e) => {
+                let _ = child.kill();
+                let _ = child.wait();
+                run.error = Some(format!("Failed to wait for {}: {}", hook.command, e));
+                break;
+            }
+        }
+    }
+    run.stdout = stdout.recv_timeout(OUTPUT_GRACE).unwrap_or_default();
+    run.stderr = stderr.recv_timeout(OUTPUT_GRACE).unwrap_or_default();
+    run.duration_ms = start.elapsed().as_millis() as u64;
+    run
+}
+
+// 前置钩子取消操作的错误，带有已运行的钩子，最后一个为取消操作的钩子
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug)]
+pub(crate) struct HookVeto {
+    pub(crate) runs: Vec<HookRun>,
+}
+
+impl fmt::Display for HookVeto {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.runs.last() {
+            Some(run) => write!(f, "Cancelled by hook {}: {}", run.command, run.failure()),
+            None => write!(f, "Cancelled by hook"),
+        }
+    }
+}
+
+impl std::error::Error for HookVeto {}
+
+fn enabled_hooks(event: HookEvent) -> anyhow::Result<Vec<HookConfig>> {
+    Ok(load_settings()?
+        .hooks
+        .into_iter()
+        .filter(|h| h.enabled && h.event == event)
+        .collect())
+}
+
+// 按配置顺序运行前置钩子，有一个失败时停止运行其余钩子并返回 `HookVeto`
+pub(crate) fn run_pre_hooks(
+    event: HookEvent,
+    context: &HookContext,
+) -> anyhow::Result<Vec<HookRun>> {
+    let mut runs = Vec::new();
+    for hook in enabled_hooks(event)? {
+        let run = run_hook(&hook, context);
+        let succeeded = run.succeeded();
+        runs.push(run);
+        if !succeeded {
+            return Err(HookVeto { runs }.into());
+        }
+    }
+    Ok(runs)
+}
+
+// 按配置顺序运行后置钩子，此时操作已经完成，任何失败（包括无法读取设置）都只记录在结果中
+pub(crate) fn run_post_hooks(event: HookEvent, context: &HookContext) -> Vec<HookRun> {
+    match enabled_hooks(event) {
+        Ok(hooks) => hooks.iter().map(|hook| run_hook(hook, context)).collect(),
+        Err(e) => {
+            let mut run = HookRun::new(event, "");
+            run.error = Some(format!("Failed to load hooks: {:#}", e));
+            vec![run]
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions, TryLockError};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::api::endfield::{get_app_data_dir, unix_now};
+use crate::api::settings::{load_settings, AppSettings};
+
+// 应用数据目录的咨询锁：界面、命令行与自动同步可能同时运行，
+// 修改已保存账号、游戏凭证或执行同步的操作需先获取该锁，持有进程退出时由系统释放
+const LOCK_FILE: &str = "endswitcher.lock";
+// 持有者信息单独保存，Windows 上被锁定的文件不能被其他进程读取
+const HOLDER_FILE: &str = "endswitcher.lock.json";
+const RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Holder {
+    pid: u32,
+    operation: String,
+    acquired_at: i64,
+}
+
+// 释放时先删除持有者信息再解锁，避免删掉下一个持有者写入的信息
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct InstanceLock {
+    file: File,
+    holder_path: PathBuf,
+}
+
+impl Drop for InstanceLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.holder_path);
+        let _ = self.file.unlock();
+    }
+}
+
+fn try_lock(operation: &str) -> Result<Option<InstanceLock>> {
+    let dir = get_app_data_dir()?;
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .truncate(false)
+        .open(dir.join(LOCK_FILE))?;
+    match file.try_lock() {
+        Ok(()) => {}
+        Err(TryLockError::WouldBlock) => return Ok(None),
+        Err(TryLockError::Error(e)) => return Err(e.into()),
+    }
+    let holder = Holder {
+        pid: std::process::id(),
+        operation: operation.to_string(),
+        acquired_at: unix_now(),
+    };
+    let holder_path = dir.join(HOLDER_FILE);
+    // 持有者信息只用于提示，写入失败不影响加锁
+    let _ = fs::write(&holder_path, serde_json::to_vec(&holder)?);
+    Ok(Some(InstanceLock { file, holder_path }))
+}
+
+fn busy_error() -> anyhow::Error {
+    let holder = get_app_data_dir()
+        .ok()
+        .and_then(|dir| fs::read(dir.join(HOLDER_FILE)).ok())
+        .and_then(|data| serde_json::from_slice::<Holder>(&data).ok());
+    match holder {
+        Some(holder) => anyhow!(
+            "EndSwitcher is busy: {} in process {}, try again later",
+            holder.operation,
+            holder.pid
+        ),
+        None => anyhow!("EndSwitcher is busy with another operation, try again later"),
+    }
+}
+
+// 设置无法读取时按默认时长等待，配置文件损坏时仍能获取锁以重置设置
+fn deadline() -> Instant {
+    let secs = load_settings()
+        .map(|s| s.lock_timeout_secs)
+        .unwrap_or_else(|_| AppSettings::default().lock_timeout_secs);
+    Instant::now() + Duration::from_secs(secs.into())
+}
+
+// 获取锁，其他操作进行中时最多等待设置中的时长，超时返回忙碌错误
+// 锁不可重入，持有期间不能再调用同样加锁的接口
+pub(crate) fn lock_instance(operation: &str) -> Result<InstanceLock> {
+    let deadline = deadline();
+    loop {
+        if let Some(lock) = try_lock(operation)? {
+            return Ok(lock);
+        }
+        if Instant::now() >= deadline {
+            return Err(busy_error());
+        }
+        std::thread::sleep(RETRY_INTERVAL);
+    }
+}
+
+pub(crate) async fn lock_instance_async(operation: &str) -> Result<InstanceLock> {
+    let deadline = deadline();
+    loop {
+        if let Some(lock) = try_lock(operation)? {
+            return Ok(lock);
+        }
+        if Instant::now() >= deadline {
+            return Err(busy_error());
+        }
+        tokio::time::sleep(RETRY_INTERVAL).await;
+    }
+}
@@ -0,0 +1,261 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::api::audit::{AuditOperation, AuditRecord};
+use crate::api::endfield::{get_accounts_dir, get_app_data_dir, unix_now};
+use crate::api::instance::{lock_instance, lock_instance_async};
+use crate::api::meta::{restore_synced_state, synced_state, SyncedState};
+use crate::api::report::SyncReport;
+use crate::api::settings::SyncRemote;
+use crate::api::sync::resume_pull;
+
+// 拉取期间的同步日志，应用中途退出后据此继续或回滚：
+//   sync_journal/journal.json      计划下载的账号及完成情况
+//   sync_journal/backup/<alias>    下载前的本地副本
+const JOURNAL_DIR: &str = "sync_journal";
+const JOURNAL_FILE: &str = "journal.json";
+const BACKUP_DIR: &str = "backup";
+
+/// 上次未完成的拉取，应用启动时检查并提示继续或回滚
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterruptedSync {
+    /// 开始时间（Unix 秒）
+    pub started_at: i64,
+    /// 参与拉取的远端，按优先级排列
+    pub remotes: Vec<String>,
+    /// 已下载完成的账号
+    pub completed: Vec<String>,
+    /// 计划下载但尚未完成的账号
+    pub pending: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalEntry {
+    remote: String,
+    alias: String,
+    // 计划下载的远端版本哈希，远端没有版本历史时为 None
+    #[serde(default)]
+    hash: Option<String>,
+    // 下载前本地没有该账号时，回滚会删除下载的文件
+    had_local: bool,
+    previous: SyncedState,
+    completed: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct JournalFile {
+    started_at: i64,
+    remotes: Vec<String>,
+    entries: Vec<JournalEntry>,
+}
+
+fn journal_dir() -> Result<PathBuf> {
+    Ok(get_app_data_dir()?.join(JOURNAL_DIR))
+}
+
+fn load_journal() -> Result<Option<JournalFile>> {
+    let path = journal_dir()?.join(JOURNAL_FILE);
+    if !path.exists() {
+        return Ok(None);
+    }
+    let data = fs::read_to_string(&path)?;
+    let journal = serde_json::from_str(&data).context("Sync journal is corrupted")?;
+    Ok(Some(journal))
+}
+
+// 新的同步会覆盖中断前的记录，必须先处理
+pub(crate) fn ensure_no_interrupted_sync() -> Result<()> {
+    if journal_dir()?.join(JOURNAL_FILE).exists() {
+        bail!("A previous sync was interrupted, resume or roll it back first");
+    }
+    Ok(())
+}
+
+// 一次拉取的同步日志，每次变化都立即写入磁盘
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct PullJournal {
+    dir: PathBuf,
+    state: Mutex<JournalFile>,
+}
+
+impl PullJournal {
+    pub(crate) fn begin(remotes: &[SyncRemote]) -> Result<Self> {
+        ensure_no_interrupted_sync()?;
+        let dir = journal_dir()?;
+        // 清理上次结束时未删干净的备份
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(BACKUP_DIR))?;
+        let journal = Self {
+            dir,
+            state: Mutex::new(JournalFile {
+                started_at: unix_now(),
+                remotes: remotes.iter().map(|r| r.name.clone()).collect(),
+                entries: Vec::new(),
+            }),
+        };
+        journal.save(&journal.state.lock().unwrap())?;
+        Ok(journal)
+    }
+
+    // 打开中断的同步日志，沿用其中的备份
+    pub(crate) fn reopen() -> Result<Option<Self>> {
+        let Some(state) = load_journal()? else {
+            return Ok(None);
+        };
+        Ok(Some(Self {
+            dir: journal_dir()?,
+            state: Mutex::new(state),
+        }))
+    }
+
+    pub(crate) fn remotes(&self) -> Vec<String> {
+        self.state.lock().unwrap().remotes.clone()
+    }
+
+    // 先写入临时文件再替换，中途退出时不会留下不完整的日志
+    fn save(&self, state: &JournalFile) -> Result<()> {
+        let path = self.dir.join(JOURNAL_FILE);
+        let temp = self.dir.join(format!("{}.tmp", JOURNAL_FILE));
+        fs::write(&temp, serde_json::to_vec_pretty(state)?)?;
+        fs::rename(temp, path)?;
+        Ok(())
+    }
+
+    // 下载开始前记录计划（账号及远端版本哈希），并备份将被覆盖的本地账号
+    // 返回中断前已从同一远端下载完成同一版本的账号，继续时无需再次下载
+    pub(crate) fn plan(
+        &self,
+        remote: &str,
+        downloads: &[(&str, Option<&str>)],
+    ) -> Result<HashSet<String>> {
+        let mut completed = HashSet::new();
+        if downloads.is_empty() {
+            return Ok(completed);
+        }
+        let accounts_dir = get_accounts_dir()?;
+        let mut state = self.state.lock().unwrap();
+        for (alias, hash) in downloads {
+            // 继续中断的同步时保留最初的备份
+            if let Some(entry) = state.entries.iter_mut().find(|e| e.alias == *alias) {
+                if entry.completed && entry.remote == remote && entry.hash.as_deref() == *hash {
+                    completed.insert(alias.to_string());
+                    continue;
+                }
+                entry.remote = remote.to_string();
+                entry.hash = hash.map(str::to_string);
+                entry.completed = false;
+                continue;
+            }
+            let local = accounts_dir.join(alias);
+            let had_local = local.exists();
+            if had_local {
+                fs::copy(&local, self.dir.join(BACKUP_DIR).join(alias))
+                    .with_context(|| format!("Failed to back up {}", alias))?;
+            }
+            state.entries.push(JournalEntry {
+                remote: remote.to_string(),
+                alias: alias.to_string(),
+                hash: hash.map(str::to_string),
+                had_local,
+                previous: synced_state(alias)?,
+                completed: false,
+            });
+        }
+        self.save(&state)?;
+        Ok(completed)
+    }
+
+    // 中断前已从 `remote` 下载完成的账号，本地文件即为下载的内容
+    pub(crate) fn downloaded(&self, remote: &str, alias: &str) -> bool {
+        let state = self.state.lock().unwrap();
+        state
+            .entries
+            .iter()
+            .any(|e| e.completed && e.alias == alias && e.remote == remote)
+    }
+
+    pub(crate) fn complete(&self, alias: &str) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        if let Some(entry) = state.entries.iter_mut().find(|e| e.alias == alias) {
+            entry.completed = true;
+        }
+        self.save(&state)
+    }
+
+    // 同步正常结束（包括部分失败）后删除日志与备份
+    pub(crate) fn finish(self) -> Result<()> {
+        fs::remove_file(self.dir.join(JOURNAL_FILE))?;
+        let _ = fs::remove_dir_all(&self.dir);
+        Ok(())
+    }
+
+    // 恢复下载前的本地账号与同步记录，返回内容有变化的账号
+    fn rollback(self) -> Result<Vec<String>> {
+        let accounts_dir = get_accounts_dir()?;
+        let entries = self.state.lock().unwrap().entries.clone();
+        let mut restored = Vec::new();
+        for entry in entries {
+            let local = accounts_dir.join(&entry.alias);
+            let current = fs::read(&local).ok();
+            if entry.had_local {
+                let backup = fs::read(self.dir.join(BACKUP_DIR).join(&entry.alias))
+                    .with_context(|| format!("Backup of {} is missing", entry.alias))?;
+                if current.as_deref() != Some(backup.as_slice()) {
+                    fs::write(&local, backup)?;
+                    restored.push(entry.alias.clone());
+                }
+            } else if current.is_some() {
+                fs::remove_file(&local)?;
+                restored.push(entry.alias.clone());
+            }
+            restore_synced_state(&entry.alias, &entry.previous)?;
+        }
+        self.finish()?;
+        Ok(restored)
+    }
+}
+
+// ============== 同步日志 API ==============
+
+/// 获取上次中断的拉取，没有时返回 `None`
+pub fn get_interrupted_sync() -> anyhow::Result<Option<InterruptedSync>> {
+    Ok(load_journal()?.map(|journal| {
+        let (completed, pending): (Vec<_>, Vec<_>) =
+            journal.entries.into_iter().partition(|e| e.completed);
+        InterruptedSync {
+            started_at: journal.started_at,
+            remotes: journal.remotes,
+            completed: completed.into_iter().map(|e| e.alias).collect(),
+            pending: pending.into_iter().map(|e| e.alias).collect(),
+        }
+    }))
+}
+
+/// 继续中断的拉取：重新从原来的远端拉取，已下载完成的账号不会重复下载
+pub async fn resume_interrupted_sync() -> anyhow::Result<SyncReport> {
+    let _lock = lock_instance_async("syncing").await?;
+    let journal = PullJournal::reopen()?.context("No interrupted sync to resume")?;
+    resume_pull(journal).await
+}
+
+/// 回滚中断的拉取：恢复下载前的本地账号与同步记录，返回被恢复的账号
+pub fn rollback_interrupted_sync() -> anyhow::Result<Vec<String>> {
+    let _lock = lock_instance("rolling back a sync")?;
+    let journal = PullJournal::reopen()?.context("No interrupted sync to roll back")?;
+    let result = journal.rollback();
+    match &result {
+        Ok(restored) => {
+            for alias in restored {
+                let mut audit = AuditRecord::new(AuditOperation::Rollback, Some(alias));
+                audit.target(get_accounts_dir()?.join(alias));
+                audit.finish(&result);
+            }
+        }
+        Err(_) => AuditRecord::new(AuditOperation::Rollback, None).finish(&result),
+    }
+    result
+}
@@ -0,0 +1,178 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use crate::api::endfield::switch_to_account;
+use crate::api::instance::lock_instance;
+use crate::api::settings::{load_settings, update_settings_with};
+
+/// 启动时额外设置的环境变量
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchEnvVar {
+    pub key: String,
+    pub value: String,
+}
+
+/// 游戏启动配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchConfig {
+    /// 游戏本体或启动器的可执行文件路径
+    pub executable: String,
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// 工作目录，未设置时使用可执行文件所在目录
+    #[serde(default)]
+    pub working_dir: Option<String>,
+    /// Linux 下的包装程序，例如 `wine` 或 Proton 脚本
+    #[serde(default)]
+    pub wrapper: Option<String>,
+    /// 传给包装程序的参数，位于可执行文件路径之前，例如 Proton 的 `run`
+    #[serde(default)]
+    pub wrapper_args: Vec<String>,
+    #[serde(default)]
+    pub env: Vec<LaunchEnvVar>,
+}
+
+/// 由本程序启动的游戏进程状态
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameProcessStatus {
+    pub pid: u32,
+    pub running: bool,
+    /// 进程退出码，仍在运行或被信号终止时为 `None`
+    pub exit_code: Option<i32>,
+}
+
+// 已启动进程的退出状态：`None` 表示仍在运行
+static GAME_PROCESSES: LazyLock<Mutex<HashMap<u32, Option<Option<i32>>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn build_command(config: &LaunchConfig) -> Result<Command> {
+    let executable = config.executable.trim();
+    if executable.is_empty() {
+        bail!("Game executable path is required");
+    }
+    let exe_path = Path::new(executable);
+    if !exe_path.is_file() {
+        bail!("Game executable not found: {}", executable);
+    }
+
+    let wrapper = config
+        .wrapper
+        .as_deref()
+        .map(str::trim)
+        .filter(|w| !w.is_empty());
+    let mut cmd = match wrapper {
+        Some(wrapper) => {
+            let mut cmd = Command::new(wrapper);
+            cmd.args(&config.wrapper_args).arg(exe_path);
+            cmd
+        }
+        None => Command::new(exe_path),
+    };
+    cmd.args(&config.args);
+
+    let working_dir = match config.working_dir.as_deref().map(str::trim) {
+        Some(dir) if !dir.is_empty() => PathBuf::from(dir),
+        _ => exe_path
+            .parent()
+            .map(Path::to_path_buf)
+            .unwrap_or_else(|| PathBuf::from(".")),
+    };
+    if !working_dir.is_dir() {
+        bail!("Working directory not found: {}", working_dir.display());
+    }
+    cmd.current_dir(working_dir);
+
+    for var in &config.env {
+        let key = var.key.trim();
+        if key.is_empty() || key.contains('=') {
+            bail!("Invalid environment variable name: {}", var.key);
+        }
+        cmd.env(key, &var.value);
+    }
+    Ok(cmd)
+}
+
+fn spawn_game(config: &LaunchConfig) -> Result<u32> {
+    let mut child = build_command(config)?
+        .spawn()
+        .context("Failed to start game process")?;
+    let pid = child.id();
+    GAME_PROCESSES.lock().unwrap().insert(pid, None);
+
+    // 后台线程等待进程退出并记录退出码，避免产生僵尸进程
+    std::thread::spawn(move || {
+        let code = child.wait().ok().and_then(|status| status.code());
+        GAME_PROCESSES.lock().unwrap().insert(pid, Some(code));
+    });
+    Ok(pid)
+}
+
+fn process_status(pid: u32) -> Result<GameProcessStatus> {
+    let state = *GAME_PROCESSES
+        .lock()
+        .unwrap()
+        .get(&pid)
+        .context("Game process not started by EndSwitcher")?;
+    Ok(GameProcessStatus {
+        pid,
+        running: state.is_none(),
+        exit_code: state.flatten(),
+    })
+}
+
+// ============== 启动 API ==============
+
+pub fn save_launch_config(config: LaunchConfig) -> anyhow::Result<()> {
+    let _lock = lock_instance("saving launch settings")?;
+    update_settings_with(|settings| {
+        settings.launch = Some(config);
+        Ok(())
+    })
+}
+
+pub fn load_launch_config() -> anyhow::Result<LaunchConfig> {
+    load_settings()?.launch.context("Launch config not found")
+}
+
+/// 按启动配置启动游戏，返回进程 PID
+pub fn launch_game() -> anyhow::Result<u32> {
+    let config = load_launch_config()?;
+    spawn_game(&config)
+}
+
+/// 切换到指定账号并启动游戏，返回进程 PID
+pub fn switch_and_launch(alias: String) -> anyhow::Result<u32> {
+    // 先校验启动配置，避免账号已切换但游戏无法启动
+    let config = load_launch_config()?;
+    build_command(&config)?;
+    switch_to_account(alias)?;
+    spawn_game(&config)
+}
+
+/// 查询由本程序启动的游戏进程状态
+pub fn get_game_process_status(pid: u32) -> anyhow::Result<GameProcessStatus> {
+    process_status(pid)
+}
+
+/// 列出本次运行期间启动过的所有游戏进程
+pub fn list_game_processes() -> anyhow::Result<Vec<GameProcessStatus>> {
+    let mut pids: Vec<u32> = GAME_PROCESSES.lock().unwrap().keys().copied().collect();
+    pids.sort_unstable();
+    pids.into_iter().map(process_status).collect()
+}
+
+/// 等待游戏进程退出，返回最终状态
+pub async fn wait_game_exit(pid: u32) -> anyhow::Result<GameProcessStatus> {
+    loop {
+        let status = process_status(pid)?;
+        if !status.running {
+            return Ok(status);
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+}
@@ -0,0 +1,255 @@
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio_util::io::ReaderStream;
+
+use crate::api::backend::{
+    is_temp_file_name, read_lock, temp_file_name, LockInfo, RemoteStat, SyncBackend, LOCK_FILE,
+    LOCK_TTL_SECS,
+};
+use crate::api::transfer::part_path;
+
+/// 本地或挂载文件夹远端，账号保存在 `<path>/EndSwitcherConfig` 下
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocalFolderConfig {
+    pub path: String,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct LocalFolderBackend {
+    // 用户选择的文件夹，不存在时（例如 U 盘未插入）不自动创建
+    base: PathBuf,
+    root: PathBuf,
+}
+
+impl LocalFolderBackend {
+    pub(crate) fn new(config: &LocalFolderConfig) -> Result<Self> {
+        let path = config.path.trim();
+        if path.is_empty() {
+            bail!("Folder path is required");
+        }
+        let base = PathBuf::from(path);
+        let root = base.join("EndSwitcherConfig");
+        Ok(Self { base, root })
+    }
+
+    fn resolve(&self, path: &str) -> Result<PathBuf> {
+        let mut resolved = self.root.clone();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            if segment == "." || segment == ".." || segment.contains('\\') {
+                bail!("Invalid remote path: {}", path);
+            }
+            resolved.push(segment);
+        }
+        Ok(resolved)
+    }
+
+    fn ensure_base(&self) -> Result<()> {
+        if !self.base.is_dir() {
+            bail!("Folder not found: {}", self.base.display());
+        }
+        Ok(())
+    }
+}
+
+// 先写入同目录下的临时文件再替换，避免同步工具或其他设备读到写了一半的文件
+fn temp_path(dest: &Path) -> Result<PathBuf> {
+    let name = dest
+        .file_name()
+        .and_then(|n| n.to_str())
+        .context("Invalid remote path")?;
+    Ok(dest.with_file_name(temp_file_name(name)))
+}
+
+// 边复制边计算 SHA-256
+async fn copy_hashed(source: &Path, dest: &Path) -> Result<String> {
+    let file = tokio::fs::File::open(source)
+        .await
+        .with_context(|| format!("Failed to open {}", source.display()))?;
+    let mut stream = ReaderStream::new(file);
+    let mut out = tokio::fs::File::create(dest).await?;
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        hasher.update(&chunk);
+        out.write_all(&chunk).await?;
+    }
+    out.sync_all().await?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// 文件的修改时间是否早于 `secs` 秒前，无法读取时视为是
+async fn modified_before(path: &Path, secs: i64) -> bool {
+    let modified = match tokio::fs::metadata(path).await.and_then(|m| m.modified()) {
+        Ok(modified) => modified,
+        Err(_) => return true,
+    };
+    modified
+        .elapsed()
+        .is_ok_and(|age| age.as_secs() as i64 > secs)
+}
+
+async fn replace_with(temp: &Path, dest: &Path, result: Result<()>) -> Result<()> {
+    match result {
+        Ok(()) => Ok(tokio::fs::rename(temp, dest).await?),
+        Err(e) => {
+            let _ = tokio::fs::remove_file(temp).await;
+            Err(e)
+        }
+    }
+}
+
+#[async_trait]
+impl SyncBackend for LocalFolderBackend {
+    async fn ensure_dir(&self, dir: &str) -> Result<()> {
+        self.ensure_base()?;
+        tokio::fs::create_dir_all(self.resolve(dir)?).await?;
+        Ok(())
+    }
+
+    async fn list(&self, dir: &str) -> Result<Vec<String>> {
+        self.ensure_base()?;
+        let dir = self.resolve(dir)?;
+        let mut entries = tokio::fs::read_dir(&dir)
+            .await
+            .with_context(|| format!("Failed to read {}", dir.display()))?;
+        let mut names = Vec::new();
+        while let Some(entry) = entries.next_entry().await? {
+            if !entry.file_type().await?.is_file() {
+                continue;
+            }
+            if let Some(name) = entry.file_name().to_str().filter(|n| !is_temp_file_name(n)) {
+                names.push(name.to_string());
+            }
+        }
+        Ok(names)
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<RemoteStat>> {
+        match tokio::fs::metadata(self.resolve(path)?).await {
+            Ok(meta) if meta.is_file() => {
+                // 以修改时间与大小作为变化标识
+                let modified = meta
+                    .modified()
+                    .ok()
+                    .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok());
+                let etag = modified.map(|t| format!("{}-{}", t.as_nanos(), meta.len()));
+                Ok(Some(RemoteStat {
+                    size: meta.len(),
+                    etag,
+                }))
+            }
+            Ok(_) => Ok(None),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn get(&self, path: &str, target: &Path, expected_hash: Option<&str>) -> Result<String> {
+        let source = self.resolve(path)?;
+        let part = part_path(target)?;
+        let result = async {
+            let hash = copy_hashed(&source, &part).await?;
+            if expected_hash.is_some_and(|expected| expected != hash) {
+                bail!("Checksum mismatch");
+            }
+            Ok(hash)
+        }
+        .await;
+        match result {
+            Ok(hash) => {
+                tokio::fs::rename(&part, target).await?;
+                Ok(hash)
+            }
+            Err(e) => {
+                let _ = tokio::fs::remove_file(&part).await;
+                Err(e)
+            }
+        }
+    }
+
+    async fn read(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        match tokio::fs::read(self.resolve(path)?).await {
+            Ok(data) => Ok(Some(data)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn put(&self, path: &str, source: &Path, hash: &str) -> Result<()> {
+        let dest = self.resolve(path)?;
+        let temp = temp_path(&dest)?;
+        let result = async {
+            if copy_hashed(source, &temp).await? != hash {
+                bail!("File changed during upload");
+            }
+            Ok(())
+        }
+        .await;
+        replace_with(&temp, &dest, result).await
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> Result<()> {
+        let dest = self.resolve(path)?;
+        let temp = temp_path(&dest)?;
+        let result = async {
+            let mut out = tokio::fs::File::create(&temp).await?;
+            out.write_all(&data).await?;
+            out.sync_all().await?;
+            Ok(())
+        }
+        .await;
+        replace_with(&temp, &dest, result).await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        match tokio::fs::remove_file(self.resolve(path)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    // 本地文件系统可以原子地创建锁文件
+    async fn lock(&self) -> Result<String> {
+        let path = self.resolve(LOCK_FILE)?;
+        let info = LockInfo::new();
+        let data = serde_json::to_vec(&info)?;
+        for _ in 0..2 {
+            let created = tokio::fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+                .await;
+            match created {
+                Ok(mut file) => {
+                    file.write_all(&data).await?;
+                    file.sync_all().await?;
+                    return Ok(info.token);
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    match read_lock(self).await? {
+                        Some(holder) if !holder.is_stale() => {
+                            bail!("Remote is locked by {}", holder.host)
+                        }
+                        Some(_) => {}
+                        // 无法解析时可能是其他进程刚创建、尚未写完内容
+                        None if !modified_before(&path, LOCK_TTL_SECS).await => {
+                            bail!("Remote is locked by another device")
+                        }
+                        None => {}
+                    }
+                    // 失效的锁，删除后重试一次
+                    self.delete(LOCK_FILE).await?;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        bail!("Failed to create remote lock")
+    }
+}
@@ -0,0 +1,457 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use crate::api::endfield::{
+    get_account_list, get_app_data_dir, unix_now, validate_alias, AccountInfo, AccountSyncPolicy,
+    SyncStatus,
+};
+use crate::api::instance::lock_instance;
+
+// 同步时多个传输会并发记录同步状态，读-改-写期间需串行；
+// 其他进程由修改接口持有的应用锁排除
+static META_LOCK: Mutex<()> = Mutex::new(());
+
+/// 分组视图中的一组账号，`name` 为 `None` 表示未分组
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountGroup {
+    pub name: Option<String>,
+    pub accounts: Vec<AccountInfo>,
+}
+
+// 单个账号的附加信息，按别名存放在 accounts_meta.json 中
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct AccountMeta {
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub notes: Option<String>,
+    #[serde(default)]
+    pub last_used_at: Option<i64>,
+    // 最近一次同步的时间，以及当时本地文件的修改时间
+    #[serde(default)]
+    pub synced_at: Option<i64>,
+    #[serde(default)]
+    pub synced_updated_at: Option<i64>,
+    // 与各远端最近一次同步时本地文件的内容哈希，按远端名称存放
+    #[serde(default)]
+    pub synced_hashes: BTreeMap<String, String>,
+    #[serde(default)]
+    pub sync_policy: AccountSyncPolicy,
+}
+
+// 元数据文件的整体结构：分组顺序、账号手动顺序以及每个账号的附加信息
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct MetaStore {
+    #[serde(default)]
+    pub groups: Vec<String>,
+    #[serde(default)]
+    pub order: Vec<String>,
+    #[serde(default)]
+    pub accounts: BTreeMap<String, AccountMeta>,
+}
+
+fn get_meta_file_path() -> Result<PathBuf> {
+    Ok(get_app_data_dir()?.join("accounts_meta.json"))
+}
+
+pub(crate) fn load_meta() -> Result<MetaStore> {
+    let path = get_meta_file_path()?;
+    if !path.exists() {
+        return Ok(MetaStore::default());
+    }
+    let data = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&data)?)
+}
+
+// 先写入临时文件再替换，中途退出时不会留下不完整的元数据
+pub(crate) fn save_meta(store: &MetaStore) -> Result<()> {
+    let path = get_meta_file_path()?;
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string_pretty(store)?)?;
+    fs::rename(temp, path)?;
+    Ok(())
+}
+
+fn update_meta<T>(f: impl FnOnce(&mut MetaStore) -> Result<T>) -> Result<T> {
+    let _guard = META_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut store = load_meta()?;
+    let out = f(&mut store)?;
+    save_meta(&store)?;
+    Ok(out)
+}
+
+fn validate_label(label: &str, kind: &str) -> Result<String> {
+    let label = label.trim();
+    if label.is_empty() {
+        bail!("{} name cannot be empty", kind);
+    }
+    if label.chars().any(|c| c.is_control()) {
+        bail!("{} name contains invalid characters", kind);
+    }
+    Ok(label.to_string())
+}
+
+fn normalize_tags(tags: Vec<String>) -> Result<Vec<String>> {
+    let mut result: Vec<String> = Vec::new();
+    for tag in tags {
+        let tag = validate_label(&tag, "Tag")?;
+        // 标签比较不区分大小写，保留第一次出现时的写法
+        if !result.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            result.push(tag);
+        }
+    }
+    Ok(result)
+}
+
+// 把元数据中的分组、标签、备注等信息与手动顺序应用到账号列表上
+// 调用方传入的列表需已按修改时间倒序，未出现在手动顺序中的账号会保持原有相对顺序排在后面
+pub(crate) fn apply_metadata(accounts: &mut [AccountInfo]) -> Result<()> {
+    let store = load_meta()?;
+    for acc in accounts.iter_mut() {
+        if let Some(meta) = store.accounts.get(&acc.alias) {
+            acc.group = meta.group.clone();
+            acc.tags = meta.tags.clone();
+            acc.notes = meta.notes.clone();
+            acc.last_used_at = meta.last_used_at;
+            acc.sync_policy = meta.sync_policy;
+            acc.sync_status = match meta.synced_updated_at {
+                None => SyncStatus::NeverSynced,
+                Some(synced) if acc.updated_at <= synced => SyncStatus::Synced,
+                Some(_) => SyncStatus::Modified,
+            };
+        }
+    }
+
+    let rank: HashMap<&str, usize> = store
+        .order
+        .iter()
+        .enumerate()
+        .map(|(i, alias)| (alias.as_str(), i))
+        .collect();
+    accounts.sort_by_key(|a| rank.get(a.alias.as_str()).copied().unwrap_or(usize::MAX));
+    Ok(())
+}
+
+// 删除账号时一并清理其元数据
+pub(crate) fn forget_account(alias: &str) -> Result<()> {
+    let path = get_meta_file_path()?;
+    if !path.exists() {
+        return Ok(());
+    }
+    update_meta(|store| {
+        store.accounts.remove(alias);
+        store.order.retain(|a| a != alias);
+        Ok(())
+    })
+}
+
+// 切换账号后记录最近使用时间
+pub(crate) fn touch_last_used(alias: &str) -> Result<()> {
+    update_meta(|store| {
+        store
+            .accounts
+            .entry(alias.to_string())
+            .or_default()
+            .last_used_at = Some(unix_now());
+        Ok(())
+    })
+}
+
+// 与 `remote` 上传或下载成功后记录同步时间，`updated_at` 与 `hash` 为同步完成时本地文件的修改时间和内容哈希
+pub(crate) fn mark_synced(alias: &str, remote: &str, updated_at: i64, hash: &str) -> Result<()> {
+    update_meta(|store| {
+        let meta = store.accounts.entry(alias.to_string()).or_default();
+        meta.synced_at = Some(unix_now());
+        meta.synced_updated_at = Some(updated_at);
+        meta.synced_hashes.insert(remote.to_string(), hash.to_string());
+        Ok(())
+    })
+}
+
+// 删除远端后清除与其同步的记录，之后同名的新远端不会沿用
+pub(crate) fn forget_remote(remote: &str) -> Result<()> {
+    update_meta(|store| {
+        for meta in store.accounts.values_mut() {
+            meta.synced_hashes.remove(remote);
+        }
+        Ok(())
+    })
+}
+
+// 账号的同步记录，同步日志回滚时据此恢复下载前的状态
+#[flutter_rust_bridge::frb(ignore)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct SyncedState {
+    #[serde(default)]
+    pub synced_at: Option<i64>,
+    #[serde(default)]
+    pub synced_updated_at: Option<i64>,
+    #[serde(default)]
+    pub synced_hashes: BTreeMap<String, String>,
+}
+
+pub(crate) fn synced_state(alias: &str) -> Result<SyncedState> {
+    let store = load_meta()?;
+    Ok(store
+        .accounts
+        .get(alias)
+        .map(|meta| SyncedState {
+            synced_at: meta.synced_at,
+            synced_updated_at: meta.synced_updated_at,
+            synced_hashes: meta.synced_hashes.clone(),
+        })
+        .unwrap_or_default())
+}
+
+pub(crate) fn restore_synced_state(alias: &str, state: &SyncedState) -> Result<()> {
+    update_meta(|store| {
+        let meta = store.accounts.entry(alias.to_string()).or_default();
+        meta.synced_at = state.synced_at;
+        meta.synced_updated_at = state.synced_updated_at;
+        meta.synced_hashes = state.synced_hashes.clone();
+        Ok(())
+    })
+}
+
+// 导入账号时写入其附加信息，引用的分组不存在时自动创建
+pub(crate) fn restore_meta(
+    alias: &str,
+    group: Option<String>,
+    tags: Vec<String>,
+    notes: Option<String>,
+) -> Result<()> {
+    let group = group.map(|g| validate_label(&g, "Group")).transpose()?;
+    let tags = normalize_tags(tags)?;
+    update_meta(|store| {
+        if let Some(group) = &group {
+            if !store.groups.contains(group) {
+                store.groups.push(group.clone());
+            }
+        }
+        let meta = store.accounts.entry(alias.to_string()).or_default();
+        meta.group = group;
+        meta.tags = tags;
+        meta.notes = notes;
+        Ok(())
+    })
+}
+
+fn ensure_account_exists(alias: &str) -> Result<String> {
+    let alias = validate_alias(alias)?;
+    if !get_account_list()?.iter().any(|a| a.alias == alias) {
+        bail!("Saved account not found");
+    }
+    Ok(alias.to_string())
+}
+
+// ============== 分组 ==============
+
+/// 获取所有分组（按用户定义的顺序）
+pub fn list_groups() -> anyhow::Result<Vec<String>> {
+    Ok(load_meta()?.groups)
+}
+
+/// 新建分组
+pub fn create_group(name: String) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let name = validate_label(&name, "Group")?;
+    update_meta(|store| {
+        if store.groups.contains(&name) {
+            bail!("Group already exists");
+        }
+        store.groups.push(name);
+        Ok(())
+    })
+}
+
+/// 重命名分组，组内账号随之迁移
+pub fn rename_group(old_name: String, new_name: String) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let new_name = validate_label(&new_name, "Group")?;
+    update_meta(|store| {
+        let Some(pos) = store.groups.iter().position(|g| *g == old_name) else {
+            bail!("Group not found");
+        };
+        if old_name != new_name && store.groups.contains(&new_name) {
+            bail!("Group already exists");
+        }
+        store.groups[pos] = new_name.clone();
+        for meta in store.accounts.values_mut() {
+            if meta.group.as_deref() == Some(old_name.as_str()) {
+                meta.group = Some(new_name.clone());
+            }
+        }
+        Ok(())
+    })
+}
+
+/// 删除分组，组内账号变为未分组，账号本身不会被删除
+pub fn delete_group(name: String) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    update_meta(|store| {
+        store.groups.retain(|g| *g != name);
+        for meta in store.accounts.values_mut() {
+            if meta.group.as_deref() == Some(name.as_str()) {
+                meta.group = None;
+            }
+        }
+        Ok(())
+    })
+}
+
+/// 调整分组顺序，`names` 需包含全部现有分组
+pub fn reorder_groups(names: Vec<String>) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    update_meta(|store| {
+        let mut expected = store.groups.clone();
+        let mut given = names.clone();
+        expected.sort();
+        given.sort();
+        if expected != given {
+            bail!("Group order must contain every existing group exactly once");
+        }
+        store.groups = names;
+        Ok(())
+    })
+}
+
+/// 设置账号所属分组，传入 `None` 表示移出分组；不存在的分组会自动创建
+pub fn set_account_group(alias: String, group: Option<String>) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let alias = ensure_account_exists(&alias)?;
+    let group = group.map(|g| validate_label(&g, "Group")).transpose()?;
+    update_meta(|store| {
+        if let Some(group) = &group {
+            if !store.groups.contains(group) {
+                store.groups.push(group.clone());
+            }
+        }
+        store.accounts.entry(alias).or_default().group = group;
+        Ok(())
+    })
+}
+
+/// 按分组获取账号，分组按用户顺序排列，未分组账号放在最后
+pub fn get_accounts_by_group() -> anyhow::Result<Vec<AccountGroup>> {
+    let accounts = get_account_list()?;
+    let groups = list_groups()?;
+
+    let mut result: Vec<AccountGroup> = groups
+        .into_iter()
+        .map(|name| AccountGroup {
+            name: Some(name),
+            accounts: Vec::new(),
+        })
+        .collect();
+    let mut ungrouped = Vec::new();
+
+    for acc in accounts {
+        let slot = acc
+            .group
+            .as_deref()
+            .and_then(|g| result.iter_mut().find(|r| r.name.as_deref() == Some(g)));
+        match slot {
+            Some(slot) => slot.accounts.push(acc),
+            None => ungrouped.push(acc),
+        }
+    }
+
+    if !ungrouped.is_empty() {
+        result.push(AccountGroup {
+            name: None,
+            accounts: ungrouped,
+        });
+    }
+    Ok(result)
+}
+
+// ============== 标签 ==============
+
+/// 获取所有已使用的标签
+pub fn list_tags() -> anyhow::Result<Vec<String>> {
+    let store = load_meta()?;
+    let mut tags: Vec<String> = Vec::new();
+    for meta in store.accounts.values() {
+        for tag in &meta.tags {
+            if !tags.iter().any(|t| t.eq_ignore_ascii_case(tag)) {
+                tags.push(tag.clone());
+            }
+        }
+    }
+    tags.sort_by_key(|t| t.to_lowercase());
+    Ok(tags)
+}
+
+/// 覆盖设置账号的标签
+pub fn set_account_tags(alias: String, tags: Vec<String>) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let alias = ensure_account_exists(&alias)?;
+    let tags = normalize_tags(tags)?;
+    update_meta(|store| {
+        store.accounts.entry(alias).or_default().tags = tags;
+        Ok(())
+    })
+}
+
+/// 获取带有指定标签的账号（不区分大小写）
+pub fn get_accounts_by_tag(tag: String) -> anyhow::Result<Vec<AccountInfo>> {
+    let tag = tag.trim().to_string();
+    Ok(get_account_list()?
+        .into_iter()
+        .filter(|a| a.tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)))
+        .collect())
+}
+
+// ============== 备注 ==============
+
+/// 设置账号备注，传入 `None` 或空白内容表示清除
+pub fn set_account_notes(alias: String, notes: Option<String>) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let alias = ensure_account_exists(&alias)?;
+    let notes = notes
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    update_meta(|store| {
+        store.accounts.entry(alias).or_default().notes = notes;
+        Ok(())
+    })
+}
+
+// ============== 同步策略 ==============
+
+/// 设置账号的同步策略
+pub fn set_account_sync_policy(alias: String, policy: AccountSyncPolicy) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let alias = ensure_account_exists(&alias)?;
+    update_meta(|store| {
+        store.accounts.entry(alias).or_default().sync_policy = policy;
+        Ok(())
+    })
+}
+
+// ============== 排序 ==============
+
+/// 保存账号的手动排序，未包含在 `aliases` 中的账号排在其后
+pub fn reorder_accounts(aliases: Vec<String>) -> anyhow::Result<()> {
+    let _lock = lock_instance("updating account details")?;
+    let mut order: Vec<String> = Vec::new();
+    for alias in &aliases {
+        let alias = validate_alias(alias)?.to_string();
+        if order.contains(&alias) {
+            bail!("Duplicate alias in order: {}", alias);
+        }
+        order.push(alias);
+    }
+    update_meta(|store| {
+        store.order = order;
+        Ok(())
+    })
+}
@@ -1,3 +1,24 @@
 pub mod simple;
+pub mod audit;
+pub mod autosync;
+pub mod backend;
+pub mod bundle;
+pub mod control;
 pub mod endfield;
+pub mod git;
+pub mod health;
+pub mod hooks;
+pub mod history;
+pub mod instance;
+pub mod journal;
+pub mod launch;
+pub mod local_folder;
+pub mod meta;
+pub mod report;
+pub mod s3;
+pub mod search;
+pub mod settings;
+pub mod sftp;
+pub mod sync;
+pub mod transfer;
 pub mod webdav;
@@ -0,0 +1,210 @@
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::audit::record_sync;
+use crate::api::control::{publish_event, ControlEvent};
+use crate::api::endfield::get_app_data_dir;
+
+// 同步历史中保留的报告数
+const MAX_REPORTS: usize = 20;
+
+/// 同步操作类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncOperation {
+    Push,
+    Pull,
+}
+
+/// 单个条目的处理结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncAction {
+    Uploaded,
+    Downloaded,
+    /// 内容未变化或无需处理
+    Skipped,
+    /// 本地与远端都有修改，未覆盖任何一方
+    Conflicted,
+    /// 远端删除了过期的历史版本
+    Deleted,
+    Failed,
+}
+
+/// 同步报告中的一个条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncEntry {
+    pub remote: String,
+    /// 账号别名，为 `None` 时表示整个远端（例如无法连接）
+    pub name: Option<String>,
+    pub action: SyncAction,
+    pub reason: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// 一次同步的详细报告
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncReport {
+    pub operation: SyncOperation,
+    /// 开始与结束时间（Unix 毫秒）
+    pub started_at: i64,
+    pub finished_at: i64,
+    pub entries: Vec<SyncEntry>,
+}
+
+/// 预演中的一个条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlannedEntry {
+    pub remote: String,
+    /// 账号别名，为 `None` 时表示整个远端
+    pub name: Option<String>,
+    /// 同步时将执行的动作
+    pub action: SyncAction,
+    pub reason: Option<String>,
+}
+
+/// 同步预演结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncPlan {
+    pub operation: SyncOperation,
+    pub entries: Vec<PlannedEntry>,
+}
+
+impl SyncPlan {
+    pub(crate) fn new(operation: SyncOperation) -> Self {
+        Self {
+            operation,
+            entries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn add(
+        &mut self,
+        remote: &str,
+        name: Option<&str>,
+        action: SyncAction,
+        reason: Option<String>,
+    ) {
+        self.entries.push(PlannedEntry {
+            remote: remote.to_string(),
+            name: name.map(str::to_string),
+            action,
+            reason,
+        });
+    }
+}
+
+fn now_millis() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or(0)
+}
+
+impl SyncReport {
+    pub(crate) fn new(operation: SyncOperation) -> Self {
+        Self {
+            operation,
+            started_at: now_millis(),
+            finished_at: 0,
+            entries: Vec::new(),
+        }
+    }
+
+    pub(crate) fn record(
+        &mut self,
+        remote: &str,
+        name: Option<&str>,
+        action: SyncAction,
+        reason: Option<String>,
+        duration_ms: u64,
+    ) {
+        self.entries.push(SyncEntry {
+            remote: remote.to_string(),
+            name: name.map(str::to_string),
+            action,
+            reason,
+            duration_ms,
+        });
+    }
+
+    pub(crate) fn fail(
+        &mut self,
+        remote: &str,
+        name: Option<&str>,
+        error: anyhow::Error,
+        duration_ms: u64,
+    ) {
+        let reason = format!("{:#}", error);
+        self.record(remote, name, SyncAction::Failed, Some(reason), duration_ms);
+    }
+
+    // 有失败项时转换为错误，供只关心成败的调用方使用
+    pub(crate) fn ensure_complete(&self) -> Result<()> {
+        let failed: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|e| e.action == SyncAction::Failed)
+            .map(|e| {
+                let reason = e.reason.as_deref().unwrap_or_default();
+                match &e.name {
+                    Some(name) => format!("{}/{}: {}", e.remote, name, reason),
+                    None => format!("{}: {}", e.remote, reason),
+                }
+            })
+            .collect();
+        if failed.is_empty() {
+            return Ok(());
+        }
+        bail!("{} entries failed: {}", failed.len(), failed.join("; "))
+    }
+
+    // 记录结束时间并写入同步历史与审计日志，写入失败不影响同步结果
+    pub(crate) fn finish(mut self) -> Self {
+        self.finished_at = now_millis();
+        let _ = append_report(&self);
+        record_sync(&self);
+        publish_event(ControlEvent::Synced {
+            report: self.clone(),
+        });
+        self
+    }
+}
+
+fn get_history_file_path() -> Result<PathBuf> {
+    Ok(get_app_data_dir()?.join("sync_history.json"))
+}
+
+fn load_reports() -> Result<Vec<SyncReport>> {
+    let path = get_history_file_path()?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let data = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&data).unwrap_or_default())
+}
+
+fn append_report(report: &SyncReport) -> Result<()> {
+    let mut reports = load_reports()?;
+    reports.insert(0, report.clone());
+    reports.truncate(MAX_REPORTS);
+    fs::write(get_history_file_path()?, serde_json::to_string(&reports)?)?;
+    Ok(())
+}
+
+// ============== 同步历史 API ==============
+
+/// 最近的同步报告，新报告在前
+pub fn get_sync_history() -> anyhow::Result<Vec<SyncReport>> {
+    load_reports()
+}
+
+/// 清空同步历史
+pub fn clear_sync_history() -> anyhow::Result<()> {
+    let path = get_history_file_path()?;
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
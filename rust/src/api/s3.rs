@@ -0,0 +1,432 @@
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::api::backend::{read_lock, LockInfo, RemoteStat, SyncBackend, LOCK_FILE};
+use crate::api::transfer::{get_file, header_content_length, put_file};
+
+// 流式上传时不对内容签名，完整性由上传后的大小与哈希校验保证
+const UNSIGNED_PAYLOAD: &str = "UNSIGNED-PAYLOAD";
+const EMPTY_PAYLOAD_SHA256: &str =
+    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855";
+
+/// S3 兼容对象存储（MinIO、Cloudflare R2、Backblaze B2、阿里云 OSS 等）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct S3Config {
+    /// 服务地址，例如 `https://s3.us-east-1.amazonaws.com`
+    pub endpoint: String,
+    /// 签名使用的区域，R2 为 `auto`
+    #[serde(default = "default_region")]
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// 桶内的路径前缀，账号保存在 `<prefix>/EndSwitcherConfig/` 下
+    #[serde(default)]
+    pub prefix: Option<String>,
+    /// 使用 `endpoint/bucket/key` 形式的地址（MinIO 等），否则使用 `bucket.endpoint/key`
+    #[serde(default)]
+    pub path_style: bool,
+}
+
+fn default_region() -> String {
+    "us-east-1".to_string()
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct S3Backend {
+    client: reqwest::Client,
+    config: S3Config,
+    // 桶的根地址，以 `/` 结尾
+    bucket_url: reqwest::Url,
+    // 对象键前缀，以 `/` 结尾
+    root: String,
+}
+
+// 按 SigV4 规则编码，只保留非保留字符
+fn uri_encode(value: &str, keep_slash: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            b'/' if keep_slash => out.push('/'),
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts any key length");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+// Unix 秒转换为 SigV4 使用的 UTC 日期（YYYYMMDD）与时间（YYYYMMDDTHHMMSSZ）
+fn amz_timestamp(secs: u64) -> (String, String) {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+    // 公历日期换算（Howard Hinnant 的 civil_from_days）
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let date = format!("{:04}{:02}{:02}", year, month, day);
+    let time = format!(
+        "{}T{:02}{:02}{:02}Z",
+        date,
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    );
+    (date, time)
+}
+
+impl S3Backend {
+    pub(crate) fn new(config: S3Config) -> Result<Self> {
+        let endpoint = config.endpoint.trim().trim_end_matches('/');
+        if endpoint.is_empty() {
+            bail!("S3 endpoint is required");
+        }
+        let bucket = config.bucket.trim();
+        if bucket.is_empty() {
+            bail!("S3 bucket is required");
+        }
+        let mut url = reqwest::Url::parse(endpoint).context("Invalid S3 endpoint")?;
+        if config.path_style {
+            url.set_path(&format!("{}/{}/", url.path().trim_end_matches('/'), bucket));
+        } else {
+            let host = url.host_str().context("Invalid S3 endpoint")?;
+            let host = format!("{}.{}", bucket, host);
+            url.set_host(Some(&host)).context("Invalid S3 endpoint")?;
+            url.set_path(&format!("{}/", url.path().trim_end_matches('/')));
+        }
+
+        let prefix = config
+            .prefix
+            .as_deref()
+            .unwrap_or("")
+            .trim()
+            .trim_matches('/');
+        let root = if prefix.is_empty() {
+            "EndSwitcherConfig/".to_string()
+        } else {
+            format!("{}/EndSwitcherConfig/", prefix)
+        };
+        Ok(Self {
+            client: reqwest::Client::new(),
+            config,
+            bucket_url: url,
+            root,
+        })
+    }
+
+    fn key(&self, path: &str) -> String {
+        format!("{}{}", self.root, path.trim_matches('/'))
+    }
+
+    // 构造带 SigV4 签名的请求，`key` 为空时指向桶本身
+    fn request(
+        &self,
+        method: reqwest::Method,
+        key: &str,
+        query: &[(&str, &str)],
+        payload_hash: &str,
+    ) -> reqwest::RequestBuilder {
+        let canonical_uri = format!("{}{}", self.bucket_url.path(), uri_encode(key, true));
+        let mut query: Vec<(String, String)> = query
+            .iter()
+            .map(|(k, v)| (uri_encode(k, false), uri_encode(v, false)))
+            .collect();
+        query.sort();
+        let canonical_query = query
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        let mut url = self.bucket_url.clone();
+        url.set_path(&canonical_uri);
+        url.set_query((!canonical_query.is_empty()).then_some(canonical_query.as_str()));
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            _ => String::new(),
+        };
+
+        let secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let (date, timestamp) = amz_timestamp(secs);
+        let region = self.config.region.trim();
+        let scope = format!("{}/{}/s3/aws4_request", date, region);
+        let signed_headers = "host;x-amz-content-sha256;x-amz-date";
+        let canonical_request = format!(
+            "{}\n{}\n{}\nhost:{}\nx-amz-content-sha256:{}\nx-amz-date:{}\n\n{}\n{}",
+            method.as_str(),
+            canonical_uri,
+            canonical_query,
+            host,
+            payload_hash,
+            timestamp,
+            signed_headers,
+            payload_hash
+        );
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+            timestamp,
+            scope,
+            sha256_hex(canonical_request.as_bytes())
+        );
+        let secret = format!("AWS4{}", self.config.secret_access_key);
+        let key = hmac_sha256(secret.as_bytes(), &date);
+        let key = hmac_sha256(&key, region);
+        let key = hmac_sha256(&key, "s3");
+        let key = hmac_sha256(&key, "aws4_request");
+        let signature = hmac_sha256(&key, &string_to_sign)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect::<String>();
+        let authorization = format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.config.access_key_id.trim(),
+            scope,
+            signed_headers,
+            signature
+        );
+
+        self.client
+            .request(method, url)
+            .header("x-amz-date", timestamp)
+            .header("x-amz-content-sha256", payload_hash)
+            .header(reqwest::header::AUTHORIZATION, authorization)
+    }
+
+    // 列出一页对象，返回（对象键，下一页的令牌）
+    async fn list_page(
+        &self,
+        prefix: &str,
+        token: Option<&str>,
+    ) -> Result<(Vec<String>, Option<String>)> {
+        let mut query = vec![("list-type", "2"), ("prefix", prefix), ("delimiter", "/")];
+        if let Some(token) = token {
+            query.push(("continuation-token", token));
+        }
+        let res = self
+            .request(reqwest::Method::GET, "", &query, EMPTY_PAYLOAD_SHA256)
+            .send()
+            .await?;
+        if !res.status().is_success() {
+            bail!("Failed to list S3 bucket: {}", res.status());
+        }
+        let xml = res.text().await?;
+
+        use quick_xml::events::Event;
+        use quick_xml::reader::Reader;
+
+        let mut reader = Reader::from_str(&xml);
+        reader.trim_text(true);
+        let mut buf = Vec::new();
+        let mut path: Vec<Vec<u8>> = Vec::new();
+        let mut keys = Vec::new();
+        let mut truncated = false;
+        let mut next = None;
+        loop {
+            match reader.read_event_into(&mut buf) {
+                Ok(Event::Start(e)) => path.push(e.local_name().as_ref().to_vec()),
+                Ok(Event::End(_)) => {
+                    path.pop();
+                }
+                Ok(Event::Text(e)) => {
+                    let text = e.unescape()?.into_owned();
+                    match path.iter().map(Vec::as_slice).collect::<Vec<_>>()[..] {
+                        [.., b"Contents", b"Key"] => keys.push(text),
+                        [.., b"IsTruncated"] => truncated = text == "true",
+                        [.., b"NextContinuationToken"] => next = Some(text),
+                        _ => {}
+                    }
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => bail!("XML parse error: {:?}", e),
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok((keys, next.filter(|_| truncated)))
+    }
+}
+
+#[async_trait]
+impl SyncBackend for S3Backend {
+    // 对象存储没有目录
+    async fn ensure_dir(&self, _dir: &str) -> Result<()> {
+        Ok(())
+    }
+
+    async fn list(&self, dir: &str) -> Result<Vec<String>> {
+        let dir = dir.trim_matches('/');
+        let prefix = if dir.is_empty() {
+            self.root.clone()
+        } else {
+            format!("{}{}/", self.root, dir)
+        };
+        let mut names = Vec::new();
+        let mut token = None;
+        loop {
+            let (keys, next) = self.list_page(&prefix, token.as_deref()).await?;
+            names.extend(
+                keys.iter()
+                    .filter_map(|key| key.strip_prefix(&prefix))
+                    .filter(|name| !name.is_empty() && !name.contains('/'))
+                    .map(str::to_string),
+            );
+            match next {
+                Some(next) => token = Some(next),
+                None => break,
+            }
+        }
+        Ok(names)
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<RemoteStat>> {
+        let res = self
+            .request(
+                reqwest::Method::HEAD,
+                &self.key(path),
+                &[],
+                EMPTY_PAYLOAD_SHA256,
+            )
+            .send()
+            .await?;
+        if res.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !res.status().is_success() {
+            bail!("{}", res.status());
+        }
+        let etag = res
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        Ok(Some(RemoteStat {
+            size: header_content_length(&res).unwrap_or(0),
+            etag,
+        }))
+    }
+
+    async fn get(&self, path: &str, target: &Path, expected_hash: Option<&str>) -> Result<String> {
+        let key = self.key(path);
+        let request = |method| self.request(method, &key, &[], EMPTY_PAYLOAD_SHA256);
+        get_file(&request, target, expected_hash).await
+    }
+
+    async fn read(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let res = self
+            .request(
+                reqwest::Method::GET,
+                &self.key(path),
+                &[],
+                EMPTY_PAYLOAD_SHA256,
+            )
+            .send()
+            .await?;
+        if res.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !res.status().is_success() {
+            bail!("{}", res.status());
+        }
+        Ok(Some(res.bytes().await?.to_vec()))
+    }
+
+    async fn put(&self, path: &str, source: &Path, hash: &str) -> Result<()> {
+        let key = self.key(path);
+        let request = |method: reqwest::Method| {
+            // HEAD 校验时没有请求体
+            let payload = if method == reqwest::Method::PUT {
+                UNSIGNED_PAYLOAD
+            } else {
+                EMPTY_PAYLOAD_SHA256
+            };
+            self.request(method, &key, &[], payload)
+        };
+        put_file(&request, source, hash).await
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> Result<()> {
+        let payload = sha256_hex(&data);
+        let res = self
+            .request(reqwest::Method::PUT, &self.key(path), &[], &payload)
+            .body(data)
+            .send()
+            .await?;
+        if !res.status().is_success() {
+            bail!("{}", res.status());
+        }
+        Ok(())
+    }
+
+    // S3 删除不存在的对象同样返回成功
+    async fn delete(&self, path: &str) -> Result<()> {
+        let res = self
+            .request(
+                reqwest::Method::DELETE,
+                &self.key(path),
+                &[],
+                EMPTY_PAYLOAD_SHA256,
+            )
+            .send()
+            .await?;
+        if !res.status().is_success() && res.status() != reqwest::StatusCode::NOT_FOUND {
+            bail!("{}", res.status());
+        }
+        Ok(())
+    }
+
+    // 条件写入（`If-None-Match: *`）保证只有一台设备能创建锁文件
+    async fn lock(&self) -> Result<String> {
+        let info = LockInfo::new();
+        let data = serde_json::to_vec(&info)?;
+        let payload = sha256_hex(&data);
+        for _ in 0..2 {
+            let res = self
+                .request(reqwest::Method::PUT, &self.key(LOCK_FILE), &[], &payload)
+                .header(reqwest::header::IF_NONE_MATCH, "*")
+                .body(data.clone())
+                .send()
+                .await?;
+            match res.status() {
+                status if status.is_success() => return Ok(info.token),
+                // 412 表示锁文件已存在，409 表示其他设备正在同时创建
+                reqwest::StatusCode::PRECONDITION_FAILED | reqwest::StatusCode::CONFLICT => {}
+                status => bail!("Failed to create remote lock: {}", status),
+            }
+            // S3 的写入是原子的，无法解析的锁文件不会是写了一半的内容
+            if let Some(holder) = read_lock(self).await? {
+                if !holder.is_stale() {
+                    bail!("Remote is locked by {}", holder.host);
+                }
+            }
+            // 失效的锁，删除后重试一次
+            self.delete(LOCK_FILE).await?;
+        }
+        bail!("Failed to create remote lock")
+    }
+}
@@ -0,0 +1,294 @@
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::api::endfield::{get_account_list, AccountInfo, SyncStatus};
+use crate::api::health::HealthStatus;
+
+/// 搜索结果的排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AccountSortKey {
+    /// 与 `get_account_list` 一致的手动顺序
+    Manual,
+    /// 按别名
+    Alias,
+    /// 按凭证更新时间
+    UpdatedAt,
+    /// 按最近使用时间，从未使用过的账号视为最早
+    LastUsedAt,
+    /// 按文本匹配程度，未指定搜索文本时等同于 `Manual`
+    Relevance,
+}
+
+/// 账号搜索条件，所有已设置的条件需同时满足
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountQuery {
+    /// 在别名和备注中搜索的文本，不区分大小写与全角/半角
+    pub text: Option<String>,
+    /// 为 `true` 时按子序列模糊匹配，否则按子串匹配
+    pub fuzzy: bool,
+    /// 账号需带有其中每一个标签
+    pub tags: Vec<String>,
+    /// 限定分组
+    pub group: Option<String>,
+    /// 最近使用时间下限（Unix 秒，含）
+    pub used_after: Option<i64>,
+    /// 最近使用时间上限（Unix 秒，含）
+    pub used_before: Option<i64>,
+    /// 是否只保留（或排除）凭证可能已失效（`LikelyExpired` 或 `Invalid`）的账号
+    pub stale: Option<bool>,
+    /// 限定同步状态
+    pub sync_status: Option<SyncStatus>,
+    pub sort_by: AccountSortKey,
+    pub descending: bool,
+}
+
+impl Default for AccountQuery {
+    fn default() -> Self {
+        Self {
+            text: None,
+            fuzzy: false,
+            tags: Vec::new(),
+            group: None,
+            used_after: None,
+            used_before: None,
+            stale: None,
+            sync_status: None,
+            sort_by: AccountSortKey::Manual,
+            descending: false,
+        }
+    }
+}
+
+// 子串匹配：越靠前得分越高
+fn substring_score(haystack: &str, needle: &str) -> Option<i64> {
+    let pos = haystack.find(needle)?;
+    Some(1000 - haystack[..pos].chars().count() as i64)
+}
+
+// 子序列模糊匹配：字符需按顺序出现，连续命中加分，间隔扣分
+fn fuzzy_score(haystack: &str, needle: &str) -> Option<i64> {
+    if let Some(score) = substring_score(haystack, needle) {
+        return Some(score);
+    }
+    let mut chars = haystack.chars().enumerate();
+    let mut score = 0i64;
+    let mut last: Option<usize> = None;
+    for nc in needle.chars() {
+        let (idx, _) = chars.find(|(_, hc)| *hc == nc)?;
+        score += match last {
+            Some(prev) if idx == prev + 1 => 10,
+            Some(prev) => -((idx - prev - 1) as i64),
+            None => -(idx as i64),
+        };
+        last = Some(idx);
+    }
+    Some(score)
+}
+
+// 统一大小写与全角/半角，输入法切换时输入的全角字母、数字与空格同样可以命中
+fn fold(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            _ => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// 文本匹配得分，别名命中优先于备注命中；未命中返回 None
+fn text_score(acc: &AccountInfo, text: &str, fuzzy: bool) -> Option<i64> {
+    let needle = fold(text);
+    let matcher = if fuzzy { fuzzy_score } else { substring_score };
+    let alias_score = matcher(&fold(&acc.alias), &needle).map(|s| s + 1000);
+    let notes_score = acc
+        .notes
+        .as_deref()
+        .and_then(|n| matcher(&fold(n), &needle));
+    alias_score.max(notes_score)
+}
+
+fn is_stale(acc: &AccountInfo) -> bool {
+    matches!(
+        acc.health,
+        HealthStatus::LikelyExpired | HealthStatus::Invalid
+    )
+}
+
+fn matches_filters(acc: &AccountInfo, query: &AccountQuery) -> bool {
+    if !query
+        .tags
+        .iter()
+        .all(|tag| acc.tags.iter().any(|t| t.eq_ignore_ascii_case(tag.trim())))
+    {
+        return false;
+    }
+    if let Some(group) = &query.group {
+        if acc.group.as_deref() != Some(group.as_str()) {
+            return false;
+        }
+    }
+    if query.used_after.is_some() || query.used_before.is_some() {
+        let Some(used) = acc.last_used_at else {
+            return false;
+        };
+        if query.used_after.is_some_and(|after| used < after) {
+            return false;
+        }
+        if query.used_before.is_some_and(|before| used > before) {
+            return false;
+        }
+    }
+    if let Some(stale) = query.stale {
+        if is_stale(acc) != stale {
+            return false;
+        }
+    }
+    if let Some(status) = query.sync_status {
+        if acc.sync_status != status {
+            return false;
+        }
+    }
+    true
+}
+
+fn query_accounts(accounts: Vec<AccountInfo>, query: &AccountQuery) -> Vec<AccountInfo> {
+    let text = query
+        .text
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty());
+
+    let mut scored: Vec<(AccountInfo, i64)> = accounts
+        .into_iter()
+        .filter(|acc| matches_filters(acc, query))
+        .filter_map(|acc| match text {
+            Some(text) => text_score(&acc, text, query.fuzzy).map(|score| (acc, score)),
+            None => Some((acc, 0)),
+        })
+        .collect();
+
+    // 输入列表已是手动顺序，记录下标作为 Manual 排序及其他排序的兜底
+    let manual: HashMap<String, usize> = scored
+        .iter()
+        .enumerate()
+        .map(|(i, (acc, _))| (acc.alias.clone(), i))
+        .collect();
+
+    let sort_by = match query.sort_by {
+        AccountSortKey::Relevance if text.is_none() => AccountSortKey::Manual,
+        other => other,
+    };
+    scored.sort_by(|(a, sa), (b, sb)| {
+        let ord = match sort_by {
+            AccountSortKey::Manual => Ordering::Equal,
+            AccountSortKey::Alias => a.alias.to_lowercase().cmp(&b.alias.to_lowercase()),
+            AccountSortKey::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+            AccountSortKey::LastUsedAt => a.last_used_at.cmp(&b.last_used_at),
+            // 得分越高越相关，升序时排在前面
+            AccountSortKey::Relevance => sb.cmp(sa),
+        };
+        let ord = if query.descending { ord.reverse() } else { ord };
+        ord.then_with(|| manual[&a.alias].cmp(&manual[&b.alias]))
+    });
+
+    scored.into_iter().map(|(acc, _)| acc).collect()
+}
+
+/// 按条件搜索、筛选并排序已保存的账号
+pub fn search_accounts(query: AccountQuery) -> anyhow::Result<Vec<AccountInfo>> {
+    Ok(query_accounts(get_account_list()?, &query))
+}
+
+/// 返回默认搜索条件（不筛选，按手动顺序）
+#[flutter_rust_bridge::frb(sync)]
+pub fn default_account_query() -> AccountQuery {
+    AccountQuery::default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::endfield::AccountSyncPolicy;
+
+    fn account(alias: &str, notes: Option<&str>) -> AccountInfo {
+        AccountInfo {
+            alias: alias.to_string(),
+            updated_at: 0,
+            group: None,
+            tags: Vec::new(),
+            notes: notes.map(str::to_string),
+            last_used_at: None,
+            sync_status: SyncStatus::NeverSynced,
+            sync_policy: AccountSyncPolicy::default(),
+            health: HealthStatus::Fresh,
+        }
+    }
+
+    fn search(accounts: &[AccountInfo], text: &str, fuzzy: bool) -> Vec<String> {
+        let query = AccountQuery {
+            text: Some(text.to_string()),
+            fuzzy,
+            sort_by: AccountSortKey::Relevance,
+            ..AccountQuery::default()
+        };
+        query_accounts(accounts.to_vec(), &query)
+            .into_iter()
+            .map(|a| a.alias)
+            .collect()
+    }
+
+    #[test]
+    fn relevance_prefers_alias_and_earlier_matches() {
+        let accounts = [
+            account("backup", Some("main account")),
+            account("my-main", None),
+            account("main", None),
+            account("other", None),
+        ];
+        assert_eq!(
+            search(&accounts, "main", false),
+            ["main", "my-main", "backup"]
+        );
+    }
+
+    #[test]
+    fn fuzzy_prefers_substrings_then_consecutive_hits() {
+        let accounts = [
+            account("a-l-t", None),
+            account("alt2", None),
+            account("salt", None),
+            account("bob", None),
+        ];
+        assert_eq!(search(&accounts, "alt", true), ["alt2", "salt", "a-l-t"]);
+        assert_eq!(search(&accounts, "alt", false), ["alt2", "salt"]);
+        assert!(fuzzy_score("alice", "ila").is_none());
+    }
+
+    #[test]
+    fn matching_folds_case_and_width() {
+        let accounts = [account("Alice2", None), account("ｂｏｂ　ｍａｉｎ", None)];
+        assert_eq!(search(&accounts, "ALICE", false), ["Alice2"]);
+        assert_eq!(search(&accounts, "ａｌｉｃｅ２", false), ["Alice2"]);
+        assert_eq!(search(&accounts, "bob main", false), ["ｂｏｂ　ｍａｉｎ"]);
+        assert_eq!(fold("ＡＢＣ　１２３"), "abc 123");
+    }
+
+    #[test]
+    fn empty_query_keeps_manual_order() {
+        let accounts = [account("zed", None), account("amy", None)];
+        assert_eq!(search(&accounts, "", false), ["zed", "amy"]);
+        assert_eq!(search(&accounts, "   ", true), ["zed", "amy"]);
+        let query = AccountQuery {
+            sort_by: AccountSortKey::Alias,
+            ..AccountQuery::default()
+        };
+        let sorted: Vec<_> = query_accounts(accounts.to_vec(), &query)
+            .into_iter()
+            .map(|a| a.alias)
+            .collect();
+        assert_eq!(sorted, ["amy", "zed"]);
+    }
+}
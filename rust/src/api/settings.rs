@@ -0,0 +1,450 @@
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+
+use crate::api::endfield::get_app_data_dir;
+use crate::api::git::GitConfig;
+use crate::api::hooks::HookConfig;
+use crate::api::instance::lock_instance;
+use crate::api::launch::LaunchConfig;
+use crate::api::local_folder::LocalFolderConfig;
+use crate::api::s3::S3Config;
+use crate::api::sftp::SftpConfig;
+use crate::api::webdav::WebDavConfig;
+
+// 当前配置结构版本，结构变化时递增并在 `migrate` 中补充迁移步骤
+const CURRENT_SCHEMA_VERSION: u32 = 1;
+const MAX_BACKUP_DEPTH: u32 = 100;
+const MAX_REMOTE_HISTORY_DEPTH: u32 = 100;
+const MAX_TRANSFER_CONCURRENCY: u32 = 16;
+const MAX_LOCK_TIMEOUT_SECS: u32 = 600;
+const MAX_HOOK_TIMEOUT_SECS: u32 = 300;
+
+/// 自定义路径
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PathSettings {
+    /// 游戏数据目录（包含 sdk_data_* 的目录），未设置时使用默认位置
+    #[serde(default)]
+    pub game_data_dir: Option<String>,
+}
+
+/// 远端允许的同步方向
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncDirection {
+    /// 只上传
+    PushOnly,
+    /// 只下载
+    PullOnly,
+    /// 双向
+    #[default]
+    TwoWay,
+}
+
+impl SyncDirection {
+    pub(crate) fn allows_push(self) -> bool {
+        self != SyncDirection::PullOnly
+    }
+
+    pub(crate) fn allows_pull(self) -> bool {
+        self != SyncDirection::PushOnly
+    }
+}
+
+/// 远端使用的存储后端及其配置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum RemoteBackend {
+    WebDav(WebDavConfig),
+    /// 本地或挂载的文件夹（Syncthing、NAS 共享、U 盘等）
+    Folder(LocalFolderConfig),
+    /// S3 兼容对象存储
+    S3(S3Config),
+    /// Git 仓库，每次推送生成一次提交
+    Git(GitConfig),
+    /// SSH 服务器上的目录
+    Sftp(SftpConfig),
+}
+
+/// 一个命名的同步远端，列表中靠前的远端优先级更高
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncRemote {
+    pub name: String,
+    #[serde(default)]
+    pub direction: SyncDirection,
+    pub backend: RemoteBackend,
+    /// 只同步匹配其中任一规则的账号，为空表示全部
+    /// 规则可以是别名、`tag:<标签>` 或 `group:<分组>`
+    #[serde(default)]
+    pub include: Vec<String>,
+    /// 排除匹配其中任一规则的账号，优先于 `include`
+    #[serde(default)]
+    pub exclude: Vec<String>,
+}
+
+/// 自动同步设置
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoSyncSettings {
+    pub enabled: bool,
+    /// 定时同步间隔（分钟）
+    pub interval_minutes: u32,
+    /// 保存、切换、删除账号后同步
+    pub on_change: bool,
+    /// 启动时同步
+    pub on_startup: bool,
+}
+
+impl Default for AutoSyncSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_minutes: 30,
+            on_change: true,
+            on_startup: true,
+        }
+    }
+}
+
+/// 本地控制服务设置，供 Stream Deck、脚本等自动化工具调用
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ControlServerSettings {
+    pub enabled: bool,
+    /// 监听 127.0.0.1 的端口，0 表示由系统分配
+    pub port: u16,
+}
+
+impl Default for ControlServerSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            port: 47615,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ThemeMode {
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+/// 界面外观偏好，仅由 Flutter 端读取
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ThemeSettings {
+    #[serde(default)]
+    pub mode: ThemeMode,
+    /// 强调色，形如 `#RRGGBB`
+    #[serde(default)]
+    pub accent_color: Option<String>,
+}
+
+/// 应用的全部设置，保存在 config.json 中
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AppSettings {
+    pub schema_version: u32,
+    #[serde(default)]
+    pub paths: PathSettings,
+    #[serde(default)]
+    pub remotes: Vec<SyncRemote>,
+    /// 切换账号前为游戏内当前凭证保留的备份份数，0 表示不备份
+    #[serde(default = "default_backup_depth")]
+    pub backup_depth: u32,
+    /// 远端为每个账号保留的历史版本数
+    #[serde(default = "default_remote_history_depth")]
+    pub remote_history_depth: u32,
+    /// 同步时同时进行的上传/下载数
+    #[serde(default = "default_transfer_concurrency")]
+    pub transfer_concurrency: u32,
+    /// 其他操作（另一个窗口、命令行或自动同步）进行中时的最长等待秒数，0 表示不等待
+    #[serde(default = "default_lock_timeout_secs")]
+    pub lock_timeout_secs: u32,
+    #[serde(default)]
+    pub launch: Option<LaunchConfig>,
+    #[serde(default)]
+    pub auto_sync: AutoSyncSettings,
+    #[serde(default)]
+    pub control_server: ControlServerSettings,
+    /// 切换、保存账号前后运行的外部命令
+    #[serde(default)]
+    pub hooks: Vec<HookConfig>,
+    #[serde(default)]
+    pub theme: ThemeSettings,
+}
+
+fn default_backup_depth() -> u32 {
+    5
+}
+
+fn default_remote_history_depth() -> u32 {
+    10
+}
+
+fn default_transfer_concurrency() -> u32 {
+    4
+}
+
+fn default_lock_timeout_secs() -> u32 {
+    10
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            schema_version: CURRENT_SCHEMA_VERSION,
+            paths: PathSettings::default(),
+            remotes: Vec::new(),
+            backup_depth: default_backup_depth(),
+            remote_history_depth: default_remote_history_depth(),
+            transfer_concurrency: default_transfer_concurrency(),
+            lock_timeout_secs: default_lock_timeout_secs(),
+            launch: None,
+            auto_sync: AutoSyncSettings::default(),
+            control_server: ControlServerSettings::default(),
+            hooks: Vec::new(),
+            theme: ThemeSettings::default(),
+        }
+    }
+}
+
+fn get_settings_file_path() -> Result<PathBuf> {
+    Ok(get_app_data_dir()?.join("config.json"))
+}
+
+fn get_legacy_launch_file_path() -> Result<PathBuf> {
+    Ok(get_app_data_dir()?.join("launch.json"))
+}
+
+// v0：早期版本的 config.json 直接保存一个 WebDavConfig，启动配置单独保存在 launch.json
+fn migrate_v0(value: Value) -> Result<Value> {
+    let mut remotes = Vec::new();
+    if value.get("url").is_some() {
+        let config: WebDavConfig =
+            serde_json::from_value(value).context("Failed to read legacy WebDAV config")?;
+        let backend = RemoteBackend::WebDav(config);
+        remotes.push(json!({ "name": "default", "backend": backend }));
+    }
+    let mut launch = Value::Null;
+    let launch_file = get_legacy_launch_file_path()?;
+    if launch_file.exists() {
+        let data = fs::read_to_string(&launch_file)?;
+        let config: LaunchConfig =
+            serde_json::from_str(&data).context("Failed to read legacy launch config")?;
+        launch = serde_json::to_value(config)?;
+    }
+    Ok(json!({
+        "schema_version": 1,
+        "remotes": remotes,
+        "launch": launch,
+    }))
+}
+
+// 逐级把旧版本配置迁移到当前版本，迁移结果在下次保存设置时写入
+fn migrate(mut value: Value) -> Result<AppSettings> {
+    let mut version = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32;
+    if version > CURRENT_SCHEMA_VERSION {
+        bail!(
+            "Config schema version {} is newer than supported version {}",
+            version,
+            CURRENT_SCHEMA_VERSION
+        );
+    }
+    while version < CURRENT_SCHEMA_VERSION {
+        value = match version {
+            0 => migrate_v0(value)?,
+            _ => unreachable!(),
+        };
+        version += 1;
+    }
+    serde_json::from_value(value).context("Config file is corrupted")
+}
+
+fn validate_settings(settings: &AppSettings) -> Result<()> {
+    if let Some(dir) = &settings.paths.game_data_dir {
+        if dir.trim().is_empty() {
+            bail!("Game data directory cannot be empty");
+        }
+    }
+
+    let mut names: Vec<&str> = Vec::new();
+    for remote in &settings.remotes {
+        let name = remote.name.trim();
+        if name.is_empty() {
+            bail!("Remote name cannot be empty");
+        }
+        if names.contains(&name) {
+            bail!("Duplicate remote name: {}", name);
+        }
+        names.push(name);
+        match &remote.backend {
+            RemoteBackend::WebDav(config) => {
+                let url = config.url.trim();
+                if !(url.starts_with("http://") || url.starts_with("https://")) {
+                    bail!("WebDAV remote {} must use an http(s) URL", name);
+                }
+            }
+            RemoteBackend::Folder(config) => {
+                if config.path.trim().is_empty() {
+                    bail!("Folder path of remote {} cannot be empty", name);
+                }
+            }
+            RemoteBackend::S3(config) => {
+                let endpoint = config.endpoint.trim();
+                if !(endpoint.starts_with("http://") || endpoint.starts_with("https://")) {
+                    bail!("S3 remote {} must use an http(s) endpoint", name);
+                }
+                if config.bucket.trim().is_empty() || config.region.trim().is_empty() {
+                    bail!("S3 remote {} needs a bucket and a region", name);
+                }
+                if config.access_key_id.trim().is_empty() {
+                    bail!("S3 remote {} needs an access key", name);
+                }
+            }
+            RemoteBackend::Git(config) => {
+                if config.path.trim().is_empty() {
+                    bail!("Repository path of remote {} cannot be empty", name);
+                }
+                let branch = config.branch.trim();
+                if branch.is_empty()
+                    || branch.starts_with('-')
+                    || branch.contains(char::is_whitespace)
+                {
+                    bail!("Git remote {} has an invalid branch name", name);
+                }
+            }
+            RemoteBackend::Sftp(config) => {
+                if config.host.trim().is_empty() || config.port == 0 {
+                    bail!("SFTP remote {} needs a host and a port", name);
+                }
+                if config.username.trim().is_empty() {
+                    bail!("SFTP remote {} needs a username", name);
+                }
+                let has_key = config
+                    .private_key_path
+                    .as_deref()
+                    .is_some_and(|k| !k.trim().is_empty());
+                if !has_key && config.password.is_none() {
+                    bail!("SFTP remote {} needs a password or a private key", name);
+                }
+            }
+        }
+    }
+
+    if settings.backup_depth > MAX_BACKUP_DEPTH {
+        bail!("Backup depth cannot exceed {}", MAX_BACKUP_DEPTH);
+    }
+    if settings.remote_history_depth == 0
+        || settings.remote_history_depth > MAX_REMOTE_HISTORY_DEPTH
+    {
+        bail!(
+            "Remote history depth must be between 1 and {}",
+            MAX_REMOTE_HISTORY_DEPTH
+        );
+    }
+    if settings.transfer_concurrency == 0
+        || settings.transfer_concurrency > MAX_TRANSFER_CONCURRENCY
+    {
+        bail!(
+            "Transfer concurrency must be between 1 and {}",
+            MAX_TRANSFER_CONCURRENCY
+        );
+    }
+    if settings.lock_timeout_secs > MAX_LOCK_TIMEOUT_SECS {
+        bail!(
+            "Lock timeout cannot exceed {} seconds",
+            MAX_LOCK_TIMEOUT_SECS
+        );
+    }
+    for hook in &settings.hooks {
+        if hook.command.trim().is_empty() {
+            bail!("Hook command cannot be empty");
+        }
+        if hook.timeout_secs == 0 || hook.timeout_secs > MAX_HOOK_TIMEOUT_SECS {
+            bail!(
+                "Hook timeout must be between 1 and {} seconds",
+                MAX_HOOK_TIMEOUT_SECS
+            );
+        }
+    }
+    if let Some(launch) = &settings.launch {
+        if launch.executable.trim().is_empty() {
+            bail!("Game executable path is required");
+        }
+    }
+    if settings.auto_sync.enabled && settings.auto_sync.interval_minutes == 0 {
+        bail!("Auto-sync interval must be at least 1 minute");
+    }
+    if let Some(color) = &settings.theme.accent_color {
+        let hex = color.strip_prefix('#').unwrap_or("");
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            bail!("Accent color must look like #RRGGBB");
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn load_settings() -> Result<AppSettings> {
+    let path = get_settings_file_path()?;
+    if !path.exists() {
+        return Ok(AppSettings::default());
+    }
+    let data = fs::read_to_string(&path)?;
+    let value: Value = serde_json::from_str(&data).context("Config file is corrupted")?;
+    migrate(value)
+}
+
+// 先写入临时文件再替换，中途退出时不会留下不完整的配置；调用方需持有应用锁
+pub(crate) fn save_settings(settings: &AppSettings) -> Result<()> {
+    let path = get_settings_file_path()?;
+    let temp = path.with_extension("json.tmp");
+    fs::write(&temp, serde_json::to_string_pretty(settings)?)?;
+    fs::rename(temp, path)?;
+    // 旧版本的启动配置已随迁移写入 config.json
+    let launch_file = get_legacy_launch_file_path()?;
+    if launch_file.exists() {
+        fs::remove_file(launch_file)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn update_settings_with<T>(f: impl FnOnce(&mut AppSettings) -> Result<T>) -> Result<T> {
+    let mut settings = load_settings()?;
+    let out = f(&mut settings)?;
+    validate_settings(&settings)?;
+    save_settings(&settings)?;
+    Ok(out)
+}
+
+// ============== 设置 API ==============
+
+/// 读取应用设置，首次运行时返回默认设置
+pub fn get_settings() -> anyhow::Result<AppSettings> {
+    load_settings()
+}
+
+/// 校验并保存应用设置
+pub fn update_settings(settings: AppSettings) -> anyhow::Result<()> {
+    let _lock = lock_instance("saving settings")?;
+    let settings = AppSettings {
+        schema_version: CURRENT_SCHEMA_VERSION,
+        ..settings
+    };
+    validate_settings(&settings)?;
+    save_settings(&settings)
+}
+
+/// 恢复默认设置
+pub fn reset_settings() -> anyhow::Result<()> {
+    let _lock = lock_instance("saving settings")?;
+    save_settings(&AppSettings::default())
+}
+
+#[flutter_rust_bridge::frb(sync)]
+pub fn default_settings() -> AppSettings {
+    AppSettings::default()
+}
@@ -0,0 +1,510 @@
+use anyhow::{bail, Context, Result};
+use async_trait::async_trait;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use ssh2::{
+    CheckResult, ErrorCode, HashType, HostKeyType, KnownHostFileKind, OpenFlags, OpenType, Session,
+    Sftp,
+};
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::OnceCell;
+
+use crate::api::backend::{
+    is_temp_file_name, read_lock, temp_file_name, LockInfo, RemoteStat, SyncBackend, LOCK_FILE,
+    LOCK_TTL_SECS,
+};
+use crate::api::endfield::unix_now;
+use crate::api::transfer::part_path;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(15);
+// 单个 SSH 操作的超时（毫秒）
+const IO_TIMEOUT_MS: u32 = 60_000;
+// LIBSSH2_FX_NO_SUCH_FILE
+const FX_NO_SUCH_FILE: i32 = 2;
+
+/// SFTP 远端，账号保存在 `<path>/EndSwitcherConfig` 下
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SftpConfig {
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    pub username: String,
+    /// 密码认证，设置了私钥时不使用
+    #[serde(default)]
+    pub password: Option<String>,
+    /// OpenSSH 格式的私钥文件路径
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    /// 服务器上的目录，相对路径从登录用户的主目录开始，未设置时使用主目录
+    #[serde(default)]
+    pub path: Option<String>,
+    /// known_hosts 文件路径，未设置时使用 `~/.ssh/known_hosts`
+    #[serde(default)]
+    pub known_hosts_path: Option<String>,
+    /// 首次连接时信任服务器的主机密钥并写入 known_hosts
+    #[serde(default)]
+    pub accept_new_host: bool,
+}
+
+fn default_port() -> u16 {
+    22
+}
+
+struct Connection {
+    // `Sftp` 依赖会话保持连接
+    _session: Session,
+    sftp: Sftp,
+}
+
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct SftpBackend {
+    config: SftpConfig,
+    root: String,
+    // 首次访问时建立连接，之后的操作共用
+    conn: OnceCell<Arc<Connection>>,
+}
+
+fn known_hosts_path(config: &SftpConfig) -> Result<PathBuf> {
+    match config.known_hosts_path.as_deref().map(str::trim) {
+        Some(path) if !path.is_empty() => Ok(PathBuf::from(path)),
+        _ => {
+            let dirs = directories::BaseDirs::new().context("Could not find home directory")?;
+            Ok(dirs.home_dir().join(".ssh").join("known_hosts"))
+        }
+    }
+}
+
+fn key_type_name(kind: HostKeyType) -> Result<&'static str> {
+    Ok(match kind {
+        HostKeyType::Rsa => "ssh-rsa",
+        HostKeyType::Dss => "ssh-dss",
+        HostKeyType::Ecdsa256 => "ecdsa-sha2-nistp256",
+        HostKeyType::Ecdsa384 => "ecdsa-sha2-nistp384",
+        HostKeyType::Ecdsa521 => "ecdsa-sha2-nistp521",
+        HostKeyType::Ed25519 => "ssh-ed25519",
+        HostKeyType::Unknown => bail!("Unsupported host key type"),
+    })
+}
+
+// 按 known_hosts 校验服务器身份，防止中间人攻击
+fn verify_host_key(session: &Session, config: &SftpConfig) -> Result<()> {
+    let host = config.host.trim();
+    let (key, kind) = session
+        .host_key()
+        .context("Server did not send a host key")?;
+    let fingerprint = session
+        .host_key_hash(HashType::Sha256)
+        .map(|hash| base64::engine::general_purpose::STANDARD_NO_PAD.encode(hash))
+        .unwrap_or_default();
+
+    let path = known_hosts_path(config)?;
+    let mut known = session.known_hosts()?;
+    if path.exists() {
+        known
+            .read_file(&path, KnownHostFileKind::OpenSSH)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+    }
+    match known.check_port(host, config.port, key) {
+        CheckResult::Match => Ok(()),
+        CheckResult::Mismatch => bail!(
+            "Host key of {} does not match known_hosts (SHA256:{}), the server may have been replaced",
+            host,
+            fingerprint
+        ),
+        CheckResult::NotFound if config.accept_new_host => {
+            // 追加而不是重写整个文件，保留 libssh2 无法解析的条目
+            let name = if config.port == 22 {
+                host.to_string()
+            } else {
+                format!("[{}]:{}", host, config.port)
+            };
+            let line = format!(
+                "{} {} {}\n",
+                name,
+                key_type_name(kind)?,
+                base64::engine::general_purpose::STANDARD.encode(key)
+            );
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            let mut file = std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&path)
+                .with_context(|| format!("Failed to update {}", path.display()))?;
+            file.write_all(line.as_bytes())?;
+            Ok(())
+        }
+        CheckResult::NotFound => bail!(
+            "Host key of {} is not in known_hosts (SHA256:{})",
+            host,
+            fingerprint
+        ),
+        CheckResult::Failure => bail!("Failed to check host key of {}", host),
+    }
+}
+
+fn authenticate(session: &Session, config: &SftpConfig) -> Result<()> {
+    let username = config.username.trim();
+    let key = config
+        .private_key_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|k| !k.is_empty());
+    let result = match (key, &config.password) {
+        (Some(key), _) => session.userauth_pubkey_file(
+            username,
+            None,
+            Path::new(key),
+            config.passphrase.as_deref(),
+        ),
+        (None, Some(password)) => session.userauth_password(username, password),
+        (None, None) => bail!("SFTP remote needs a password or a private key"),
+    };
+    result.context("SSH authentication failed")?;
+    if !session.authenticated() {
+        bail!("SSH authentication failed");
+    }
+    Ok(())
+}
+
+fn connect(config: &SftpConfig) -> Result<Connection> {
+    let host = config.host.trim();
+    let addr = (host, config.port)
+        .to_socket_addrs()
+        .with_context(|| format!("Failed to resolve {}", host))?
+        .next()
+        .with_context(|| format!("Failed to resolve {}", host))?;
+    let tcp = TcpStream::connect_timeout(&addr, CONNECT_TIMEOUT)
+        .with_context(|| format!("Failed to connect to {}:{}", host, config.port))?;
+    // SFTP 是逐个请求应答的协议，关闭 Nagle 算法避免每次往返的延迟
+    tcp.set_nodelay(true)?;
+
+    let mut session = Session::new()?;
+    session.set_tcp_stream(tcp);
+    session.set_timeout(IO_TIMEOUT_MS);
+    session.handshake().context("SSH handshake failed")?;
+    verify_host_key(&session, config)?;
+    authenticate(&session, config)?;
+    let sftp = session.sftp().context("Failed to start SFTP session")?;
+    Ok(Connection {
+        _session: session,
+        sftp,
+    })
+}
+
+fn is_not_found(e: &ssh2::Error) -> bool {
+    e.code() == ErrorCode::SFTP(FX_NO_SUCH_FILE)
+}
+
+async fn blocking<T: Send + 'static>(f: impl FnOnce() -> Result<T> + Send + 'static) -> Result<T> {
+    tokio::task::spawn_blocking(f).await?
+}
+
+// 边读边计算 SHA-256
+fn copy_hashed(mut reader: impl Read, mut writer: impl Write) -> Result<String> {
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = reader.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+        writer.write_all(&buf[..n])?;
+    }
+    writer.flush()?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+// 写入同目录下的临时文件后替换目标，避免其他设备读到写了一半的文件
+fn upload(
+    sftp: &Sftp,
+    dest: &Path,
+    write: impl FnOnce(&mut ssh2::File) -> Result<()>,
+) -> Result<()> {
+    let name = dest
+        .file_name()
+        .and_then(|n| n.to_str())
+        .context("Invalid remote path")?;
+    let temp = dest.with_file_name(temp_file_name(name));
+    let result = (|| {
+        let mut file = sftp.create(&temp)?;
+        write(&mut file)?;
+        file.close()?;
+        // SFTP v3 的服务器（如 OpenSSH）不支持覆盖，目标已存在时先把旧文件移到一旁，
+        // 替换失败时再移回，任何时候都不会丢失旧内容；暂存名同样会在列出目录时被跳过
+        if sftp.rename(&temp, dest, None).is_err() {
+            let aside = dest.with_file_name(temp_file_name(&format!("{}.old", name)));
+            let _ = sftp.unlink(&aside);
+            sftp.rename(dest, &aside, None)?;
+            if let Err(e) = sftp.rename(&temp, dest, None) {
+                let _ = sftp.rename(&aside, dest, None);
+                return Err(e.into());
+            }
+            let _ = sftp.unlink(&aside);
+        }
+        Ok(())
+    })();
+    if result.is_err() {
+        let _ = sftp.unlink(&temp);
+    }
+    result
+}
+
+impl SftpBackend {
+    pub(crate) fn new(config: SftpConfig) -> Result<Self> {
+        if config.host.trim().is_empty() {
+            bail!("SFTP host is required");
+        }
+        let base = config.path.as_deref().unwrap_or("").trim();
+        let base = base.trim_end_matches('/');
+        let root = if base.is_empty() {
+            "EndSwitcherConfig".to_string()
+        } else {
+            format!("{}/EndSwitcherConfig", base)
+        };
+        Ok(Self {
+            config,
+            root,
+            conn: OnceCell::new(),
+        })
+    }
+
+    async fn conn(&self) -> Result<Arc<Connection>> {
+        let conn = self
+            .conn
+            .get_or_try_init(|| {
+                let config = self.config.clone();
+                async move { blocking(move || connect(&config).map(Arc::new)).await }
+            })
+            .await?;
+        Ok(conn.clone())
+    }
+
+    fn resolve(&self, path: &str) -> Result<PathBuf> {
+        let mut resolved = self.root.clone();
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            if segment == "." || segment == ".." || segment.contains('\\') {
+                bail!("Invalid remote path: {}", path);
+            }
+            resolved.push('/');
+            resolved.push_str(segment);
+        }
+        Ok(PathBuf::from(resolved))
+    }
+
+    // 在持有连接的阻塞线程中执行
+    async fn with_sftp<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&Sftp) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let conn = self.conn().await?;
+        blocking(move || f(&conn.sftp)).await
+    }
+
+    // 用独占方式创建锁文件，已存在时返回 `false`
+    async fn create_lock(&self, path: PathBuf, data: Vec<u8>) -> Result<bool> {
+        self.with_sftp(move |sftp| {
+            let flags = OpenFlags::WRITE | OpenFlags::EXCLUSIVE;
+            match sftp.open_mode(&path, flags, 0o600, OpenType::File) {
+                Ok(mut file) => {
+                    file.write_all(&data)?;
+                    file.close()?;
+                    Ok(true)
+                }
+                // 多数服务器对已存在的文件只返回通用失败，按文件是否存在区分
+                Err(e) => match sftp.stat(&path) {
+                    Ok(_) => Ok(false),
+                    Err(_) => Err(e.into()),
+                },
+            }
+        })
+        .await
+    }
+}
+
+#[async_trait]
+impl SyncBackend for SftpBackend {
+    async fn ensure_dir(&self, dir: &str) -> Result<()> {
+        let target = self.resolve(dir)?.to_string_lossy().into_owned();
+        self.with_sftp(move |sftp| {
+            let mut current = String::new();
+            for (i, segment) in target.split('/').enumerate() {
+                if segment.is_empty() {
+                    // 绝对路径的开头
+                    if i == 0 {
+                        current.push('/');
+                    }
+                    continue;
+                }
+                if !current.is_empty() && !current.ends_with('/') {
+                    current.push('/');
+                }
+                current.push_str(segment);
+                let path = Path::new(&current);
+                if sftp.stat(path).is_ok_and(|s| s.is_dir()) {
+                    continue;
+                }
+                if let Err(e) = sftp.mkdir(path, 0o700) {
+                    // 并发创建时目录可能已经存在
+                    if !sftp.stat(path).is_ok_and(|s| s.is_dir()) {
+                        return Err(e).with_context(|| format!("Failed to create {}", current));
+                    }
+                }
+            }
+            Ok(())
+        })
+        .await
+    }
+
+    async fn list(&self, dir: &str) -> Result<Vec<String>> {
+        let dir = self.resolve(dir)?;
+        self.with_sftp(move |sftp| {
+            let entries = sftp
+                .readdir(&dir)
+                .with_context(|| format!("Failed to list {}", dir.display()))?;
+            Ok(entries
+                .into_iter()
+                .filter(|(_, stat)| stat.is_file())
+                .filter_map(|(path, _)| {
+                    let name = path.file_name()?.to_str()?;
+                    (!is_temp_file_name(name)).then(|| name.to_string())
+                })
+                .collect())
+        })
+        .await
+    }
+
+    async fn stat(&self, path: &str) -> Result<Option<RemoteStat>> {
+        let path = self.resolve(path)?;
+        self.with_sftp(move |sftp| match sftp.stat(&path) {
+            Ok(stat) if stat.is_file() => {
+                let size = stat.size.unwrap_or(0);
+                Ok(Some(RemoteStat {
+                    size,
+                    etag: stat.mtime.map(|mtime| format!("{}-{}", mtime, size)),
+                }))
+            }
+            Ok(_) => Ok(None),
+            Err(e) if is_not_found(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        })
+        .await
+    }
+
+    async fn get(&self, path: &str, target: &Path, expected_hash: Option<&str>) -> Result<String> {
+        let source = self.resolve(path)?;
+        let target = target.to_path_buf();
+        let expected = expected_hash.map(str::to_string);
+        self.with_sftp(move |sftp| {
+            let part = part_path(&target)?;
+            let result = (|| {
+                let remote = sftp
+                    .open(&source)
+                    .with_context(|| format!("Failed to open {}", source.display()))?;
+                let mut out = std::fs::File::create(&part)?;
+                let hash = copy_hashed(remote, &mut out)?;
+                out.sync_all()?;
+                if expected.as_deref().is_some_and(|expected| expected != hash) {
+                    bail!("Checksum mismatch");
+                }
+                Ok(hash)
+            })();
+            match result {
+                Ok(hash) => {
+                    std::fs::rename(&part, &target)?;
+                    Ok(hash)
+                }
+                Err(e) => {
+                    let _ = std::fs::remove_file(&part);
+                    Err(e)
+                }
+            }
+        })
+        .await
+    }
+
+    async fn read(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let path = self.resolve(path)?;
+        self.with_sftp(move |sftp| match sftp.open(&path) {
+            Ok(mut file) => {
+                let mut data = Vec::new();
+                file.read_to_end(&mut data)?;
+                Ok(Some(data))
+            }
+            Err(e) if is_not_found(&e) => Ok(None),
+            Err(e) => Err(e.into()),
+        })
+        .await
+    }
+
+    async fn put(&self, path: &str, source: &Path, hash: &str) -> Result<()> {
+        let dest = self.resolve(path)?;
+        let source = source.to_path_buf();
+        let hash = hash.to_string();
+        self.with_sftp(move |sftp| {
+            upload(sftp, &dest, |file| {
+                let local = std::fs::File::open(&source)
+                    .with_context(|| format!("Failed to open {}", source.display()))?;
+                if copy_hashed(local, file)? != hash {
+                    bail!("File changed during upload");
+                }
+                Ok(())
+            })
+        })
+        .await
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> Result<()> {
+        let dest = self.resolve(path)?;
+        self.with_sftp(move |sftp| upload(sftp, &dest, |file| Ok(file.write_all(&data)?)))
+            .await
+    }
+
+    async fn delete(&self, path: &str) -> Result<()> {
+        let path = self.resolve(path)?;
+        self.with_sftp(move |sftp| match sftp.unlink(&path) {
+            Ok(()) => Ok(()),
+            Err(e) if is_not_found(&e) => Ok(()),
+            Err(e) => Err(e.into()),
+        })
+        .await
+    }
+
+    // SFTP 支持独占创建，可以原子地获取锁
+    async fn lock(&self) -> Result<String> {
+        let path = self.resolve(LOCK_FILE)?;
+        let info = LockInfo::new();
+        let data = serde_json::to_vec(&info)?;
+        for _ in 0..2 {
+            if self.create_lock(path.clone(), data.clone()).await? {
+                return Ok(info.token);
+            }
+            match read_lock(self).await? {
+                Some(holder) if !holder.is_stale() => bail!("Remote is locked by {}", holder.host),
+                Some(_) => {}
+                None => {
+                    // 无法解析时可能是其他设备刚创建、尚未写完内容
+                    let lock_path = path.clone();
+                    let mtime = self
+                        .with_sftp(move |sftp| Ok(sftp.stat(&lock_path).ok().and_then(|s| s.mtime)))
+                        .await?;
+                    if mtime.is_some_and(|t| unix_now() - (t as i64) <= LOCK_TTL_SECS) {
+                        bail!("Remote is locked by another device");
+                    }
+                }
+            }
+            // 失效的锁，删除后重试一次
+            self.delete(LOCK_FILE).await?;
+        }
+        bail!("Failed to create remote lock")
+    }
+}
@@ -7,4 +7,8 @@ pub fn greet(name: String) -> String {
 pub fn init_app() {
     // Default utilities - feel free to customize
     flutter_rust_bridge::setup_default_user_utils();
+    // 设置中开启了自动同步时随应用启动
+    let _ = crate::api::autosync::start_auto_sync();
+    // 设置中开启了控制服务时随应用启动
+    let _ = crate::api::control::start_control_server();
 }
@@ -0,0 +1,734 @@
+use anyhow::{bail, Context, Result};
+use futures_util::stream::{self, StreamExt};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::Instant;
+
+use crate::api::backend::{open_backend, open_planning_backend, SyncBackend, LOCK_FILE};
+use crate::api::endfield::{file_updated_at, get_account_list, get_accounts_dir, AccountInfo};
+use crate::api::history::{
+    fetch_history, history_depth, store_history, upload_blob, RemoteHistory, BLOBS_DIR,
+    HISTORY_FILE,
+};
+use crate::api::instance::{lock_instance, lock_instance_async};
+use crate::api::journal::{ensure_no_interrupted_sync, PullJournal};
+use crate::api::meta::{forget_remote, load_meta, mark_synced};
+use crate::api::report::{SyncAction, SyncOperation, SyncPlan, SyncReport};
+use crate::api::settings::{load_settings, update_settings_with, SyncRemote};
+use crate::api::transfer::hash_file;
+
+// 远端目录结构：
+//   EndSwitcherConfig/<alias>         每个账号的当前副本
+//   EndSwitcherConfig/accounts.json   推送时附带的账号元数据
+//   EndSwitcherConfig/.lock           推送期间持有的互斥锁
+// 历史版本见 history.rs
+const MANIFEST_FILE: &str = "accounts.json";
+
+fn validate_remote_entry_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() || name == "." || name == ".." {
+        bail!("Invalid remote entry name");
+    }
+    if name.chars().any(|c| {
+        c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|')
+    }) {
+        bail!("Invalid remote entry name");
+    }
+    Ok(name.to_string())
+}
+
+// 按名称查找远端，`None` 时取第一个远端
+pub(crate) fn find_remote(name: Option<&str>) -> Result<SyncRemote> {
+    let remotes = load_settings()?.remotes;
+    match name {
+        Some(name) => remotes
+            .into_iter()
+            .find(|r| r.name == name)
+            .with_context(|| format!("Remote not found: {}", name)),
+        None => remotes
+            .into_iter()
+            .next()
+            .context("No sync remote is configured"),
+    }
+}
+
+// 选出本次同步的目标远端：指定名称时只取该远端，否则取所有方向允许的远端
+pub(crate) fn select_remotes(name: Option<&str>, push: bool) -> Result<Vec<SyncRemote>> {
+    let remotes = load_settings()?.remotes;
+    if remotes.is_empty() {
+        bail!("No sync remote is configured");
+    }
+    let allowed = |r: &SyncRemote| {
+        if push {
+            r.direction.allows_push()
+        } else {
+            r.direction.allows_pull()
+        }
+    };
+    let action = if push { "pushing" } else { "pulling" };
+
+    match name {
+        Some(name) => {
+            let remote = remotes
+                .into_iter()
+                .find(|r| r.name == name)
+                .with_context(|| format!("Remote not found: {}", name))?;
+            if !allowed(&remote) {
+                bail!("Remote {} does not allow {}", remote.name, action);
+            }
+            Ok(vec![remote])
+        }
+        None => {
+            let selected: Vec<_> = remotes.into_iter().filter(allowed).collect();
+            if selected.is_empty() {
+                bail!("No remote allows {}", action);
+            }
+            Ok(selected)
+        }
+    }
+}
+
+fn rule_matches(rule: &str, alias: &str, acc: Option<&AccountInfo>) -> bool {
+    let rule = rule.trim();
+    if let Some(tag) = rule.strip_prefix("tag:") {
+        let tag = tag.trim();
+        acc.is_some_and(|a| a.tags.iter().any(|t| t.eq_ignore_ascii_case(tag)))
+    } else if let Some(group) = rule.strip_prefix("group:") {
+        acc.is_some_and(|a| a.group.as_deref() == Some(group.trim()))
+    } else {
+        rule == alias
+    }
+}
+
+// 判断账号是否符合远端的包含/排除规则
+fn remote_accepts(remote: &SyncRemote, alias: &str, acc: Option<&AccountInfo>) -> bool {
+    let included =
+        remote.include.is_empty() || remote.include.iter().any(|r| rule_matches(r, alias, acc));
+    included && !remote.exclude.iter().any(|r| rule_matches(r, alias, acc))
+}
+
+// 按账号同步策略与远端规则筛选需要推送的账号
+fn accounts_to_push(remote: &SyncRemote) -> Result<Vec<AccountInfo>> {
+    Ok(get_account_list()?
+        .into_iter()
+        .filter(|a| a.sync_policy.allows_push() && remote_accepts(remote, &a.alias, Some(a)))
+        .collect())
+}
+
+// 单个账号的同步结果
+enum EntryOutcome {
+    Transferred { hash: String, size: u64 },
+    // 本地与远端内容一致，只需记录同步状态
+    Unchanged(String),
+    Skipped(String),
+    Conflicted(String),
+}
+
+// 转换为报告条目，`transferred` 为实际传输时对应的动作
+fn outcome_entry(outcome: EntryOutcome, transferred: SyncAction) -> (SyncAction, Option<String>) {
+    match outcome {
+        EntryOutcome::Transferred { .. } => (transferred, None),
+        EntryOutcome::Unchanged(_) => (SyncAction::Skipped, Some("Unchanged".to_string())),
+        EntryOutcome::Skipped(reason) => (SyncAction::Skipped, Some(reason)),
+        EntryOutcome::Conflicted(reason) => (SyncAction::Conflicted, Some(reason)),
+    }
+}
+
+fn elapsed_ms(start: Instant) -> u64 {
+    start.elapsed().as_millis() as u64
+}
+
+// 各账号最近一次与 `remote` 同步时的内容哈希
+fn synced_hashes(remote: &str) -> Result<HashMap<String, String>> {
+    Ok(load_meta()?
+        .accounts
+        .into_iter()
+        .filter_map(|(alias, mut meta)| Some((alias, meta.synced_hashes.remove(remote)?)))
+        .collect())
+}
+
+fn transfer_concurrency() -> Result<usize> {
+    Ok(load_settings()?.transfer_concurrency.max(1) as usize)
+}
+
+// 持有远端锁执行 `f`，无论成功与否都会释放锁
+pub(crate) async fn with_remote_lock<T>(
+    backend: &dyn SyncBackend,
+    f: impl std::future::Future<Output = Result<T>>,
+) -> Result<T> {
+    let token = backend.lock().await?;
+    let result = match f.await {
+        Ok(out) => backend.commit().await.map(|_| out),
+        Err(e) => Err(e),
+    };
+    let released = backend.unlock(&token).await;
+    let out = result?;
+    released.context("Failed to release remote lock")?;
+    Ok(out)
+}
+
+// ============== 同步远端 API ==============
+
+/// 获取所有同步远端（按优先级排列）
+pub fn list_remotes() -> anyhow::Result<Vec<SyncRemote>> {
+    Ok(load_settings()?.remotes)
+}
+
+/// 新增或更新（按名称匹配）一个同步远端
+pub fn save_remote(remote: SyncRemote) -> anyhow::Result<()> {
+    let _lock = lock_instance("saving sync remotes")?;
+    let remote = SyncRemote {
+        name: remote.name.trim().to_string(),
+        ..remote
+    };
+    update_settings_with(|settings| {
+        match settings.remotes.iter_mut().find(|r| r.name == remote.name) {
+            Some(existing) => *existing = remote,
+            None => settings.remotes.push(remote),
+        }
+        Ok(())
+    })
+}
+
+/// 删除指定的同步远端
+pub fn remove_remote(name: String) -> anyhow::Result<()> {
+    let _lock = lock_instance("saving sync remotes")?;
+    update_settings_with(|settings| {
+        let before = settings.remotes.len();
+        settings.remotes.retain(|r| r.name != name);
+        if settings.remotes.len() == before {
+            bail!("Remote not found: {}", name);
+        }
+        Ok(())
+    })?;
+    forget_remote(&name)
+}
+
+// ============== 同步 API ==============
+
+/// 推送到指定远端，`remote` 为 `None` 时推送到所有允许推送的远端
+pub async fn sync_push(remote: Option<String>) -> anyhow::Result<SyncReport> {
+    push_all(remote.as_deref(), false).await
+}
+
+/// 推送账号及 manifest 到指定远端，`remote` 为 `None` 时推送到所有允许推送的远端
+pub async fn sync_push_with_manifest(remote: Option<String>) -> anyhow::Result<SyncReport> {
+    push_all(remote.as_deref(), true).await
+}
+
+/// 从指定远端拉取，`remote` 为 `None` 时按顺序从所有允许拉取的远端拉取，同名账号以靠前的远端为准
+pub async fn sync_pull(remote: Option<String>) -> anyhow::Result<SyncReport> {
+    pull_selected(|| select_remotes(remote.as_deref(), false)).await
+}
+
+/// 预演推送或拉取，列出每个账号将执行的操作及原因，不修改本地或远端的任何内容
+/// `remote` 为 `None` 时包含所有允许该方向的远端
+pub async fn plan_sync(
+    operation: SyncOperation,
+    remote: Option<String>,
+) -> anyhow::Result<SyncPlan> {
+    let push = operation == SyncOperation::Push;
+    let mut plan = SyncPlan::new(operation);
+    let mut pulled = HashSet::new();
+    for remote in select_remotes(remote.as_deref(), push)? {
+        let result = async {
+            let backend = open_planning_backend(&remote)?;
+            if push {
+                plan_push(&remote, backend.as_ref(), &mut plan).await
+            } else {
+                plan_pull(&remote, backend.as_ref(), &mut pulled, &mut plan).await
+            }
+        }
+        .await;
+        if let Err(e) = result {
+            let reason = format!("{:#}", e);
+            plan.add(&remote.name, None, SyncAction::Failed, Some(reason));
+        }
+    }
+    Ok(plan)
+}
+
+// 持有应用锁后从 `select` 选出的远端拉取
+pub(crate) async fn pull_selected(
+    select: impl FnOnce() -> Result<Vec<SyncRemote>>,
+) -> Result<SyncReport> {
+    let _lock = lock_instance_async("syncing").await?;
+    let remotes = select()?;
+    let journal = PullJournal::begin(&remotes)?;
+    pull_remotes(remotes, journal).await
+}
+
+// 继续中断的拉取，远端按当前设置中的顺序，已删除的远端不再拉取
+pub(crate) async fn resume_pull(journal: PullJournal) -> Result<SyncReport> {
+    let names = journal.remotes();
+    let remotes = select_remotes(None, false)?
+        .into_iter()
+        .filter(|remote| names.contains(&remote.name))
+        .collect();
+    pull_remotes(remotes, journal).await
+}
+
+async fn pull_remotes(remotes: Vec<SyncRemote>, journal: PullJournal) -> Result<SyncReport> {
+    let mut report = SyncReport::new(SyncOperation::Pull);
+    let mut pulled = HashSet::new();
+    for remote in remotes {
+        let start = Instant::now();
+        let result = async {
+            let backend = open_backend(&remote)?;
+            let backend = backend.as_ref();
+            pull_from_remote(&remote, backend, &journal, &mut pulled, &mut report).await
+        }
+        .await;
+        if let Err(e) = result {
+            report.fail(&remote.name, None, e, elapsed_ms(start));
+        }
+    }
+    // 中途退出时日志保留到下次启动
+    journal.finish()?;
+    Ok(report.finish())
+}
+
+async fn push_all(remote: Option<&str>, manifest: bool) -> Result<SyncReport> {
+    push_selected(|| select_remotes(remote, true), manifest).await
+}
+
+// 持有应用锁后推送到 `select` 选出的远端
+pub(crate) async fn push_selected(
+    select: impl FnOnce() -> Result<Vec<SyncRemote>>,
+    manifest: bool,
+) -> Result<SyncReport> {
+    let _lock = lock_instance_async("syncing").await?;
+    ensure_no_interrupted_sync()?;
+    let mut report = SyncReport::new(SyncOperation::Push);
+    for remote in select()? {
+        let start = Instant::now();
+        let result = async {
+            let backend = open_backend(&remote)?;
+            let backend = backend.as_ref();
+            // 确保远端文件夹存在
+            backend.ensure_dir("").await?;
+            backend.ensure_dir(BLOBS_DIR).await?;
+            let uploaded = with_remote_lock(backend, async {
+                let uploaded = push_to_remote(&remote, backend, &mut report).await?;
+                if manifest {
+                    push_manifest(&remote, backend).await?;
+                }
+                Ok(uploaded)
+            })
+            .await?;
+            // 提交成功后才记录同步状态，推送被拒绝时下次同步仍按本地修改上传
+            for (alias, updated_at, hash) in uploaded {
+                if let Err(e) = mark_synced(&alias, &remote.name, updated_at, &hash) {
+                    report.fail(&remote.name, Some(&alias), e, 0);
+                }
+            }
+            anyhow::Ok(())
+        }
+        .await;
+        if let Err(e) = result {
+            report.fail(&remote.name, None, e, elapsed_ms(start));
+        }
+    }
+    Ok(report.finish())
+}
+
+async fn plan_push(
+    remote: &SyncRemote,
+    backend: &dyn SyncBackend,
+    plan: &mut SyncPlan,
+) -> Result<()> {
+    let depth = history_depth()?;
+    let mut history = fetch_history(backend).await?;
+    let synced = synced_hashes(&remote.name)?;
+    let accounts_dir = get_accounts_dir()?;
+
+    for acc in accounts_to_push(remote)? {
+        let synced_hash = synced.get(&acc.alias).map(String::as_str);
+        let planned = async {
+            let alias = validate_remote_entry_name(&acc.alias)?;
+            let (hash, size) = hash_file(&accounts_dir.join(&alias)).await?;
+            if let Some(outcome) = decide_push(&history, &alias, &hash, synced_hash) {
+                return Ok(outcome_entry(outcome, SyncAction::Uploaded));
+            }
+            let reason = match history.current_hash(&alias) {
+                None => "Not on remote yet",
+                Some(_) => "Local version differs from remote",
+            };
+            // 在副本上记录版本，以便预测会被清理的旧版本
+            history.record_version(&alias, &hash, size, acc.updated_at, depth);
+            anyhow::Ok((SyncAction::Uploaded, Some(reason.to_string())))
+        }
+        .await;
+        let (action, reason) =
+            planned.unwrap_or_else(|e| (SyncAction::Failed, Some(format!("{:#}", e))));
+        plan.add(&remote.name, Some(&acc.alias), action, reason);
+    }
+
+    for (alias, hash) in history.pending_removals() {
+        let short = hash.get(..12).unwrap_or(hash);
+        let reason = format!("Old version {} would be pruned", short);
+        plan.add(&remote.name, Some(alias), SyncAction::Deleted, Some(reason));
+    }
+    Ok(())
+}
+
+async fn plan_pull(
+    remote: &SyncRemote,
+    backend: &dyn SyncBackend,
+    pulled: &mut HashSet<String>,
+    plan: &mut SyncPlan,
+) -> Result<()> {
+    let files = list_pull_candidates(remote, backend).await?;
+    let history = fetch_history(backend).await?;
+    let synced = synced_hashes(&remote.name)?;
+    let accounts_dir = get_accounts_dir()?;
+
+    for filename in files {
+        if pulled.contains(&filename) {
+            let reason = "Already pulled from a higher-priority remote".to_string();
+            plan.add(
+                &remote.name,
+                Some(&filename),
+                SyncAction::Skipped,
+                Some(reason),
+            );
+            continue;
+        }
+        let target = accounts_dir.join(&filename);
+        let synced_hash = synced.get(&filename).map(String::as_str);
+        let (action, reason) = match decide_pull(&history, &target, &filename, synced_hash).await {
+            Ok(Some(outcome)) => {
+                if matches!(outcome, EntryOutcome::Unchanged(_)) {
+                    pulled.insert(filename.clone());
+                }
+                outcome_entry(outcome, SyncAction::Downloaded)
+            }
+            Ok(None) => {
+                pulled.insert(filename.clone());
+                let reason = if !target.exists() {
+                    "Not present locally"
+                } else {
+                    "Remote version differs from local"
+                };
+                (SyncAction::Downloaded, Some(reason.to_string()))
+            }
+            Err(e) => (SyncAction::Failed, Some(format!("{:#}", e))),
+        };
+        plan.add(&remote.name, Some(&filename), action, reason);
+    }
+    Ok(())
+}
+
+// 调用方需持有远端锁，返回需要记录同步状态的账号及其修改时间与内容哈希
+async fn push_to_remote(
+    remote: &SyncRemote,
+    backend: &dyn SyncBackend,
+    report: &mut SyncReport,
+) -> Result<Vec<(String, i64, String)>> {
+    let depth = history_depth()?;
+    let limit = transfer_concurrency()?;
+    let mut history = fetch_history(backend).await?;
+    let synced = synced_hashes(&remote.name)?;
+    let accounts_dir = get_accounts_dir()?;
+    let accounts = accounts_to_push(remote)?;
+
+    let history_ref = &history;
+    let results: Vec<_> = stream::iter(accounts)
+        .map(|acc| {
+            let accounts_dir = &accounts_dir;
+            let synced_hash = synced.get(&acc.alias).map(String::as_str);
+            async move {
+                let start = Instant::now();
+                let result =
+                    push_account(backend, history_ref, accounts_dir, &acc.alias, synced_hash).await;
+                (acc, result, elapsed_ms(start))
+            }
+        })
+        .buffer_unordered(limit)
+        .collect()
+        .await;
+
+    // 各文件的结果在全部传输结束后依次记录，避免并发修改元数据与历史
+    let mut uploaded = Vec::new();
+    for (acc, result, duration_ms) in results {
+        let recorded = result.map(|outcome| {
+            match &outcome {
+                EntryOutcome::Transferred { hash, size } => {
+                    history.record_version(&acc.alias, hash, *size, acc.updated_at, depth);
+                    uploaded.push((acc.alias.clone(), acc.updated_at, hash.clone()));
+                }
+                EntryOutcome::Unchanged(hash) => {
+                    uploaded.push((acc.alias.clone(), acc.updated_at, hash.clone()));
+                }
+                _ => {}
+            }
+            outcome_entry(outcome, SyncAction::Uploaded)
+        });
+        match recorded {
+            Ok((action, reason)) => {
+                report.record(&remote.name, Some(&acc.alias), action, reason, duration_ms)
+            }
+            Err(e) => report.fail(&remote.name, Some(&acc.alias), e, duration_ms),
+        }
+    }
+
+    let removed = store_history(backend, &mut history).await?;
+    for (alias, hash) in removed {
+        let short = hash.get(..12).unwrap_or(&hash);
+        let reason = format!("Pruned old version {}", short);
+        report.record(
+            &remote.name,
+            Some(&alias),
+            SyncAction::Deleted,
+            Some(reason),
+            0,
+        );
+    }
+    Ok(uploaded)
+}
+
+// 判断本地内容是否需要上传，返回 `None` 表示需要上传；远端在上次同步后被其他设备修改时不覆盖
+fn decide_push(
+    history: &RemoteHistory,
+    alias: &str,
+    hash: &str,
+    synced_hash: Option<&str>,
+) -> Option<EntryOutcome> {
+    let remote_hash = history.current_hash(alias);
+    if remote_hash == Some(hash) {
+        return Some(EntryOutcome::Unchanged(hash.to_string()));
+    }
+    if let (Some(remote_hash), Some(synced_hash)) = (remote_hash, synced_hash) {
+        if remote_hash != synced_hash {
+            if hash == synced_hash {
+                return Some(EntryOutcome::Skipped(
+                    "Remote has a newer version".to_string(),
+                ));
+            }
+            return Some(EntryOutcome::Conflicted(
+                "Both local and remote changed since last sync".to_string(),
+            ));
+        }
+    }
+    None
+}
+
+// 上传一个账号的版本内容及当前副本
+async fn push_account(
+    backend: &dyn SyncBackend,
+    history: &RemoteHistory,
+    accounts_dir: &Path,
+    alias: &str,
+    synced_hash: Option<&str>,
+) -> Result<EntryOutcome> {
+    let alias = validate_remote_entry_name(alias)?;
+    let cache_file = accounts_dir.join(&alias);
+
+    let (hash, size) = hash_file(&cache_file).await?;
+    if let Some(outcome) = decide_push(history, &alias, &hash, synced_hash) {
+        return Ok(outcome);
+    }
+
+    upload_blob(backend, history, &cache_file, &hash, size)
+        .await
+        .context("Failed to upload version")?;
+    backend
+        .put(&alias, &cache_file, &hash)
+        .await
+        .context("Failed to upload")?;
+    Ok(EntryOutcome::Transferred { hash, size })
+}
+
+// 上传 manifest，只包含该远端实际同步的账号
+async fn push_manifest(remote: &SyncRemote, backend: &dyn SyncBackend) -> Result<()> {
+    let accounts = accounts_to_push(remote)?;
+    backend
+        .write(MANIFEST_FILE, serde_json::to_vec(&accounts)?)
+        .await
+        .context("Failed to upload manifest")
+}
+
+// 列出远端目录中的账号文件，并按账号同步策略与远端规则筛选需要拉取的账号
+async fn list_pull_candidates(
+    remote: &SyncRemote,
+    backend: &dyn SyncBackend,
+) -> Result<Vec<String>> {
+    let mut files: Vec<String> = backend
+        .list("")
+        .await?
+        .into_iter()
+        .filter(|name| ![MANIFEST_FILE, HISTORY_FILE, LOCK_FILE].contains(&name.as_str()))
+        .filter_map(|name| validate_remote_entry_name(&name).ok())
+        .collect();
+
+    let local: HashMap<String, AccountInfo> = get_account_list()?
+        .into_iter()
+        .map(|a| (a.alias.clone(), a))
+        .collect();
+    // 本地不存在的账号按默认策略处理
+    files.retain(|filename| {
+        let acc = local.get(filename);
+        let policy = acc.map(|a| a.sync_policy).unwrap_or_default();
+        policy.allows_pull() && remote_accepts(remote, filename, acc)
+    });
+    Ok(files)
+}
+
+// `pulled` 记录本轮已从优先级更高的远端下载过的账号，同名账号不再重复下载
+async fn pull_from_remote(
+    remote: &SyncRemote,
+    backend: &dyn SyncBackend,
+    journal: &PullJournal,
+    pulled: &mut HashSet<String>,
+    report: &mut SyncReport,
+) -> Result<()> {
+    let files = list_pull_candidates(remote, backend).await?;
+    let accounts_dir = get_accounts_dir()?;
+
+    // 有历史记录时用其中的哈希校验下载内容
+    let history = fetch_history(backend).await?;
+
+    let (already, files): (Vec<_>, Vec<_>) = files
+        .into_iter()
+        .partition(|filename| pulled.contains(filename));
+    for filename in already {
+        let reason = "Already pulled from a higher-priority remote".to_string();
+        report.record(
+            &remote.name,
+            Some(&filename),
+            SyncAction::Skipped,
+            Some(reason),
+            0,
+        );
+    }
+
+    let limit = transfer_concurrency()?;
+    let synced = synced_hashes(&remote.name)?;
+    // 先判断所有账号，把需要下载的账号写入同步日志后再开始下载
+    let decided: Vec<_> = stream::iter(files)
+        .map(|filename| {
+            let (accounts_dir, history) = (&accounts_dir, &history);
+            let synced_hash = synced.get(&filename).map(String::as_str);
+            async move {
+                let start = Instant::now();
+                let target = accounts_dir.join(&filename);
+                // 远端没有版本历史时本地文件可能是中断前已下载完成的，仍按下载处理
+                let resumed = history.current_hash(&filename).is_none()
+                    && journal.downloaded(&remote.name, &filename);
+                let decision = if resumed {
+                    Ok(None)
+                } else {
+                    decide_pull(history, &target, &filename, synced_hash).await
+                };
+                (filename, target, decision, start)
+            }
+        })
+        .buffer_unordered(limit)
+        .collect()
+        .await;
+    let downloads: Vec<_> = decided
+        .iter()
+        .filter(|(_, _, decision, _)| matches!(decision, Ok(None)))
+        .map(|(filename, ..)| (filename.as_str(), history.current_hash(filename)))
+        .collect();
+    let completed = journal.plan(&remote.name, &downloads)?;
+
+    let (history, completed) = (&history, &completed);
+    let results: Vec<_> = stream::iter(decided)
+        .map(|(filename, target, decision, start)| async move {
+            let result = match decision {
+                // 继续中断的拉取时，已下载完成的账号只需记录同步状态
+                Ok(None) if completed.contains(&filename) => downloaded_outcome(&target).await,
+                Ok(None) => pull_account(backend, history, journal, &target, &filename).await,
+                Ok(Some(outcome)) => Ok(outcome),
+                Err(e) => Err(e),
+            };
+            (filename, target, result, elapsed_ms(start))
+        })
+        .buffer_unordered(limit)
+        .collect()
+        .await;
+
+    for (filename, target, result, duration_ms) in results {
+        let recorded = result.and_then(|outcome| {
+            if let EntryOutcome::Transferred { hash, .. } | EntryOutcome::Unchanged(hash) = &outcome
+            {
+                mark_synced(&filename, &remote.name, file_updated_at(&target)?, hash)?;
+                pulled.insert(filename.clone());
+            }
+            Ok(outcome_entry(outcome, SyncAction::Downloaded))
+        });
+        match recorded {
+            Ok((action, reason)) => {
+                report.record(&remote.name, Some(&filename), action, reason, duration_ms)
+            }
+            Err(e) => report.fail(&remote.name, Some(&filename), e, duration_ms),
+        }
+    }
+
+    Ok(())
+}
+
+// 判断远端内容是否需要下载，返回 `None` 表示需要下载；本地在上次同步后有未推送的修改时不覆盖
+async fn decide_pull(
+    history: &RemoteHistory,
+    target: &Path,
+    filename: &str,
+    synced_hash: Option<&str>,
+) -> Result<Option<EntryOutcome>> {
+    let exists = target.exists();
+    let Some(expected) = history.current_hash(filename) else {
+        // 远端没有该账号的版本记录时无法判断哪边更新，只在本地没有该账号时下载
+        if exists {
+            return Ok(Some(EntryOutcome::Skipped(
+                "Remote has no version history".to_string(),
+            )));
+        }
+        return Ok(None);
+    };
+    if !exists {
+        return Ok(None);
+    }
+    let (local_hash, _) = hash_file(target).await?;
+    if local_hash == expected {
+        return Ok(Some(EntryOutcome::Unchanged(local_hash)));
+    }
+    if let Some(synced_hash) = synced_hash {
+        if local_hash != synced_hash {
+            if expected == synced_hash {
+                return Ok(Some(EntryOutcome::Skipped(
+                    "Local changes have not been pushed yet".to_string(),
+                )));
+            }
+            return Ok(Some(EntryOutcome::Conflicted(
+                "Both local and remote changed since last sync".to_string(),
+            )));
+        }
+    }
+    Ok(None)
+}
+
+// 下载一个账号，完成后记入同步日志
+async fn pull_account(
+    backend: &dyn SyncBackend,
+    history: &RemoteHistory,
+    journal: &PullJournal,
+    target: &Path,
+    filename: &str,
+) -> Result<EntryOutcome> {
+    let expected = history.current_hash(filename);
+    // 校验失败或收到 HTML 错误页时不会覆盖本地文件
+    let hash = backend
+        .get(filename, target, expected)
+        .await
+        .context("Failed to download")?;
+    journal.complete(filename)?;
+    let size = std::fs::metadata(target)?.len();
+    Ok(EntryOutcome::Transferred { hash, size })
+}
+
+// 中断前已下载完成的账号，按本地文件计算结果
+async fn downloaded_outcome(target: &Path) -> Result<EntryOutcome> {
+    let (hash, size) = hash_file(target).await?;
+    Ok(EntryOutcome::Transferred { hash, size })
+}
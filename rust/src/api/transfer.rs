@@ -0,0 +1,215 @@
+use anyhow::{bail, Context, Result};
+use futures_util::StreamExt;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use tokio::io::AsyncWriteExt;
+use tokio_util::io::ReaderStream;
+
+use crate::api::endfield::get_app_data_dir;
+
+// 按请求方法构造指向同一文件、已带认证信息的请求，各后端的地址与认证方式不同
+pub(crate) type RequestFn<'a> =
+    dyn Fn(reqwest::Method) -> reqwest::RequestBuilder + Send + Sync + 'a;
+
+// 单个文件传输失败或校验不通过时的最大尝试次数
+const MAX_ATTEMPTS: u32 = 3;
+// 用于识别伪装成 200 响应的 HTML 错误页
+const HTML_SNIFF_LEN: usize = 512;
+
+// 下载中的临时文件，校验通过后才移动到目标位置
+pub(crate) fn get_transfer_dir() -> Result<PathBuf> {
+    let dir = get_app_data_dir()?.join("transfers");
+    if !dir.exists() {
+        std::fs::create_dir_all(&dir)?;
+    }
+    Ok(dir)
+}
+
+// 下载到 `target` 时使用的临时文件
+pub(crate) fn part_path(target: &Path) -> Result<PathBuf> {
+    let name = target
+        .file_name()
+        .and_then(|n| n.to_str())
+        .context("Invalid download target")?;
+    Ok(get_transfer_dir()?.join(format!("{}.part", name)))
+}
+
+/// 按块计算文件的 SHA-256，返回哈希与大小
+pub(crate) async fn hash_file(path: &Path) -> Result<(String, u64)> {
+    let file = tokio::fs::File::open(path)
+        .await
+        .with_context(|| format!("Failed to open {}", path.display()))?;
+    let mut stream = ReaderStream::new(file);
+    let mut hasher = Sha256::new();
+    let mut size = 0u64;
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        size += chunk.len() as u64;
+        hasher.update(&chunk);
+    }
+    Ok((format!("{:x}", hasher.finalize()), size))
+}
+
+fn looks_like_html(prefix: &[u8]) -> bool {
+    let text = String::from_utf8_lossy(prefix);
+    let text = text
+        .trim_start_matches('\u{feff}')
+        .trim_start()
+        .to_ascii_lowercase();
+    text.starts_with("<!doctype html") || text.starts_with("<html")
+}
+
+// HEAD 响应没有响应体，reqwest 的 `content_length()` 返回的是空响应体的长度，需直接读取响应头
+pub(crate) fn header_content_length(res: &reqwest::Response) -> Option<u64> {
+    res.headers()
+        .get(reqwest::header::CONTENT_LENGTH)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse().ok())
+}
+
+fn is_html_response(res: &reqwest::Response) -> bool {
+    res.headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.trim_start().to_ascii_lowercase().starts_with("text/html"))
+}
+
+async fn put_file_once(request: &RequestFn<'_>, path: &Path, expected_hash: &str) -> Result<()> {
+    let file = tokio::fs::File::open(path).await?;
+    let size = file.metadata().await?.len();
+
+    // 上传的同时计算哈希，发现文件在上传过程中被修改时视为失败
+    let hasher = Arc::new(Mutex::new((Sha256::new(), 0u64)));
+    let tracker = hasher.clone();
+    let stream = ReaderStream::new(file).map(move |chunk| {
+        if let Ok(bytes) = &chunk {
+            let mut state = tracker.lock().unwrap();
+            state.0.update(bytes);
+            state.1 += bytes.len() as u64;
+        }
+        chunk
+    });
+
+    let res = request(reqwest::Method::PUT)
+        .header(reqwest::header::CONTENT_LENGTH, size)
+        .body(reqwest::Body::wrap_stream(stream))
+        .send()
+        .await?;
+    if !res.status().is_success() {
+        let status = res.status();
+        let text = res.text().await.unwrap_or_default();
+        bail!("{} - {}", status, text);
+    }
+
+    let (hasher, sent) = std::mem::take(&mut *hasher.lock().unwrap());
+    if sent != size {
+        bail!("Sent {} bytes but expected {}", sent, size);
+    }
+    if format!("{:x}", hasher.finalize()) != expected_hash {
+        bail!("File changed during upload");
+    }
+
+    // 服务器返回大小时再核对一次
+    let head = request(reqwest::Method::HEAD).send().await?;
+    if head.status().is_success() {
+        if let Some(len) = header_content_length(&head) {
+            if len != size {
+                bail!("Server stored {} bytes but {} were sent", len, size);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 流式上传文件，`expected_hash` 为上传前计算的 SHA-256，失败时重试
+pub(crate) async fn put_file(
+    request: &RequestFn<'_>,
+    path: &Path,
+    expected_hash: &str,
+) -> Result<()> {
+    let mut attempt = 1;
+    loop {
+        match put_file_once(request, path, expected_hash).await {
+            Ok(()) => return Ok(()),
+            Err(e) if attempt >= MAX_ATTEMPTS => return Err(e),
+            Err(_) => attempt += 1,
+        }
+    }
+}
+
+async fn get_file_once(
+    request: &RequestFn<'_>,
+    part: &Path,
+    expected_hash: Option<&str>,
+) -> Result<String> {
+    let mut res = request(reqwest::Method::GET).send().await?;
+    if !res.status().is_success() {
+        bail!("{}", res.status());
+    }
+    if is_html_response(&res) {
+        bail!("Server returned an HTML page instead of the file");
+    }
+    let expected_len = res.content_length();
+
+    let mut file = tokio::fs::File::create(part).await?;
+    let mut hasher = Sha256::new();
+    let mut received = 0u64;
+    let mut prefix = Vec::new();
+    while let Some(chunk) = res.chunk().await? {
+        if prefix.len() < HTML_SNIFF_LEN {
+            let take = (HTML_SNIFF_LEN - prefix.len()).min(chunk.len());
+            prefix.extend_from_slice(&chunk[..take]);
+        }
+        hasher.update(&chunk);
+        received += chunk.len() as u64;
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    drop(file);
+
+    if let Some(len) = expected_len {
+        if len != received {
+            bail!("Received {} bytes but Content-Length is {}", received, len);
+        }
+    }
+    if looks_like_html(&prefix) {
+        bail!("Server returned an HTML page instead of the file");
+    }
+    let hash = format!("{:x}", hasher.finalize());
+    if let Some(expected) = expected_hash {
+        if hash != expected {
+            bail!("Checksum mismatch");
+        }
+    }
+    Ok(hash)
+}
+
+/// 流式下载文件到 `target`，校验通过后才替换目标文件，返回内容的 SHA-256
+pub(crate) async fn get_file(
+    request: &RequestFn<'_>,
+    target: &Path,
+    expected_hash: Option<&str>,
+) -> Result<String> {
+    let part = part_path(target)?;
+
+    let mut attempt = 1;
+    let result = loop {
+        match get_file_once(request, &part, expected_hash).await {
+            Ok(hash) => break Ok(hash),
+            Err(e) if attempt >= MAX_ATTEMPTS => break Err(e),
+            Err(_) => attempt += 1,
+        }
+    };
+    let hash = match result {
+        Ok(hash) => hash,
+        Err(e) => {
+            let _ = tokio::fs::remove_file(&part).await;
+            return Err(e);
+        }
+    };
+
+    // 临时目录与账号目录位于同一数据目录下，rename 不会跨文件系统
+    tokio::fs::rename(&part, target).await?;
+    Ok(hash)
+}
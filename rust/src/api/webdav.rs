@@ -1,8 +1,19 @@
 use anyhow::{bail, Context, Result};
-use directories::BaseDirs;
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::fs;
-use std::path::PathBuf;
+use std::path::Path;
+
+use crate::api::backend::{
+    host_name, read_lock, release_lock_file, LockInfo, RemoteStat, SyncBackend,
+    LOCK_FILE, LOCK_TTL_SECS,
+};
+use crate::api::instance::lock_instance;
+use crate::api::report::SyncReport;
+use crate::api::settings::{
+    load_settings, update_settings_with, RemoteBackend, SyncDirection, SyncRemote,
+};
+use crate::api::sync::{pull_selected, push_selected, select_remotes};
+use crate::api::transfer::{get_file, header_content_length, put_file};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WebDavConfig {
@@ -12,35 +23,7 @@ pub struct WebDavConfig {
     pub path: Option<String>,
 }
 
-fn get_config_file_path() -> Result<PathBuf> {
-    let base_dirs = BaseDirs::new().context("Could not find base directories")?;
-    let path = base_dirs
-        .home_dir()
-        .join("AppData")
-        .join("Roaming")
-        .join("EndSwitcher");
-
-    if !path.exists() {
-        fs::create_dir_all(&path)?;
-    }
-    Ok(path.join("config.json"))
-}
-
-fn get_accounts_dir() -> Result<PathBuf> {
-    let base_dirs = BaseDirs::new().context("Could not find base directories")?;
-    let path = base_dirs
-        .home_dir()
-        .join("AppData")
-        .join("Roaming")
-        .join("EndSwitcher")
-        .join("accounts");
-    if !path.exists() {
-        fs::create_dir_all(&path)?;
-    }
-    Ok(path)
-}
-
-fn build_base_url(config: &WebDavConfig) -> Result<String> {
+pub(crate) fn build_base_url(config: &WebDavConfig) -> Result<String> {
     let url = config.url.trim().trim_end_matches('/');
     if url.is_empty() {
         bail!("WebDAV server URL is required");
@@ -56,227 +39,417 @@ fn build_base_url(config: &WebDavConfig) -> Result<String> {
     Ok(format!("{}/{}/", url, remote_dir))
 }
 
-fn validate_remote_entry_name(name: &str) -> Result<String> {
-    let name = name.trim();
-    if name.is_empty() || name == "." || name == ".." {
-        bail!("Invalid remote entry name");
-    }
-    if name.chars().any(|c| {
-        c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|')
-    }) {
-        bail!("Invalid remote entry name");
+// 使用 WebDAV LOCK 时令牌的前缀，其余令牌来自通用锁文件
+const DAV_LOCK_PREFIX: &str = "dav:";
+
+pub(crate) fn with_auth(req: reqwest::RequestBuilder, config: &WebDavConfig) -> reqwest::RequestBuilder {
+    if let Some(pwd) = &config.password {
+        req.basic_auth(&config.username, Some(pwd))
+    } else if !config.username.is_empty() {
+        req.basic_auth(&config.username, None::<&str>)
+    } else {
+        req
     }
-    Ok(name.to_string())
 }
 
+/// 保存 WebDAV 配置（写入第一个 WebDAV 远端，没有时新建名为 default 的远端）
 pub fn save_webdav_config(config: WebDavConfig) -> anyhow::Result<()> {
-    let path = get_config_file_path()?;
-    let data = serde_json::to_string_pretty(&config)?;
-    fs::write(path, data)?;
-    Ok(())
+    let _lock = lock_instance("saving WebDAV settings")?;
+    update_settings_with(|settings| {
+        let existing = settings
+            .remotes
+            .iter_mut()
+            .find(|remote| matches!(remote.backend, RemoteBackend::WebDav(_)));
+        match existing {
+            Some(existing) => existing.backend = RemoteBackend::WebDav(config),
+            None => settings.remotes.push(SyncRemote {
+                name: "default".to_string(),
+                direction: SyncDirection::TwoWay,
+                backend: RemoteBackend::WebDav(config),
+                include: Vec::new(),
+                exclude: Vec::new(),
+            }),
+        }
+        Ok(())
+    })
 }
 
+/// 读取 WebDAV 配置（第一个 WebDAV 远端）
 pub fn load_webdav_config() -> anyhow::Result<WebDavConfig> {
-    let path = get_config_file_path()?;
-    if !path.exists() {
-        anyhow::bail!("Config file not found");
-    }
-    let data = fs::read_to_string(path)?;
-    let config: WebDavConfig = serde_json::from_str(&data)?;
-    Ok(config)
+    load_settings()?
+        .remotes
+        .into_iter()
+        .find_map(|remote| match remote.backend {
+            RemoteBackend::WebDav(config) => Some(config),
+            _ => None,
+        })
+        .context("WebDAV is not configured")
 }
 
-// 简单的 WebDAV 同步逻辑
+// 以下接口保留给旧版界面，只同步 WebDAV 远端
 
-pub async fn sync_to_webdav() -> anyhow::Result<()> {
-    let config = load_webdav_config()?;
-    let client = reqwest::Client::new();
+fn webdav_remotes(push: bool) -> Result<Vec<SyncRemote>> {
+    let remotes: Vec<_> = select_remotes(None, push)?
+        .into_iter()
+        .filter(|r| matches!(r.backend, RemoteBackend::WebDav(_)))
+        .collect();
+    if remotes.is_empty() {
+        bail!("No WebDAV remote is configured");
+    }
+    Ok(remotes)
+}
 
-    // 本地结构：accounts/<alias>
-    // 远端结构：/webdav_url/EndSwitcherConfig/<alias>
+pub async fn sync_to_webdav() -> anyhow::Result<SyncReport> {
+    push_selected(|| webdav_remotes(true), false).await
+}
 
-    let base_url = build_base_url(&config)?;
+pub async fn sync_to_webdav_with_manifest() -> anyhow::Result<SyncReport> {
+    push_selected(|| webdav_remotes(true), true).await
+}
 
-    // 确保远端文件夹存在 (MKCOL)
-    let mkcol_method = reqwest::Method::from_bytes(b"MKCOL").context("Invalid MKCOL method")?;
-    let mkcol_req = client.request(mkcol_method, &base_url);
-    let mut mkcol_req = mkcol_req;
-    if let Some(pwd) = &config.password {
-        mkcol_req = mkcol_req.basic_auth(&config.username, Some(pwd));
-    } else if !config.username.is_empty() {
-        mkcol_req = mkcol_req.basic_auth(&config.username, None::<&str>);
-    }
-    let _ = mkcol_req.send().await; // 忽略错误，可能远端已经存在文件夹
-
-    let accounts = crate::api::endfield::get_account_list()?;
-    for acc in accounts {
-        let alias = validate_remote_entry_name(&acc.alias)?;
-        let cache_file = get_accounts_dir()?.join(&alias);
-        let file_url = format!("{}{}", base_url, alias);
-        let mut req = client.put(&file_url);
-        if let Some(pwd) = &config.password {
-            req = req.basic_auth(&config.username, Some(pwd));
-        } else if !config.username.is_empty() {
-            req = req.basic_auth(&config.username, None::<&str>);
-        }
+pub async fn sync_from_webdav() -> anyhow::Result<SyncReport> {
+    pull_selected(|| webdav_remotes(false)).await
+}
 
-        let file_data = fs::read(&cache_file)?;
-        let res = req.body(file_data).send().await?;
-        if !res.status().is_success() {
-            let status = res.status();
-            let text = res.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to upload {}: {} - {}", alias, status, text);
-        }
-    }
-    Ok(())
+#[flutter_rust_bridge::frb(ignore)]
+pub(crate) struct WebDavBackend {
+    client: reqwest::Client,
+    config: WebDavConfig,
+    base_url: String,
 }
 
-// 注意：这只是一个骨架，由于从 WebDAV 下载通常需要支持 PROPFIND 解析 XML 来获取目录列表，
-// 或者是拉取一个已知结构的列表。这里简单起见，可以考虑前端不做全量下载，而是依赖一个配置。
-// 为了简化设计，我们在上传时多上传一个 accounts.json 文件存元数据。
+impl WebDavBackend {
+    pub(crate) fn new(config: WebDavConfig) -> Result<Self> {
+        let base_url = build_base_url(&config)?;
+        Ok(Self {
+            client: reqwest::Client::new(),
+            config,
+            base_url,
+        })
+    }
 
-pub async fn sync_to_webdav_with_manifest() -> anyhow::Result<()> {
-    // 调用前面的同步
-    sync_to_webdav().await?;
+    // 逐段编码相对路径
+    fn url(&self, path: &str) -> String {
+        let encoded: Vec<_> = path
+            .split('/')
+            .filter(|s| !s.is_empty())
+            .map(|s| urlencoding::encode(s).into_owned())
+            .collect();
+        format!("{}{}", self.base_url, encoded.join("/"))
+    }
 
-    let config = load_webdav_config()?;
-    let client = reqwest::Client::new();
-    let base_url = build_base_url(&config)?;
+    fn dir_url(&self, dir: &str) -> String {
+        let url = self.url(dir);
+        if url.ends_with('/') {
+            url
+        } else {
+            format!("{}/", url)
+        }
+    }
 
-    // 生成 manifest
-    let accounts = crate::api::endfield::get_account_list()?;
-    let manifest_str = serde_json::to_string(&accounts)?;
+    // 对锁文件发送 LOCK，服务器到期后自动释放，持有者崩溃也不会一直占用
+    async fn send_lock(&self) -> Result<reqwest::Response> {
+        let body = format!(
+            r#"<?xml version="1.0" encoding="utf-8" ?>
+    <D:lockinfo xmlns:D="DAV:">
+      <D:lockscope><D:exclusive/></D:lockscope>
+      <D:locktype><D:write/></D:locktype>
+      <D:owner>{}</D:owner>
+    </D:lockinfo>"#,
+            quick_xml::escape::escape(&host_name())
+        );
+        let method = reqwest::Method::from_bytes(b"LOCK").context("Invalid LOCK method")?;
+        let req = with_auth(
+            self.client
+                .request(method, self.url(LOCK_FILE))
+                .header("Depth", "0")
+                .header("Timeout", format!("Second-{}", LOCK_TTL_SECS))
+                .header("Content-Type", "text/xml")
+                .body(body),
+            &self.config,
+        );
+        Ok(req.send().await?)
+    }
 
-    // Put manifest
-    let manifest_url = format!("{}accounts.json", base_url);
-    let mut req = client.put(&manifest_url);
-    if let Some(pwd) = &config.password {
-        req = req.basic_auth(&config.username, Some(pwd));
-    } else if !config.username.is_empty() {
-        req = req.basic_auth(&config.username, None::<&str>);
+    async fn send_unlock(&self, token: &str) -> Result<()> {
+        let method = reqwest::Method::from_bytes(b"UNLOCK").context("Invalid UNLOCK method")?;
+        let res = with_auth(
+            self.client
+                .request(method, self.url(LOCK_FILE))
+                .header("Lock-Token", format!("<{}>", token)),
+            &self.config,
+        )
+        .send()
+        .await?;
+        if !res.status().is_success() {
+            bail!("Failed to unlock remote: {}", res.status());
+        }
+        Ok(())
     }
 
-    let res = req.body(manifest_str).send().await?;
-    if !res.status().is_success() {
-        anyhow::bail!("Failed to upload manifest");
+    // 返回 `None` 表示服务器不支持 LOCK（仅实现 class 1）
+    async fn acquire_dav_lock(&self) -> Result<Option<String>> {
+        let mut recovered = false;
+        let res = loop {
+            let res = self.send_lock().await?;
+            match res.status() {
+                reqwest::StatusCode::BAD_REQUEST
+                | reqwest::StatusCode::METHOD_NOT_ALLOWED
+                | reqwest::StatusCode::NOT_IMPLEMENTED => return Ok(None),
+                reqwest::StatusCode::LOCKED => match read_lock(self).await? {
+                    // 服务器没有按时释放失效的锁，用锁文件中记录的令牌解锁后重试一次
+                    Some(holder) if holder.is_stale() && !recovered => {
+                        recovered = true;
+                        let _ = self.send_unlock(&holder.token).await;
+                    }
+                    Some(holder) => bail!("Remote is locked by {}", holder.host),
+                    None => bail!("Remote is locked by another device"),
+                },
+                status if status.is_success() => break res,
+                status => bail!("Failed to lock remote: {}", status),
+            }
+        };
+        let token = res
+            .headers()
+            .get("Lock-Token")
+            .and_then(|v| v.to_str().ok())
+            .map(|v| v.trim().trim_start_matches('<').trim_end_matches('>').to_string())
+            .filter(|v| !v.is_empty())
+            .context("Server did not return a lock token")?;
+
+        // 锁文件记录持有者，供其他设备显示锁定者并恢复服务器没有释放的失效锁
+        let info = LockInfo {
+            token: token.clone(),
+            ..LockInfo::new()
+        };
+        let res = with_auth(self.client.put(self.url(LOCK_FILE)), &self.config)
+            .header("If", format!("(<{}>)", token))
+            .body(serde_json::to_vec(&info)?)
+            .send()
+            .await?;
+        if !res.status().is_success() {
+            let _ = self.send_unlock(&token).await;
+            bail!("Failed to create remote lock: {}", res.status());
+        }
+        Ok(Some(token))
     }
 
-    Ok(())
+    // 不支持 LOCK 的服务器用条件写入（`If-None-Match: *`）独占地创建锁文件
+    async fn acquire_lock_file(&self) -> Result<String> {
+        let info = LockInfo::new();
+        let data = serde_json::to_vec(&info)?;
+        for _ in 0..2 {
+            let res = with_auth(self.client.put(self.url(LOCK_FILE)), &self.config)
+                .header(reqwest::header::IF_NONE_MATCH, "*")
+                .body(data.clone())
+                .send()
+                .await?;
+            match res.status() {
+                status if status.is_success() => return Ok(info.token),
+                reqwest::StatusCode::PRECONDITION_FAILED => {}
+                status => bail!("Failed to create remote lock: {}", status),
+            }
+            if let Some(holder) = read_lock(self).await? {
+                if !holder.is_stale() {
+                    bail!("Remote is locked by {}", holder.host);
+                }
+            }
+            // 失效的锁，删除后重试一次
+            self.delete(LOCK_FILE).await?;
+        }
+        bail!("Failed to create remote lock")
+    }
 }
 
-pub async fn sync_from_webdav() -> anyhow::Result<()> {
-    let config = load_webdav_config()?;
-    let client = reqwest::Client::new();
-    let base_url = build_base_url(&config)?;
-
-    // 1. 发送 PROPFIND 请求获取目录下所有文件
-    let propfind_body = r#"<?xml version="1.0" encoding="utf-8" ?>
-<D:propfind xmlns:D="DAV:">
-  <D:prop>
-    <D:resourcetype/>
-  </D:prop>
-</D:propfind>"#;
-
-    let propfind_method = reqwest::Method::from_bytes(b"PROPFIND").context("Invalid PROPFIND method")?;
-    let mut req = client
-        .request(propfind_method, &base_url)
-        .header("Depth", "1")
-        .header("Content-Type", "text/xml")
-        .body(propfind_body);
-
-    if let Some(pwd) = &config.password {
-        req = req.basic_auth(&config.username, Some(pwd));
-    } else if !config.username.is_empty() {
-        req = req.basic_auth(&config.username, None::<&str>);
+#[async_trait]
+impl SyncBackend for WebDavBackend {
+    async fn ensure_dir(&self, dir: &str) -> Result<()> {
+        let mkcol = reqwest::Method::from_bytes(b"MKCOL").context("Invalid MKCOL method")?;
+        let _ = with_auth(self.client.request(mkcol, self.dir_url(dir)), &self.config)
+            .send()
+            .await; // 忽略错误，可能远端已经存在文件夹
+        Ok(())
     }
 
-    let res = req.send().await?;
-    if !res.status().is_success() {
-        anyhow::bail!("Failed to execute PROPFIND on WebDAV server. Check path or permissions.");
-    }
+    async fn list(&self, dir: &str) -> Result<Vec<String>> {
+        // 1. 发送 PROPFIND 请求获取目录下所有文件
+        let propfind_body = r#"<?xml version="1.0" encoding="utf-8" ?>
+    <D:propfind xmlns:D="DAV:">
+      <D:prop>
+        <D:resourcetype/>
+      </D:prop>
+    </D:propfind>"#;
+
+        let propfind_method = reqwest::Method::from_bytes(b"PROPFIND").context("Invalid PROPFIND method")?;
+        let req = with_auth(
+            self.client
+                .request(propfind_method, self.dir_url(dir))
+                .header("Depth", "1")
+                .header("Content-Type", "text/xml")
+                .body(propfind_body),
+            &self.config,
+        );
+
+        let res = req.send().await?;
+        if !res.status().is_success() {
+            anyhow::bail!("Failed to execute PROPFIND on WebDAV server. Check path or permissions.");
+        }
 
-    let xml_response = res.text().await?;
-
-    // 2. 解析 XML 提取所有文件路径 (排除目录)
-    use quick_xml::events::Event;
-    use quick_xml::reader::Reader;
-
-    let mut reader = Reader::from_str(&xml_response);
-    reader.trim_text(true);
-
-    let mut in_href = false;
-    let mut in_collection = false;
-    let mut current_href = String::new();
-    let mut buf = Vec::new();
-    let mut files_to_download = Vec::new();
-
-    loop {
-        match reader.read_event_into(&mut buf) {
-            Ok(Event::Start(ref e)) => {
-                let name = e.name();
-                let name_ref = name.as_ref();
-                if name_ref == b"D:href" || name_ref == b"href" {
-                    in_href = true;
-                } else if name_ref == b"D:collection" || name_ref == b"collection" {
+        let xml_response = res.text().await?;
+
+        // 2. 解析 XML 提取所有文件名 (排除目录)
+        use quick_xml::events::Event;
+        use quick_xml::reader::Reader;
+
+        let mut reader = Reader::from_str(&xml_response);
+        reader.trim_text(true);
+
+        let mut in_href = false;
+        let mut in_collection = false;
+        let mut current_href = String::new();
+        let mut buf = Vec::new();
+        let mut files = Vec::new();
+
+        loop {
+            match reader.read_event_into(&mut buf) {
+                // 只比较本地名称，兼容 `D:`、`d:` 等不同前缀以及默认命名空间
+                Ok(Event::Start(ref e)) => {
+                    let name = e.local_name();
+                    let name_ref = name.as_ref();
+                    if name_ref == b"href" {
+                        in_href = true;
+                    } else if name_ref == b"collection" {
+                        in_collection = true;
+                    }
+                }
+                // 多数服务器把 collection 写成自闭合标签
+                Ok(Event::Empty(ref e)) if e.local_name().as_ref() == b"collection" => {
                     in_collection = true;
                 }
-            }
-            Ok(Event::Text(e)) => {
-                if in_href {
+                Ok(Event::Text(e)) if in_href => {
                     current_href = e.unescape()?.into_owned();
                 }
-            }
-            Ok(Event::End(ref e)) => {
-                let name = e.name();
-                let name_ref = name.as_ref();
-                if name_ref == b"D:href" || name_ref == b"href" {
-                    in_href = false;
-                } else if name_ref == b"D:response" || name_ref == b"response" {
-                    // 如果是一个文件（不是 collection），且不是当前目录（通常以 / 结尾或等同于 base_url）
-                    if !in_collection && !current_href.ends_with('/') {
-                        // 从 href 提取文件名
-                        if let Some(filename) = current_href.split('/').last() {
-                            if !filename.is_empty() && filename != "accounts.json" {
-                                // 进行 URL 解码（如果目录包含中文等）
-                                if let Ok(decoded) = urlencoding::decode(filename) {
-                                    if let Ok(safe_name) = validate_remote_entry_name(&decoded) {
-                                        files_to_download.push(safe_name);
+                Ok(Event::End(ref e)) => {
+                    let name = e.local_name();
+                    let name_ref = name.as_ref();
+                    if name_ref == b"href" {
+                        in_href = false;
+                    } else if name_ref == b"response" {
+                        // 如果是一个文件（不是 collection），且不是当前目录（通常以 / 结尾或等同于 base_url）
+                        if !in_collection && !current_href.ends_with('/') {
+                            // 从 href 提取文件名
+                            if let Some(filename) = current_href.split('/').next_back() {
+                                if !filename.is_empty() {
+                                    // 进行 URL 解码（如果目录包含中文等）
+                                    match urlencoding::decode(filename) {
+                                        Ok(decoded) => files.push(decoded.into_owned()),
+                                        Err(_) => files.push(filename.to_string()),
                                     }
-                                } else if let Ok(safe_name) = validate_remote_entry_name(filename) {
-                                    files_to_download.push(safe_name);
                                 }
                             }
                         }
+                        in_collection = false;
+                        current_href.clear();
                     }
-                    in_collection = false;
-                    current_href.clear();
                 }
+                Ok(Event::Eof) => break,
+                Err(e) => anyhow::bail!("XML parse error: {:?}", e),
+                _ => (),
             }
-            Ok(Event::Eof) => break,
-            Err(e) => anyhow::bail!("XML parse error: {:?}", e),
-            _ => (),
+            buf.clear();
         }
-        buf.clear();
+
+        Ok(files)
     }
 
-    let accounts_dir = get_accounts_dir()?;
+    async fn stat(&self, path: &str) -> Result<Option<RemoteStat>> {
+        let res = with_auth(self.client.head(self.url(path)), &self.config)
+            .send()
+            .await?;
+        if res.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !res.status().is_success() {
+            bail!("{}", res.status());
+        }
+        let etag = res
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        Ok(Some(RemoteStat {
+            size: header_content_length(&res).unwrap_or(0),
+            etag,
+        }))
+    }
 
-    // 3. 挨个下载文件
-    for filename in files_to_download {
-        let file_url = format!("{}{}", base_url, urlencoding::encode(&filename));
-        let mut req = client.get(&file_url);
-        if let Some(pwd) = &config.password {
-            req = req.basic_auth(&config.username, Some(pwd));
-        } else if !config.username.is_empty() {
-            req = req.basic_auth(&config.username, None::<&str>);
+    async fn get(&self, path: &str, target: &Path, expected_hash: Option<&str>) -> Result<String> {
+        let url = self.url(path);
+        let request = |method| with_auth(self.client.request(method, &url), &self.config);
+        get_file(&request, target, expected_hash).await
+    }
+
+    async fn read(&self, path: &str) -> Result<Option<Vec<u8>>> {
+        let res = with_auth(self.client.get(self.url(path)), &self.config)
+            .send()
+            .await?;
+        if res.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !res.status().is_success() {
+            bail!("{}", res.status());
         }
+        Ok(Some(res.bytes().await?.to_vec()))
+    }
 
-        let res = req.send().await?;
-        if res.status().is_success() {
-            let data = res.bytes().await?;
-            fs::write(accounts_dir.join(&filename), data)?;
+    async fn put(&self, path: &str, source: &Path, hash: &str) -> Result<()> {
+        let url = self.url(path);
+        let request = |method| with_auth(self.client.request(method, &url), &self.config);
+        put_file(&request, source, hash).await
+    }
+
+    async fn write(&self, path: &str, data: Vec<u8>) -> Result<()> {
+        let res = with_auth(self.client.put(self.url(path)), &self.config)
+            .body(data)
+            .send()
+            .await?;
+        if !res.status().is_success() {
+            bail!("{}", res.status());
         }
+        Ok(())
     }
 
-    Ok(())
+    async fn delete(&self, path: &str) -> Result<()> {
+        let res = with_auth(self.client.delete(self.url(path)), &self.config)
+            .send()
+            .await?;
+        if !res.status().is_success() && res.status() != reqwest::StatusCode::NOT_FOUND {
+            bail!("{}", res.status());
+        }
+        Ok(())
+    }
+
+    // 优先使用 WebDAV LOCK，服务器不支持时退回通用锁文件
+    async fn lock(&self) -> Result<String> {
+        match self.acquire_dav_lock().await? {
+            Some(token) => Ok(format!("{}{}", DAV_LOCK_PREFIX, token)),
+            None => self.acquire_lock_file().await,
+        }
+    }
+
+    async fn unlock(&self, token: &str) -> Result<()> {
+        let Some(token) = token.strip_prefix(DAV_LOCK_PREFIX) else {
+            return release_lock_file(self, token).await;
+        };
+        // 删除被锁定的资源会同时释放锁
+        let res = with_auth(self.client.delete(self.url(LOCK_FILE)), &self.config)
+            .header("If", format!("(<{}>)", token))
+            .send()
+            .await?;
+        if res.status().is_success() || res.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        // 无法删除时仍然释放锁，残留的锁文件会被下一次 LOCK 接管
+        self.send_unlock(token).await
+    }
 }
@@ -2,42 +2,43 @@
 // @generated by `flutter_rust_bridge`@ 2.11.1.
 
 #![allow(
-    non_camel_case_types,
-    unused,
-    non_snake_case,
-    clippy::needless_return,
-    clippy::redundant_closure_call,
-    clippy::redundant_closure,
-    clippy::useless_conversion,
-    clippy::unit_arg,
-    clippy::unused_unit,
-    clippy::double_parens,
-    clippy::let_and_return,
-    clippy::too_many_arguments,
-    clippy::match_single_binding,
-    clippy::clone_on_copy,
-    clippy::let_unit_value,
-    clippy::deref_addrof,
-    clippy::explicit_auto_deref,
-    clippy::borrow_deref_ref,
-    clippy::needless_borrow
+non_camel_case_types,
+unused,
+non_snake_case,
+clippy::needless_return,
+clippy::redundant_closure_call,
+clippy::redundant_closure,
+clippy::useless_conversion,
+clippy::unit_arg,
+clippy::unused_unit,
+clippy::double_parens,
+clippy::let_and_return,
+clippy::too_many_arguments,
+clippy::match_single_binding,
+clippy::clone_on_copy,
+clippy::let_unit_value,
+clippy::deref_addrof,
+clippy::explicit_auto_deref,
+clippy::borrow_deref_ref,
+clippy::needless_borrow
 )]
 
 // Section: imports
 
-use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, ReadBytesExt, WriteBytesExt};
-use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
 use flutter_rust_bridge::{Handler, IntoIntoDart};
+use flutter_rust_bridge::for_generated::{Lockable, transform_result_dco, Lifetimeable};
+use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, WriteBytesExt, ReadBytesExt};
 
 // Section: boilerplate
 
 flutter_rust_bridge::frb_generated_boilerplate!(
-    default_stream_sink_codec = SseCodec,
-    default_rust_opaque = RustOpaqueMoi,
-    default_rust_auto_opaque = RustAutoOpaqueMoi,
-);
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -106721413;
+                    default_stream_sink_codec = SseCodec,
+                    default_rust_opaque = RustOpaqueMoi,
+                    default_rust_auto_opaque = RustAutoOpaqueMoi,
+                );
+                pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
+                pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1852700311;
+            
 
 // Section: executor
 
@@ -45,766 +46,3103 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__endfield__delete_account_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_account",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_alias = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::endfield::delete_account(api_alias)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__endfield__find_login_cache_path_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "find_login_cache_path",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::endfield::find_login_cache_path()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__endfield__get_account_list_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_account_list",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::endfield::get_account_list()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__simple__greet_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+fn wire__crate__api__search__account_query_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "account_query_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::search::AccountQuery::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__endfield__account_sync_policy_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "account_sync_policy_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::endfield::AccountSyncPolicy::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__app_settings_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "app_settings_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::AppSettings::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__audit__audit_query_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "audit_query_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::audit::AuditQuery::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__auto_sync_settings_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "auto_sync_settings_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::AutoSyncSettings::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__autosync__auto_sync_status_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "auto_sync_status_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::autosync::AutoSyncStatus::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__health__check_account_health_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "check_account_health", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::health::check_account_health(api_alias)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__health__check_accounts_health_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "check_accounts_health", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::health::check_accounts_health()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__report__clear_sync_history_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "clear_sync_history", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::report::clear_sync_history()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__control_server_settings_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "control_server_settings_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::ControlServerSettings::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__control__control_server_status_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "control_server_status_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::control::ControlServerStatus::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__create_group_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "create_group", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::create_group(api_name)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__search__default_account_query_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "default_account_query", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__simple__init_app_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
-            }
-        },
-    )
-}
-fn wire__crate__api__webdav__load_webdav_config_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_webdav_config",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+                     let output_ok = Result::<_,()>::Ok(crate::api::search::default_account_query())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__settings__default_settings_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "default_settings", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::webdav::load_webdav_config()?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__endfield__save_current_account_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_current_account",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::settings::default_settings())?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__endfield__delete_account_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "delete_account", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::endfield::delete_account(api_alias)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__delete_group_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "delete_group", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::delete_group(api_name)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__bundle__export_accounts_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "export_accounts", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_aliases = <Vec<String>>::sse_decode(&mut deserializer);
+let api_path = <String>::sse_decode(&mut deserializer);
+let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::bundle::export_accounts(api_aliases, api_path, api_passphrase)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__endfield__find_login_cache_path_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "find_login_cache_path", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::endfield::find_login_cache_path()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__endfield__get_account_list_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_account_list", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::endfield::get_account_list()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__get_accounts_by_group_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_accounts_by_group", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::get_accounts_by_group()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__get_accounts_by_tag_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_accounts_by_tag", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_tag = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::get_accounts_by_tag(api_tag)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__autosync__get_auto_sync_status_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_auto_sync_status", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::autosync::get_auto_sync_status()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__control__get_control_server_status_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_control_server_status", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::control::get_control_server_status()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__control__get_control_token_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_control_token", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::control::get_control_token()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__endfield__get_current_account_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_current_account", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::endfield::get_current_account()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__get_game_process_status_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_game_process_status", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::launch::get_game_process_status(api_pid)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__journal__get_interrupted_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_interrupted_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::journal::get_interrupted_sync()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__get_settings_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_settings", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::settings::get_settings()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__report__get_sync_history_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "get_sync_history", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::report::get_sync_history()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__simple__greet_impl(ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "greet", port: None, mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);deserializer.end();
+                transform_result_sse::<_, ()>((move || {
+                     let output_ok = Result::<_,()>::Ok(crate::api::simple::greet(api_name))?;   Ok(output_ok)
+                })()) })
+            }fn wire__crate__api__bundle__import_accounts_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "import_accounts", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);
+let api_conflict_policy = <crate::api::bundle::ImportConflictPolicy>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::bundle::import_accounts(api_path, api_passphrase, api_conflict_policy)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__simple__init_app_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "init_app", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok({ crate::api::simple::init_app(); })?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__bundle__inspect_bundle_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "inspect_bundle", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_path = <String>::sse_decode(&mut deserializer);
+let api_passphrase = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::bundle::inspect_bundle(api_path, api_passphrase)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__launch_game_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "launch_game", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::launch::launch_game()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__list_game_processes_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_game_processes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::launch::list_game_processes()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__list_groups_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_groups", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::list_groups()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__history__list_remote_versions_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_remote_versions", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_remote = <Option<String>>::sse_decode(&mut deserializer);
+let api_alias = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::history::list_remote_versions(api_remote, api_alias).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sync__list_remotes_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_remotes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::sync::list_remotes()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__list_tags_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "list_tags", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::list_tags()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__load_launch_config_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "load_launch_config", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::launch::load_launch_config()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__webdav__load_webdav_config_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "load_webdav_config", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::webdav::load_webdav_config()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__path_settings_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "path_settings_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::PathSettings::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__sync__plan_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "plan_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_operation = <crate::api::report::SyncOperation>::sse_decode(&mut deserializer);
+let api_remote = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::sync::plan_sync(api_operation, api_remote).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__audit__query_audit_log_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "query_audit_log", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::audit::AuditQuery>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::audit::query_audit_log(api_query)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__control__regenerate_control_token_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "regenerate_control_token", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::control::regenerate_control_token()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__sync__remove_remote_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "remove_remote", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::sync::remove_remote(api_name)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__rename_group_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "rename_group", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_old_name = <String>::sse_decode(&mut deserializer);
+let api_new_name = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::rename_group(api_old_name, api_new_name)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__reorder_accounts_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "reorder_accounts", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_aliases = <Vec<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::reorder_accounts(api_aliases)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__reorder_groups_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "reorder_groups", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_names = <Vec<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::reorder_groups(api_names)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__reset_settings_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "reset_settings", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::settings::reset_settings()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__autosync__restart_auto_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "restart_auto_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::autosync::restart_auto_sync()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__history__restore_remote_version_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "restore_remote_version", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_remote = <Option<String>>::sse_decode(&mut deserializer);
+let api_alias = <String>::sse_decode(&mut deserializer);
+let api_hash = <String>::sse_decode(&mut deserializer);
+let api_apply_locally = <bool>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::history::restore_remote_version(api_remote, api_alias, api_hash, api_apply_locally).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__journal__resume_interrupted_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "resume_interrupted_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::journal::resume_interrupted_sync().await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__journal__rollback_interrupted_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "rollback_interrupted_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::journal::rollback_interrupted_sync()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__endfield__save_current_account_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "save_current_account", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::endfield::save_current_account(api_alias)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__save_launch_config_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "save_launch_config", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::launch::LaunchConfig>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::launch::save_launch_config(api_config)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__sync__save_remote_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "save_remote", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_remote = <crate::api::settings::SyncRemote>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::sync::save_remote(api_remote)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__webdav__save_webdav_config_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "save_webdav_config", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_config = <crate::api::webdav::WebDavConfig>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::webdav::save_webdav_config(api_config)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__search__search_accounts_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "search_accounts", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <crate::api::search::AccountQuery>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::search::search_accounts(api_query)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__set_account_group_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_account_group", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_alias = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::endfield::save_current_account(api_alias)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__webdav__save_webdav_config_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_webdav_config",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_config = <crate::api::webdav::WebDavConfig>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::webdav::save_webdav_config(api_config)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__endfield__switch_to_account_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "switch_to_account",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+let api_group = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::set_account_group(api_alias, api_group)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__set_account_notes_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_account_notes", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_alias = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            move |context| {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || {
-                        let output_ok = crate::api::endfield::switch_to_account(api_alias)?;
-                        Ok(output_ok)
-                    })(),
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__webdav__sync_from_webdav_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_from_webdav",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::webdav::sync_from_webdav().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__webdav__sync_to_webdav_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_to_webdav",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::webdav::sync_to_webdav().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
-fn wire__crate__api__webdav__sync_to_webdav_with_manifest_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "sync_to_webdav_with_manifest",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>(
-                    (move || async move {
-                        let output_ok = crate::api::webdav::sync_to_webdav_with_manifest().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
-        },
-    )
-}
+let api_notes = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::set_account_notes(api_alias, api_notes)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__set_account_sync_policy_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_account_sync_policy", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);
+let api_policy = <crate::api::endfield::AccountSyncPolicy>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::set_account_sync_policy(api_alias, api_policy)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__meta__set_account_tags_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "set_account_tags", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);
+let api_tags = <Vec<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::meta::set_account_tags(api_alias, api_tags)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__autosync__start_auto_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "start_auto_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::autosync::start_auto_sync()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__control__start_control_server_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "start_control_server", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::control::start_control_server()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__autosync__stop_auto_sync_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stop_auto_sync", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::autosync::stop_auto_sync()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__control__stop_control_server_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "stop_control_server", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::control::stop_control_server()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__switch_and_launch_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "switch_and_launch", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::launch::switch_and_launch(api_alias)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__endfield__switch_to_account_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "switch_to_account", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_alias = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::endfield::switch_to_account(api_alias)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__sync_direction_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_direction_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::SyncDirection::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__webdav__sync_from_webdav_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_from_webdav", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::webdav::sync_from_webdav().await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sync__sync_pull_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_pull", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_remote = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::sync::sync_pull(api_remote).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sync__sync_push_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_push", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_remote = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::sync::sync_push(api_remote).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__sync__sync_push_with_manifest_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_push_with_manifest", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_remote = <Option<String>>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::sync::sync_push_with_manifest(api_remote).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__webdav__sync_to_webdav_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_to_webdav", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::webdav::sync_to_webdav().await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__webdav__sync_to_webdav_with_manifest_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "sync_to_webdav_with_manifest", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::webdav::sync_to_webdav_with_manifest().await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }fn wire__crate__api__settings__theme_mode_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "theme_mode_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::ThemeMode::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__theme_settings_default_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "theme_settings_default", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, ()>((move ||  {
+                         let output_ok = Result::<_,()>::Ok(crate::api::settings::ThemeSettings::default())?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__autosync__trigger_auto_sync_now_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "trigger_auto_sync_now", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::autosync::trigger_auto_sync_now()?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__settings__update_settings_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "update_settings", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings = <crate::api::settings::AppSettings>::sse_decode(&mut deserializer);deserializer.end(); move |context|  {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move ||  {
+                         let output_ok = crate::api::settings::update_settings(api_settings)?;   Ok(output_ok)
+                    })())
+                } })
+            }fn wire__crate__api__launch__wait_game_exit_impl(port_: flutter_rust_bridge::for_generated::MessagePort,ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,rust_vec_len_: i32,data_len_: i32)  {
+                FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "wait_game_exit", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_pid = <u32>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, flutter_rust_bridge::for_generated::anyhow::Error>((move || async move {
+                         let output_ok = crate::api::launch::wait_game_exit(api_pid).await?;   Ok(output_ok)
+                    })().await)
+                } })
+            }
 
 // Section: dart2rust
 
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for crate::api::endfield::AccountInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_alias = <String>::sse_decode(deserializer);
-        let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        return crate::api::endfield::AccountInfo {
-            alias: var_alias,
-            updated_at: var_updatedAt,
-        };
-    }
-}
-
-impl SseDecode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for Vec<crate::api::endfield::AccountInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
+
+                impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);}
+                }
+                
+                impl SseDecode for String {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();}
+                }
+                
+                impl SseDecode for crate::api::meta::AccountGroup {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_name = <Option<String>>::sse_decode(deserializer);
+let mut var_accounts = <Vec<crate::api::endfield::AccountInfo>>::sse_decode(deserializer);
+return crate::api::meta::AccountGroup{name: var_name, accounts: var_accounts};}
+                }
+                
+                impl SseDecode for crate::api::health::AccountHealth {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_alias = <String>::sse_decode(deserializer);
+let mut var_status = <crate::api::health::HealthStatus>::sse_decode(deserializer);
+let mut var_size = <u64>::sse_decode(deserializer);
+let mut var_ageSecs = <i64>::sse_decode(deserializer);
+let mut var_expiresAt = <Option<i64>>::sse_decode(deserializer);
+let mut var_issues = <Vec<String>>::sse_decode(deserializer);
+return crate::api::health::AccountHealth{alias: var_alias, status: var_status, size: var_size, age_secs: var_ageSecs, expires_at: var_expiresAt, issues: var_issues};}
+                }
+                
+                impl SseDecode for crate::api::endfield::AccountInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_alias = <String>::sse_decode(deserializer);
+let mut var_updatedAt = <i64>::sse_decode(deserializer);
+let mut var_group = <Option<String>>::sse_decode(deserializer);
+let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+let mut var_notes = <Option<String>>::sse_decode(deserializer);
+let mut var_lastUsedAt = <Option<i64>>::sse_decode(deserializer);
+let mut var_syncStatus = <crate::api::endfield::SyncStatus>::sse_decode(deserializer);
+let mut var_syncPolicy = <crate::api::endfield::AccountSyncPolicy>::sse_decode(deserializer);
+let mut var_health = <crate::api::health::HealthStatus>::sse_decode(deserializer);
+return crate::api::endfield::AccountInfo{alias: var_alias, updated_at: var_updatedAt, group: var_group, tags: var_tags, notes: var_notes, last_used_at: var_lastUsedAt, sync_status: var_syncStatus, sync_policy: var_syncPolicy, health: var_health};}
+                }
+                
+                impl SseDecode for crate::api::search::AccountQuery {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_text = <Option<String>>::sse_decode(deserializer);
+let mut var_fuzzy = <bool>::sse_decode(deserializer);
+let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+let mut var_group = <Option<String>>::sse_decode(deserializer);
+let mut var_usedAfter = <Option<i64>>::sse_decode(deserializer);
+let mut var_usedBefore = <Option<i64>>::sse_decode(deserializer);
+let mut var_stale = <Option<bool>>::sse_decode(deserializer);
+let mut var_syncStatus = <Option<crate::api::endfield::SyncStatus>>::sse_decode(deserializer);
+let mut var_sortBy = <crate::api::search::AccountSortKey>::sse_decode(deserializer);
+let mut var_descending = <bool>::sse_decode(deserializer);
+return crate::api::search::AccountQuery{text: var_text, fuzzy: var_fuzzy, tags: var_tags, group: var_group, used_after: var_usedAfter, used_before: var_usedBefore, stale: var_stale, sync_status: var_syncStatus, sort_by: var_sortBy, descending: var_descending};}
+                }
+                
+                impl SseDecode for crate::api::search::AccountSortKey {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::search::AccountSortKey::Manual,
+1 => crate::api::search::AccountSortKey::Alias,
+2 => crate::api::search::AccountSortKey::UpdatedAt,
+3 => crate::api::search::AccountSortKey::LastUsedAt,
+4 => crate::api::search::AccountSortKey::Relevance,
+            _ => unreachable!("Invalid variant for AccountSortKey: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::endfield::AccountSyncPolicy {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::endfield::AccountSyncPolicy::Never,
+1 => crate::api::endfield::AccountSyncPolicy::PushOnly,
+2 => crate::api::endfield::AccountSyncPolicy::TwoWay,
+            _ => unreachable!("Invalid variant for AccountSyncPolicy: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::settings::AppSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_schemaVersion = <u32>::sse_decode(deserializer);
+let mut var_paths = <crate::api::settings::PathSettings>::sse_decode(deserializer);
+let mut var_remotes = <Vec<crate::api::settings::SyncRemote>>::sse_decode(deserializer);
+let mut var_backupDepth = <u32>::sse_decode(deserializer);
+let mut var_remoteHistoryDepth = <u32>::sse_decode(deserializer);
+let mut var_transferConcurrency = <u32>::sse_decode(deserializer);
+let mut var_lockTimeoutSecs = <u32>::sse_decode(deserializer);
+let mut var_launch = <Option<crate::api::launch::LaunchConfig>>::sse_decode(deserializer);
+let mut var_autoSync = <crate::api::settings::AutoSyncSettings>::sse_decode(deserializer);
+let mut var_controlServer = <crate::api::settings::ControlServerSettings>::sse_decode(deserializer);
+let mut var_hooks = <Vec<crate::api::hooks::HookConfig>>::sse_decode(deserializer);
+let mut var_theme = <crate::api::settings::ThemeSettings>::sse_decode(deserializer);
+return crate::api::settings::AppSettings{schema_version: var_schemaVersion, paths: var_paths, remotes: var_remotes, backup_depth: var_backupDepth, remote_history_depth: var_remoteHistoryDepth, transfer_concurrency: var_transferConcurrency, lock_timeout_secs: var_lockTimeoutSecs, launch: var_launch, auto_sync: var_autoSync, control_server: var_controlServer, hooks: var_hooks, theme: var_theme};}
+                }
+                
+                impl SseDecode for crate::api::audit::AuditEntry {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_timestamp = <i64>::sse_decode(deserializer);
+let mut var_operation = <crate::api::audit::AuditOperation>::sse_decode(deserializer);
+let mut var_alias = <Option<String>>::sse_decode(deserializer);
+let mut var_source = <Option<String>>::sse_decode(deserializer);
+let mut var_target = <Option<String>>::sse_decode(deserializer);
+let mut var_success = <bool>::sse_decode(deserializer);
+let mut var_error = <Option<String>>::sse_decode(deserializer);
+let mut var_device = <String>::sse_decode(deserializer);
+return crate::api::audit::AuditEntry{timestamp: var_timestamp, operation: var_operation, alias: var_alias, source: var_source, target: var_target, success: var_success, error: var_error, device: var_device};}
+                }
+                
+                impl SseDecode for crate::api::audit::AuditOperation {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::audit::AuditOperation::Save,
+1 => crate::api::audit::AuditOperation::Switch,
+2 => crate::api::audit::AuditOperation::Delete,
+3 => crate::api::audit::AuditOperation::Push,
+4 => crate::api::audit::AuditOperation::Pull,
+5 => crate::api::audit::AuditOperation::Import,
+6 => crate::api::audit::AuditOperation::Restore,
+7 => crate::api::audit::AuditOperation::Rollback,
+            _ => unreachable!("Invalid variant for AuditOperation: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::audit::AuditQuery {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_alias = <Option<String>>::sse_decode(deserializer);
+let mut var_operation = <Option<crate::api::audit::AuditOperation>>::sse_decode(deserializer);
+let mut var_since = <Option<i64>>::sse_decode(deserializer);
+let mut var_until = <Option<i64>>::sse_decode(deserializer);
+let mut var_failuresOnly = <bool>::sse_decode(deserializer);
+let mut var_limit = <Option<u32>>::sse_decode(deserializer);
+return crate::api::audit::AuditQuery{alias: var_alias, operation: var_operation, since: var_since, until: var_until, failures_only: var_failuresOnly, limit: var_limit};}
+                }
+                
+                impl SseDecode for crate::api::settings::AutoSyncSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_enabled = <bool>::sse_decode(deserializer);
+let mut var_intervalMinutes = <u32>::sse_decode(deserializer);
+let mut var_onChange = <bool>::sse_decode(deserializer);
+let mut var_onStartup = <bool>::sse_decode(deserializer);
+return crate::api::settings::AutoSyncSettings{enabled: var_enabled, interval_minutes: var_intervalMinutes, on_change: var_onChange, on_startup: var_onStartup};}
+                }
+                
+                impl SseDecode for crate::api::autosync::AutoSyncStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_active = <bool>::sse_decode(deserializer);
+let mut var_syncing = <bool>::sse_decode(deserializer);
+let mut var_lastSuccessAt = <Option<i64>>::sse_decode(deserializer);
+let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+let mut var_lastErrorAt = <Option<i64>>::sse_decode(deserializer);
+let mut var_nextRunAt = <Option<i64>>::sse_decode(deserializer);
+let mut var_consecutiveFailures = <u32>::sse_decode(deserializer);
+return crate::api::autosync::AutoSyncStatus{active: var_active, syncing: var_syncing, last_success_at: var_lastSuccessAt, last_error: var_lastError, last_error_at: var_lastErrorAt, next_run_at: var_nextRunAt, consecutive_failures: var_consecutiveFailures};}
+                }
+                
+                impl SseDecode for bool {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u8().unwrap() != 0}
+                }
+                
+                impl SseDecode for crate::api::bundle::BundleInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_version = <u8>::sse_decode(deserializer);
+let mut var_encrypted = <bool>::sse_decode(deserializer);
+let mut var_createdAt = <Option<i64>>::sse_decode(deserializer);
+let mut var_aliases = <Vec<String>>::sse_decode(deserializer);
+return crate::api::bundle::BundleInfo{version: var_version, encrypted: var_encrypted, created_at: var_createdAt, aliases: var_aliases};}
+                }
+                
+                impl SseDecode for crate::api::settings::ControlServerSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_enabled = <bool>::sse_decode(deserializer);
+let mut var_port = <u16>::sse_decode(deserializer);
+return crate::api::settings::ControlServerSettings{enabled: var_enabled, port: var_port};}
+                }
+                
+                impl SseDecode for crate::api::control::ControlServerStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_running = <bool>::sse_decode(deserializer);
+let mut var_port = <Option<u16>>::sse_decode(deserializer);
+return crate::api::control::ControlServerStatus{running: var_running, port: var_port};}
+                }
+                
+                impl SseDecode for crate::api::launch::GameProcessStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_pid = <u32>::sse_decode(deserializer);
+let mut var_running = <bool>::sse_decode(deserializer);
+let mut var_exitCode = <Option<i32>>::sse_decode(deserializer);
+return crate::api::launch::GameProcessStatus{pid: var_pid, running: var_running, exit_code: var_exitCode};}
+                }
+                
+                impl SseDecode for crate::api::git::GitConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_path = <String>::sse_decode(deserializer);
+let mut var_remoteUrl = <Option<String>>::sse_decode(deserializer);
+let mut var_branch = <String>::sse_decode(deserializer);
+let mut var_passphrase = <String>::sse_decode(deserializer);
+return crate::api::git::GitConfig{path: var_path, remote_url: var_remoteUrl, branch: var_branch, passphrase: var_passphrase};}
+                }
+                
+                impl SseDecode for crate::api::health::HealthStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::health::HealthStatus::Fresh,
+1 => crate::api::health::HealthStatus::Aging,
+2 => crate::api::health::HealthStatus::LikelyExpired,
+3 => crate::api::health::HealthStatus::Invalid,
+            _ => unreachable!("Invalid variant for HealthStatus: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::hooks::HookConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_event = <crate::api::hooks::HookEvent>::sse_decode(deserializer);
+let mut var_command = <String>::sse_decode(deserializer);
+let mut var_args = <Vec<String>>::sse_decode(deserializer);
+let mut var_timeoutSecs = <u32>::sse_decode(deserializer);
+let mut var_enabled = <bool>::sse_decode(deserializer);
+return crate::api::hooks::HookConfig{event: var_event, command: var_command, args: var_args, timeout_secs: var_timeoutSecs, enabled: var_enabled};}
+                }
+                
+                impl SseDecode for crate::api::hooks::HookEvent {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::hooks::HookEvent::PreSwitch,
+1 => crate::api::hooks::HookEvent::PostSwitch,
+2 => crate::api::hooks::HookEvent::PreSave,
+3 => crate::api::hooks::HookEvent::PostSave,
+            _ => unreachable!("Invalid variant for HookEvent: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::hooks::HookRun {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_event = <crate::api::hooks::HookEvent>::sse_decode(deserializer);
+let mut var_command = <String>::sse_decode(deserializer);
+let mut var_exitCode = <Option<i32>>::sse_decode(deserializer);
+let mut var_timedOut = <bool>::sse_decode(deserializer);
+let mut var_stdout = <String>::sse_decode(deserializer);
+let mut var_stderr = <String>::sse_decode(deserializer);
+let mut var_error = <Option<String>>::sse_decode(deserializer);
+let mut var_durationMs = <u64>::sse_decode(deserializer);
+return crate::api::hooks::HookRun{event: var_event, command: var_command, exit_code: var_exitCode, timed_out: var_timedOut, stdout: var_stdout, stderr: var_stderr, error: var_error, duration_ms: var_durationMs};}
+                }
+                
+                impl SseDecode for i32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_i32::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for i64 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_i64::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for crate::api::bundle::ImportAction {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::bundle::ImportAction::Created,
+1 => crate::api::bundle::ImportAction::Overwritten,
+2 => crate::api::bundle::ImportAction::Renamed,
+3 => crate::api::bundle::ImportAction::Skipped,
+            _ => unreachable!("Invalid variant for ImportAction: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::bundle::ImportConflictPolicy {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::bundle::ImportConflictPolicy::Skip,
+1 => crate::api::bundle::ImportConflictPolicy::Overwrite,
+2 => crate::api::bundle::ImportConflictPolicy::KeepNewer,
+3 => crate::api::bundle::ImportConflictPolicy::Rename,
+            _ => unreachable!("Invalid variant for ImportConflictPolicy: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::bundle::ImportedAccount {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_originalAlias = <String>::sse_decode(deserializer);
+let mut var_alias = <String>::sse_decode(deserializer);
+let mut var_action = <crate::api::bundle::ImportAction>::sse_decode(deserializer);
+return crate::api::bundle::ImportedAccount{original_alias: var_originalAlias, alias: var_alias, action: var_action};}
+                }
+                
+                impl SseDecode for crate::api::journal::InterruptedSync {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_startedAt = <i64>::sse_decode(deserializer);
+let mut var_remotes = <Vec<String>>::sse_decode(deserializer);
+let mut var_completed = <Vec<String>>::sse_decode(deserializer);
+let mut var_pending = <Vec<String>>::sse_decode(deserializer);
+return crate::api::journal::InterruptedSync{started_at: var_startedAt, remotes: var_remotes, completed: var_completed, pending: var_pending};}
+                }
+                
+                impl SseDecode for crate::api::launch::LaunchConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_executable = <String>::sse_decode(deserializer);
+let mut var_args = <Vec<String>>::sse_decode(deserializer);
+let mut var_workingDir = <Option<String>>::sse_decode(deserializer);
+let mut var_wrapper = <Option<String>>::sse_decode(deserializer);
+let mut var_wrapperArgs = <Vec<String>>::sse_decode(deserializer);
+let mut var_env = <Vec<crate::api::launch::LaunchEnvVar>>::sse_decode(deserializer);
+return crate::api::launch::LaunchConfig{executable: var_executable, args: var_args, working_dir: var_workingDir, wrapper: var_wrapper, wrapper_args: var_wrapperArgs, env: var_env};}
+                }
+                
+                impl SseDecode for crate::api::launch::LaunchEnvVar {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_key = <String>::sse_decode(deserializer);
+let mut var_value = <String>::sse_decode(deserializer);
+return crate::api::launch::LaunchEnvVar{key: var_key, value: var_value};}
+                }
+                
+                impl SseDecode for Vec<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<String>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::meta::AccountGroup> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::meta::AccountGroup>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::health::AccountHealth> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::health::AccountHealth>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::endfield::AccountInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::endfield::AccountInfo>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::audit::AuditEntry> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::audit::AuditEntry>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::launch::GameProcessStatus> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::launch::GameProcessStatus>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::hooks::HookConfig> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::hooks::HookConfig>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::hooks::HookRun> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::hooks::HookRun>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::bundle::ImportedAccount> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::bundle::ImportedAccount>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::launch::LaunchEnvVar> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::launch::LaunchEnvVar>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::report::PlannedEntry> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::report::PlannedEntry>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<u8> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<u8>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::history::RemoteVersion> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::history::RemoteVersion>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::report::SyncEntry> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::endfield::AccountInfo>::sse_decode(
-                deserializer,
-            ));
-        }
-        return ans_;
-    }
-}
-
-impl SseDecode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
+        for idx_ in 0..len_ { ans_.push(<crate::api::report::SyncEntry>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::settings::SyncRemote> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
         let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
-        }
-        return ans_;
-    }
-}
-
-impl SseDecode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
-    }
-}
-
-impl SseDecode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
-    }
-}
-
-impl SseDecode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
-}
-
-impl SseDecode for crate::api::webdav::WebDavConfig {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_url = <String>::sse_decode(deserializer);
-        let mut var_username = <String>::sse_decode(deserializer);
-        let mut var_password = <Option<String>>::sse_decode(deserializer);
-        let mut var_path = <Option<String>>::sse_decode(deserializer);
-        return crate::api::webdav::WebDavConfig {
-            url: var_url,
-            username: var_username,
-            password: var_password,
-            path: var_path,
-        };
-    }
-}
-
-impl SseDecode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
-}
-
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__endfield__delete_account_impl(port, ptr, rust_vec_len, data_len),
-        2 => wire__crate__api__endfield__find_login_cache_path_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        3 => wire__crate__api__endfield__get_account_list_impl(port, ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__webdav__load_webdav_config_impl(port, ptr, rust_vec_len, data_len),
-        7 => {
-            wire__crate__api__endfield__save_current_account_impl(port, ptr, rust_vec_len, data_len)
-        }
-        8 => wire__crate__api__webdav__save_webdav_config_impl(port, ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__endfield__switch_to_account_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__webdav__sync_from_webdav_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__webdav__sync_to_webdav_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__webdav__sync_to_webdav_with_manifest_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        _ => unreachable!(),
-    }
-}
-
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        4 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
-    }
-}
+        for idx_ in 0..len_ { ans_.push(<crate::api::settings::SyncRemote>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for Vec<crate::api::report::SyncReport> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ { ans_.push(<crate::api::report::SyncReport>::sse_decode(deserializer)); }
+        return ans_;}
+                }
+                
+                impl SseDecode for crate::api::local_folder::LocalFolderConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_path = <String>::sse_decode(deserializer);
+return crate::api::local_folder::LocalFolderConfig{path: var_path};}
+                }
+                
+                impl SseDecode for Option<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<String>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::api::audit::AuditOperation> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::api::audit::AuditOperation>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<bool> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<bool>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<i32> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<i32>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<i64> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<i64>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::api::journal::InterruptedSync> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::api::journal::InterruptedSync>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::api::launch::LaunchConfig> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::api::launch::LaunchConfig>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<crate::api::endfield::SyncStatus> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<crate::api::endfield::SyncStatus>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<u16> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<u16>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for Option<u32> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {if (<bool>::sse_decode(deserializer)) {
+                return Some(<u32>::sse_decode(deserializer));
+            } else {
+                return None;
+            }}
+                }
+                
+                impl SseDecode for crate::api::settings::PathSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_gameDataDir = <Option<String>>::sse_decode(deserializer);
+return crate::api::settings::PathSettings{game_data_dir: var_gameDataDir};}
+                }
+                
+                impl SseDecode for crate::api::report::PlannedEntry {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_remote = <String>::sse_decode(deserializer);
+let mut var_name = <Option<String>>::sse_decode(deserializer);
+let mut var_action = <crate::api::report::SyncAction>::sse_decode(deserializer);
+let mut var_reason = <Option<String>>::sse_decode(deserializer);
+return crate::api::report::PlannedEntry{remote: var_remote, name: var_name, action: var_action, reason: var_reason};}
+                }
+                
+                impl SseDecode for crate::api::settings::RemoteBackend {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut tag_ = <i32>::sse_decode(deserializer);
+            match tag_ {0 => { let mut var_field0 = <crate::api::webdav::WebDavConfig>::sse_decode(deserializer);
+return crate::api::settings::RemoteBackend::WebDav(var_field0); }
+1 => { let mut var_field0 = <crate::api::local_folder::LocalFolderConfig>::sse_decode(deserializer);
+return crate::api::settings::RemoteBackend::Folder(var_field0); }
+2 => { let mut var_field0 = <crate::api::s3::S3Config>::sse_decode(deserializer);
+return crate::api::settings::RemoteBackend::S3(var_field0); }
+3 => { let mut var_field0 = <crate::api::git::GitConfig>::sse_decode(deserializer);
+return crate::api::settings::RemoteBackend::Git(var_field0); }
+4 => { let mut var_field0 = <crate::api::sftp::SftpConfig>::sse_decode(deserializer);
+return crate::api::settings::RemoteBackend::Sftp(var_field0); }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseDecode for crate::api::history::RemoteVersion {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_hash = <String>::sse_decode(deserializer);
+let mut var_size = <u64>::sse_decode(deserializer);
+let mut var_updatedAt = <i64>::sse_decode(deserializer);
+let mut var_uploadedAt = <i64>::sse_decode(deserializer);
+let mut var_current = <bool>::sse_decode(deserializer);
+return crate::api::history::RemoteVersion{hash: var_hash, size: var_size, updated_at: var_updatedAt, uploaded_at: var_uploadedAt, current: var_current};}
+                }
+                
+                impl SseDecode for crate::api::s3::S3Config {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_endpoint = <String>::sse_decode(deserializer);
+let mut var_region = <String>::sse_decode(deserializer);
+let mut var_bucket = <String>::sse_decode(deserializer);
+let mut var_accessKeyId = <String>::sse_decode(deserializer);
+let mut var_secretAccessKey = <String>::sse_decode(deserializer);
+let mut var_prefix = <Option<String>>::sse_decode(deserializer);
+let mut var_pathStyle = <bool>::sse_decode(deserializer);
+return crate::api::s3::S3Config{endpoint: var_endpoint, region: var_region, bucket: var_bucket, access_key_id: var_accessKeyId, secret_access_key: var_secretAccessKey, prefix: var_prefix, path_style: var_pathStyle};}
+                }
+                
+                impl SseDecode for crate::api::sftp::SftpConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_host = <String>::sse_decode(deserializer);
+let mut var_port = <u16>::sse_decode(deserializer);
+let mut var_username = <String>::sse_decode(deserializer);
+let mut var_password = <Option<String>>::sse_decode(deserializer);
+let mut var_privateKeyPath = <Option<String>>::sse_decode(deserializer);
+let mut var_passphrase = <Option<String>>::sse_decode(deserializer);
+let mut var_path = <Option<String>>::sse_decode(deserializer);
+let mut var_knownHostsPath = <Option<String>>::sse_decode(deserializer);
+let mut var_acceptNewHost = <bool>::sse_decode(deserializer);
+return crate::api::sftp::SftpConfig{host: var_host, port: var_port, username: var_username, password: var_password, private_key_path: var_privateKeyPath, passphrase: var_passphrase, path: var_path, known_hosts_path: var_knownHostsPath, accept_new_host: var_acceptNewHost};}
+                }
+                
+                impl SseDecode for crate::api::report::SyncAction {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::report::SyncAction::Uploaded,
+1 => crate::api::report::SyncAction::Downloaded,
+2 => crate::api::report::SyncAction::Skipped,
+3 => crate::api::report::SyncAction::Conflicted,
+4 => crate::api::report::SyncAction::Deleted,
+5 => crate::api::report::SyncAction::Failed,
+            _ => unreachable!("Invalid variant for SyncAction: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::settings::SyncDirection {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::settings::SyncDirection::PushOnly,
+1 => crate::api::settings::SyncDirection::PullOnly,
+2 => crate::api::settings::SyncDirection::TwoWay,
+            _ => unreachable!("Invalid variant for SyncDirection: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::report::SyncEntry {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_remote = <String>::sse_decode(deserializer);
+let mut var_name = <Option<String>>::sse_decode(deserializer);
+let mut var_action = <crate::api::report::SyncAction>::sse_decode(deserializer);
+let mut var_reason = <Option<String>>::sse_decode(deserializer);
+let mut var_durationMs = <u64>::sse_decode(deserializer);
+return crate::api::report::SyncEntry{remote: var_remote, name: var_name, action: var_action, reason: var_reason, duration_ms: var_durationMs};}
+                }
+                
+                impl SseDecode for crate::api::report::SyncOperation {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::report::SyncOperation::Push,
+1 => crate::api::report::SyncOperation::Pull,
+            _ => unreachable!("Invalid variant for SyncOperation: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::report::SyncPlan {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_operation = <crate::api::report::SyncOperation>::sse_decode(deserializer);
+let mut var_entries = <Vec<crate::api::report::PlannedEntry>>::sse_decode(deserializer);
+return crate::api::report::SyncPlan{operation: var_operation, entries: var_entries};}
+                }
+                
+                impl SseDecode for crate::api::settings::SyncRemote {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_name = <String>::sse_decode(deserializer);
+let mut var_direction = <crate::api::settings::SyncDirection>::sse_decode(deserializer);
+let mut var_backend = <crate::api::settings::RemoteBackend>::sse_decode(deserializer);
+let mut var_include = <Vec<String>>::sse_decode(deserializer);
+let mut var_exclude = <Vec<String>>::sse_decode(deserializer);
+return crate::api::settings::SyncRemote{name: var_name, direction: var_direction, backend: var_backend, include: var_include, exclude: var_exclude};}
+                }
+                
+                impl SseDecode for crate::api::report::SyncReport {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_operation = <crate::api::report::SyncOperation>::sse_decode(deserializer);
+let mut var_startedAt = <i64>::sse_decode(deserializer);
+let mut var_finishedAt = <i64>::sse_decode(deserializer);
+let mut var_entries = <Vec<crate::api::report::SyncEntry>>::sse_decode(deserializer);
+return crate::api::report::SyncReport{operation: var_operation, started_at: var_startedAt, finished_at: var_finishedAt, entries: var_entries};}
+                }
+                
+                impl SseDecode for crate::api::endfield::SyncStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::endfield::SyncStatus::NeverSynced,
+1 => crate::api::endfield::SyncStatus::Synced,
+2 => crate::api::endfield::SyncStatus::Modified,
+            _ => unreachable!("Invalid variant for SyncStatus: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::settings::ThemeMode {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::settings::ThemeMode::System,
+1 => crate::api::settings::ThemeMode::Light,
+2 => crate::api::settings::ThemeMode::Dark,
+            _ => unreachable!("Invalid variant for ThemeMode: {}", inner),
+        };}
+                }
+                
+                impl SseDecode for crate::api::settings::ThemeSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_mode = <crate::api::settings::ThemeMode>::sse_decode(deserializer);
+let mut var_accentColor = <Option<String>>::sse_decode(deserializer);
+return crate::api::settings::ThemeSettings{mode: var_mode, accent_color: var_accentColor};}
+                }
+                
+                impl SseDecode for u16 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u16::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for u32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u32::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for u64 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u64::<NativeEndian>().unwrap()}
+                }
+                
+                impl SseDecode for u8 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {deserializer.cursor.read_u8().unwrap()}
+                }
+                
+                impl SseDecode for () {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+                }
+                
+                impl SseDecode for crate::api::webdav::WebDavConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {let mut var_url = <String>::sse_decode(deserializer);
+let mut var_username = <String>::sse_decode(deserializer);
+let mut var_password = <Option<String>>::sse_decode(deserializer);
+let mut var_path = <Option<String>>::sse_decode(deserializer);
+return crate::api::webdav::WebDavConfig{url: var_url, username: var_username, password: var_password, path: var_path};}
+                }
+                
+                fn pde_ffi_dispatcher_primary_impl(
+                    func_id: i32,port: flutter_rust_bridge::for_generated::MessagePort,
+                    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+                    rust_vec_len: i32,
+                    data_len: i32,
+                )  {
+                    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+                    match func_id {
+                        1 => wire__crate__api__search__account_query_default_impl(port, ptr, rust_vec_len, data_len),
+2 => wire__crate__api__endfield__account_sync_policy_default_impl(port, ptr, rust_vec_len, data_len),
+3 => wire__crate__api__settings__app_settings_default_impl(port, ptr, rust_vec_len, data_len),
+4 => wire__crate__api__audit__audit_query_default_impl(port, ptr, rust_vec_len, data_len),
+5 => wire__crate__api__settings__auto_sync_settings_default_impl(port, ptr, rust_vec_len, data_len),
+6 => wire__crate__api__autosync__auto_sync_status_default_impl(port, ptr, rust_vec_len, data_len),
+7 => wire__crate__api__health__check_account_health_impl(port, ptr, rust_vec_len, data_len),
+8 => wire__crate__api__health__check_accounts_health_impl(port, ptr, rust_vec_len, data_len),
+9 => wire__crate__api__report__clear_sync_history_impl(port, ptr, rust_vec_len, data_len),
+10 => wire__crate__api__settings__control_server_settings_default_impl(port, ptr, rust_vec_len, data_len),
+11 => wire__crate__api__control__control_server_status_default_impl(port, ptr, rust_vec_len, data_len),
+12 => wire__crate__api__meta__create_group_impl(port, ptr, rust_vec_len, data_len),
+15 => wire__crate__api__endfield__delete_account_impl(port, ptr, rust_vec_len, data_len),
+16 => wire__crate__api__meta__delete_group_impl(port, ptr, rust_vec_len, data_len),
+17 => wire__crate__api__bundle__export_accounts_impl(port, ptr, rust_vec_len, data_len),
+18 => wire__crate__api__endfield__find_login_cache_path_impl(port, ptr, rust_vec_len, data_len),
+19 => wire__crate__api__endfield__get_account_list_impl(port, ptr, rust_vec_len, data_len),
+20 => wire__crate__api__meta__get_accounts_by_group_impl(port, ptr, rust_vec_len, data_len),
+21 => wire__crate__api__meta__get_accounts_by_tag_impl(port, ptr, rust_vec_len, data_len),
+22 => wire__crate__api__autosync__get_auto_sync_status_impl(port, ptr, rust_vec_len, data_len),
+23 => wire__crate__api__control__get_control_server_status_impl(port, ptr, rust_vec_len, data_len),
+24 => wire__crate__api__control__get_control_token_impl(port, ptr, rust_vec_len, data_len),
+25 => wire__crate__api__endfield__get_current_account_impl(port, ptr, rust_vec_len, data_len),
+26 => wire__crate__api__launch__get_game_process_status_impl(port, ptr, rust_vec_len, data_len),
+27 => wire__crate__api__journal__get_interrupted_sync_impl(port, ptr, rust_vec_len, data_len),
+28 => wire__crate__api__settings__get_settings_impl(port, ptr, rust_vec_len, data_len),
+29 => wire__crate__api__report__get_sync_history_impl(port, ptr, rust_vec_len, data_len),
+31 => wire__crate__api__bundle__import_accounts_impl(port, ptr, rust_vec_len, data_len),
+32 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+33 => wire__crate__api__bundle__inspect_bundle_impl(port, ptr, rust_vec_len, data_len),
+34 => wire__crate__api__launch__launch_game_impl(port, ptr, rust_vec_len, data_len),
+35 => wire__crate__api__launch__list_game_processes_impl(port, ptr, rust_vec_len, data_len),
+36 => wire__crate__api__meta__list_groups_impl(port, ptr, rust_vec_len, data_len),
+37 => wire__crate__api__history__list_remote_versions_impl(port, ptr, rust_vec_len, data_len),
+38 => wire__crate__api__sync__list_remotes_impl(port, ptr, rust_vec_len, data_len),
+39 => wire__crate__api__meta__list_tags_impl(port, ptr, rust_vec_len, data_len),
+40 => wire__crate__api__launch__load_launch_config_impl(port, ptr, rust_vec_len, data_len),
+41 => wire__crate__api__webdav__load_webdav_config_impl(port, ptr, rust_vec_len, data_len),
+42 => wire__crate__api__settings__path_settings_default_impl(port, ptr, rust_vec_len, data_len),
+43 => wire__crate__api__sync__plan_sync_impl(port, ptr, rust_vec_len, data_len),
+44 => wire__crate__api__audit__query_audit_log_impl(port, ptr, rust_vec_len, data_len),
+45 => wire__crate__api__control__regenerate_control_token_impl(port, ptr, rust_vec_len, data_len),
+46 => wire__crate__api__sync__remove_remote_impl(port, ptr, rust_vec_len, data_len),
+47 => wire__crate__api__meta__rename_group_impl(port, ptr, rust_vec_len, data_len),
+48 => wire__crate__api__meta__reorder_accounts_impl(port, ptr, rust_vec_len, data_len),
+49 => wire__crate__api__meta__reorder_groups_impl(port, ptr, rust_vec_len, data_len),
+50 => wire__crate__api__settings__reset_settings_impl(port, ptr, rust_vec_len, data_len),
+51 => wire__crate__api__autosync__restart_auto_sync_impl(port, ptr, rust_vec_len, data_len),
+52 => wire__crate__api__history__restore_remote_version_impl(port, ptr, rust_vec_len, data_len),
+53 => wire__crate__api__journal__resume_interrupted_sync_impl(port, ptr, rust_vec_len, data_len),
+54 => wire__crate__api__journal__rollback_interrupted_sync_impl(port, ptr, rust_vec_len, data_len),
+55 => wire__crate__api__endfield__save_current_account_impl(port, ptr, rust_vec_len, data_len),
+56 => wire__crate__api__launch__save_launch_config_impl(port, ptr, rust_vec_len, data_len),
+57 => wire__crate__api__sync__save_remote_impl(port, ptr, rust_vec_len, data_len),
+58 => wire__crate__api__webdav__save_webdav_config_impl(port, ptr, rust_vec_len, data_len),
+59 => wire__crate__api__search__search_accounts_impl(port, ptr, rust_vec_len, data_len),
+60 => wire__crate__api__meta__set_account_group_impl(port, ptr, rust_vec_len, data_len),
+61 => wire__crate__api__meta__set_account_notes_impl(port, ptr, rust_vec_len, data_len),
+62 => wire__crate__api__meta__set_account_sync_policy_impl(port, ptr, rust_vec_len, data_len),
+63 => wire__crate__api__meta__set_account_tags_impl(port, ptr, rust_vec_len, data_len),
+64 => wire__crate__api__autosync__start_auto_sync_impl(port, ptr, rust_vec_len, data_len),
+65 => wire__crate__api__control__start_control_server_impl(port, ptr, rust_vec_len, data_len),
+66 => wire__crate__api__autosync__stop_auto_sync_impl(port, ptr, rust_vec_len, data_len),
+67 => wire__crate__api__control__stop_control_server_impl(port, ptr, rust_vec_len, data_len),
+68 => wire__crate__api__launch__switch_and_launch_impl(port, ptr, rust_vec_len, data_len),
+69 => wire__crate__api__endfield__switch_to_account_impl(port, ptr, rust_vec_len, data_len),
+70 => wire__crate__api__settings__sync_direction_default_impl(port, ptr, rust_vec_len, data_len),
+71 => wire__crate__api__webdav__sync_from_webdav_impl(port, ptr, rust_vec_len, data_len),
+72 => wire__crate__api__sync__sync_pull_impl(port, ptr, rust_vec_len, data_len),
+73 => wire__crate__api__sync__sync_push_impl(port, ptr, rust_vec_len, data_len),
+74 => wire__crate__api__sync__sync_push_with_manifest_impl(port, ptr, rust_vec_len, data_len),
+75 => wire__crate__api__webdav__sync_to_webdav_impl(port, ptr, rust_vec_len, data_len),
+76 => wire__crate__api__webdav__sync_to_webdav_with_manifest_impl(port, ptr, rust_vec_len, data_len),
+77 => wire__crate__api__settings__theme_mode_default_impl(port, ptr, rust_vec_len, data_len),
+78 => wire__crate__api__settings__theme_settings_default_impl(port, ptr, rust_vec_len, data_len),
+79 => wire__crate__api__autosync__trigger_auto_sync_now_impl(port, ptr, rust_vec_len, data_len),
+80 => wire__crate__api__settings__update_settings_impl(port, ptr, rust_vec_len, data_len),
+81 => wire__crate__api__launch__wait_game_exit_impl(port, ptr, rust_vec_len, data_len),
+                        _ => unreachable!(),
+                    }
+                }
+                
+                fn pde_ffi_dispatcher_sync_impl(
+                    func_id: i32,
+                    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+                    rust_vec_len: i32,
+                    data_len: i32,
+                ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+                    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+                    match func_id {
+                        13 => wire__crate__api__search__default_account_query_impl(ptr, rust_vec_len, data_len),
+14 => wire__crate__api__settings__default_settings_impl(ptr, rust_vec_len, data_len),
+30 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+                        _ => unreachable!(),
+                    }
+                }
+                
 
 // Section: rust2dart
 
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::endfield::AccountInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.alias.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::endfield::AccountInfo
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::endfield::AccountInfo>
-    for crate::api::endfield::AccountInfo
-{
-    fn into_into_dart(self) -> crate::api::endfield::AccountInfo {
-        self
-    }
-}
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::webdav::WebDavConfig {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.url.into_into_dart().into_dart(),
-            self.username.into_into_dart().into_dart(),
-            self.password.into_into_dart().into_dart(),
-            self.path.into_into_dart().into_dart(),
-        ]
-        .into_dart()
-    }
-}
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::webdav::WebDavConfig
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::webdav::WebDavConfig>
-    for crate::api::webdav::WebDavConfig
-{
-    fn into_into_dart(self) -> crate::api::webdav::WebDavConfig {
-        self
-    }
-}
-
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
-    }
-}
-
-impl SseEncode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
-    }
-}
-
-impl SseEncode for crate::api::endfield::AccountInfo {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.alias, serializer);
-        <i64>::sse_encode(self.updated_at, serializer);
-    }
-}
-
-impl SseEncode for i64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
-    }
-}
-
-impl SseEncode for Vec<crate::api::endfield::AccountInfo> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::endfield::AccountInfo>::sse_encode(item, serializer);
-        }
-    }
-}
-
-impl SseEncode for Vec<u8> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
-    }
-}
-
-impl SseEncode for Option<String> {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
-    }
-}
-
-impl SseEncode for u8 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self).unwrap();
-    }
-}
-
-impl SseEncode for () {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
-}
-
-impl SseEncode for crate::api::webdav::WebDavConfig {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.url, serializer);
-        <String>::sse_encode(self.username, serializer);
-        <Option<String>>::sse_encode(self.password, serializer);
-        <Option<String>>::sse_encode(self.path, serializer);
-    }
-}
-
-impl SseEncode for i32 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
-    }
-}
-
-impl SseEncode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
-    }
-}
-
-#[cfg(not(target_family = "wasm"))]
-mod io {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.11.1.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
-
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_io!();
-}
-#[cfg(not(target_family = "wasm"))]
-pub use io::*;
-
-/// cbindgen:ignore
-#[cfg(target_family = "wasm")]
-mod web {
-    // This file is automatically generated, so please do not edit it.
-    // @generated by `flutter_rust_bridge`@ 2.11.1.
-
-    // Section: imports
-
-    use super::*;
-    use flutter_rust_bridge::for_generated::byteorder::{
-        NativeEndian, ReadBytesExt, WriteBytesExt,
-    };
-    use flutter_rust_bridge::for_generated::wasm_bindgen;
-    use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
-    use flutter_rust_bridge::for_generated::{transform_result_dco, Lifetimeable, Lockable};
-    use flutter_rust_bridge::{Handler, IntoIntoDart};
-
-    // Section: boilerplate
-
-    flutter_rust_bridge::frb_generated_boilerplate_web!();
-}
-#[cfg(target_family = "wasm")]
-pub use web::*;
+            impl flutter_rust_bridge::IntoDart for crate::api::meta::AccountGroup {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.name.into_into_dart().into_dart(),
+self.accounts.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::meta::AccountGroup {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::meta::AccountGroup> for crate::api::meta::AccountGroup {
+            fn into_into_dart(self) -> crate::api::meta::AccountGroup {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::health::AccountHealth {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.alias.into_into_dart().into_dart(),
+self.status.into_into_dart().into_dart(),
+self.size.into_into_dart().into_dart(),
+self.age_secs.into_into_dart().into_dart(),
+self.expires_at.into_into_dart().into_dart(),
+self.issues.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::health::AccountHealth {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::health::AccountHealth> for crate::api::health::AccountHealth {
+            fn into_into_dart(self) -> crate::api::health::AccountHealth {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::endfield::AccountInfo {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.alias.into_into_dart().into_dart(),
+self.updated_at.into_into_dart().into_dart(),
+self.group.into_into_dart().into_dart(),
+self.tags.into_into_dart().into_dart(),
+self.notes.into_into_dart().into_dart(),
+self.last_used_at.into_into_dart().into_dart(),
+self.sync_status.into_into_dart().into_dart(),
+self.sync_policy.into_into_dart().into_dart(),
+self.health.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::endfield::AccountInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::endfield::AccountInfo> for crate::api::endfield::AccountInfo {
+            fn into_into_dart(self) -> crate::api::endfield::AccountInfo {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::search::AccountQuery {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.text.into_into_dart().into_dart(),
+self.fuzzy.into_into_dart().into_dart(),
+self.tags.into_into_dart().into_dart(),
+self.group.into_into_dart().into_dart(),
+self.used_after.into_into_dart().into_dart(),
+self.used_before.into_into_dart().into_dart(),
+self.stale.into_into_dart().into_dart(),
+self.sync_status.into_into_dart().into_dart(),
+self.sort_by.into_into_dart().into_dart(),
+self.descending.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::search::AccountQuery {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::AccountQuery> for crate::api::search::AccountQuery {
+            fn into_into_dart(self) -> crate::api::search::AccountQuery {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::search::AccountSortKey {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Manual => 0.into_dart(),
+Self::Alias => 1.into_dart(),
+Self::UpdatedAt => 2.into_dart(),
+Self::LastUsedAt => 3.into_dart(),
+Self::Relevance => 4.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::search::AccountSortKey {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::search::AccountSortKey> for crate::api::search::AccountSortKey {
+            fn into_into_dart(self) -> crate::api::search::AccountSortKey {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::endfield::AccountSyncPolicy {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Never => 0.into_dart(),
+Self::PushOnly => 1.into_dart(),
+Self::TwoWay => 2.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::endfield::AccountSyncPolicy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::endfield::AccountSyncPolicy> for crate::api::endfield::AccountSyncPolicy {
+            fn into_into_dart(self) -> crate::api::endfield::AccountSyncPolicy {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::AppSettings {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.schema_version.into_into_dart().into_dart(),
+self.paths.into_into_dart().into_dart(),
+self.remotes.into_into_dart().into_dart(),
+self.backup_depth.into_into_dart().into_dart(),
+self.remote_history_depth.into_into_dart().into_dart(),
+self.transfer_concurrency.into_into_dart().into_dart(),
+self.lock_timeout_secs.into_into_dart().into_dart(),
+self.launch.into_into_dart().into_dart(),
+self.auto_sync.into_into_dart().into_dart(),
+self.control_server.into_into_dart().into_dart(),
+self.hooks.into_into_dart().into_dart(),
+self.theme.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::AppSettings {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::AppSettings> for crate::api::settings::AppSettings {
+            fn into_into_dart(self) -> crate::api::settings::AppSettings {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditEntry {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.timestamp.into_into_dart().into_dart(),
+self.operation.into_into_dart().into_dart(),
+self.alias.into_into_dart().into_dart(),
+self.source.into_into_dart().into_dart(),
+self.target.into_into_dart().into_dart(),
+self.success.into_into_dart().into_dart(),
+self.error.into_into_dart().into_dart(),
+self.device.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::audit::AuditEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditEntry> for crate::api::audit::AuditEntry {
+            fn into_into_dart(self) -> crate::api::audit::AuditEntry {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditOperation {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Save => 0.into_dart(),
+Self::Switch => 1.into_dart(),
+Self::Delete => 2.into_dart(),
+Self::Push => 3.into_dart(),
+Self::Pull => 4.into_dart(),
+Self::Import => 5.into_dart(),
+Self::Restore => 6.into_dart(),
+Self::Rollback => 7.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::audit::AuditOperation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditOperation> for crate::api::audit::AuditOperation {
+            fn into_into_dart(self) -> crate::api::audit::AuditOperation {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::audit::AuditQuery {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.alias.into_into_dart().into_dart(),
+self.operation.into_into_dart().into_dart(),
+self.since.into_into_dart().into_dart(),
+self.until.into_into_dart().into_dart(),
+self.failures_only.into_into_dart().into_dart(),
+self.limit.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::audit::AuditQuery {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::audit::AuditQuery> for crate::api::audit::AuditQuery {
+            fn into_into_dart(self) -> crate::api::audit::AuditQuery {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::AutoSyncSettings {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.enabled.into_into_dart().into_dart(),
+self.interval_minutes.into_into_dart().into_dart(),
+self.on_change.into_into_dart().into_dart(),
+self.on_startup.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::AutoSyncSettings {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::AutoSyncSettings> for crate::api::settings::AutoSyncSettings {
+            fn into_into_dart(self) -> crate::api::settings::AutoSyncSettings {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::autosync::AutoSyncStatus {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.active.into_into_dart().into_dart(),
+self.syncing.into_into_dart().into_dart(),
+self.last_success_at.into_into_dart().into_dart(),
+self.last_error.into_into_dart().into_dart(),
+self.last_error_at.into_into_dart().into_dart(),
+self.next_run_at.into_into_dart().into_dart(),
+self.consecutive_failures.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::autosync::AutoSyncStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::autosync::AutoSyncStatus> for crate::api::autosync::AutoSyncStatus {
+            fn into_into_dart(self) -> crate::api::autosync::AutoSyncStatus {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::bundle::BundleInfo {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.version.into_into_dart().into_dart(),
+self.encrypted.into_into_dart().into_dart(),
+self.created_at.into_into_dart().into_dart(),
+self.aliases.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::bundle::BundleInfo {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bundle::BundleInfo> for crate::api::bundle::BundleInfo {
+            fn into_into_dart(self) -> crate::api::bundle::BundleInfo {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::ControlServerSettings {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.enabled.into_into_dart().into_dart(),
+self.port.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::ControlServerSettings {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::ControlServerSettings> for crate::api::settings::ControlServerSettings {
+            fn into_into_dart(self) -> crate::api::settings::ControlServerSettings {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::control::ControlServerStatus {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.running.into_into_dart().into_dart(),
+self.port.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::control::ControlServerStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::control::ControlServerStatus> for crate::api::control::ControlServerStatus {
+            fn into_into_dart(self) -> crate::api::control::ControlServerStatus {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::launch::GameProcessStatus {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.pid.into_into_dart().into_dart(),
+self.running.into_into_dart().into_dart(),
+self.exit_code.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::launch::GameProcessStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::launch::GameProcessStatus> for crate::api::launch::GameProcessStatus {
+            fn into_into_dart(self) -> crate::api::launch::GameProcessStatus {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::git::GitConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.path.into_into_dart().into_dart(),
+self.remote_url.into_into_dart().into_dart(),
+self.branch.into_into_dart().into_dart(),
+self.passphrase.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::git::GitConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::git::GitConfig> for crate::api::git::GitConfig {
+            fn into_into_dart(self) -> crate::api::git::GitConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::health::HealthStatus {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Fresh => 0.into_dart(),
+Self::Aging => 1.into_dart(),
+Self::LikelyExpired => 2.into_dart(),
+Self::Invalid => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::health::HealthStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::health::HealthStatus> for crate::api::health::HealthStatus {
+            fn into_into_dart(self) -> crate::api::health::HealthStatus {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::hooks::HookConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.event.into_into_dart().into_dart(),
+self.command.into_into_dart().into_dart(),
+self.args.into_into_dart().into_dart(),
+self.timeout_secs.into_into_dart().into_dart(),
+self.enabled.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::hooks::HookConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hooks::HookConfig> for crate::api::hooks::HookConfig {
+            fn into_into_dart(self) -> crate::api::hooks::HookConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::hooks::HookEvent {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::PreSwitch => 0.into_dart(),
+Self::PostSwitch => 1.into_dart(),
+Self::PreSave => 2.into_dart(),
+Self::PostSave => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::hooks::HookEvent {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hooks::HookEvent> for crate::api::hooks::HookEvent {
+            fn into_into_dart(self) -> crate::api::hooks::HookEvent {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::hooks::HookRun {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.event.into_into_dart().into_dart(),
+self.command.into_into_dart().into_dart(),
+self.exit_code.into_into_dart().into_dart(),
+self.timed_out.into_into_dart().into_dart(),
+self.stdout.into_into_dart().into_dart(),
+self.stderr.into_into_dart().into_dart(),
+self.error.into_into_dart().into_dart(),
+self.duration_ms.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::hooks::HookRun {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::hooks::HookRun> for crate::api::hooks::HookRun {
+            fn into_into_dart(self) -> crate::api::hooks::HookRun {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::bundle::ImportAction {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Created => 0.into_dart(),
+Self::Overwritten => 1.into_dart(),
+Self::Renamed => 2.into_dart(),
+Self::Skipped => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::bundle::ImportAction {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bundle::ImportAction> for crate::api::bundle::ImportAction {
+            fn into_into_dart(self) -> crate::api::bundle::ImportAction {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::bundle::ImportConflictPolicy {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Skip => 0.into_dart(),
+Self::Overwrite => 1.into_dart(),
+Self::KeepNewer => 2.into_dart(),
+Self::Rename => 3.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::bundle::ImportConflictPolicy {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bundle::ImportConflictPolicy> for crate::api::bundle::ImportConflictPolicy {
+            fn into_into_dart(self) -> crate::api::bundle::ImportConflictPolicy {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::bundle::ImportedAccount {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.original_alias.into_into_dart().into_dart(),
+self.alias.into_into_dart().into_dart(),
+self.action.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::bundle::ImportedAccount {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::bundle::ImportedAccount> for crate::api::bundle::ImportedAccount {
+            fn into_into_dart(self) -> crate::api::bundle::ImportedAccount {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::journal::InterruptedSync {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.started_at.into_into_dart().into_dart(),
+self.remotes.into_into_dart().into_dart(),
+self.completed.into_into_dart().into_dart(),
+self.pending.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::journal::InterruptedSync {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::journal::InterruptedSync> for crate::api::journal::InterruptedSync {
+            fn into_into_dart(self) -> crate::api::journal::InterruptedSync {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::launch::LaunchConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.executable.into_into_dart().into_dart(),
+self.args.into_into_dart().into_dart(),
+self.working_dir.into_into_dart().into_dart(),
+self.wrapper.into_into_dart().into_dart(),
+self.wrapper_args.into_into_dart().into_dart(),
+self.env.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::launch::LaunchConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::launch::LaunchConfig> for crate::api::launch::LaunchConfig {
+            fn into_into_dart(self) -> crate::api::launch::LaunchConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::launch::LaunchEnvVar {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.key.into_into_dart().into_dart(),
+self.value.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::launch::LaunchEnvVar {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::launch::LaunchEnvVar> for crate::api::launch::LaunchEnvVar {
+            fn into_into_dart(self) -> crate::api::launch::LaunchEnvVar {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::local_folder::LocalFolderConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.path.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::local_folder::LocalFolderConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::local_folder::LocalFolderConfig> for crate::api::local_folder::LocalFolderConfig {
+            fn into_into_dart(self) -> crate::api::local_folder::LocalFolderConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::PathSettings {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.game_data_dir.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::PathSettings {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::PathSettings> for crate::api::settings::PathSettings {
+            fn into_into_dart(self) -> crate::api::settings::PathSettings {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::report::PlannedEntry {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.remote.into_into_dart().into_dart(),
+self.name.into_into_dart().into_dart(),
+self.action.into_into_dart().into_dart(),
+self.reason.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::report::PlannedEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::report::PlannedEntry> for crate::api::report::PlannedEntry {
+            fn into_into_dart(self) -> crate::api::report::PlannedEntry {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::RemoteBackend {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {crate::api::settings::RemoteBackend::WebDav(field0) => { [0.into_dart(),
+field0.into_into_dart().into_dart()].into_dart() }
+crate::api::settings::RemoteBackend::Folder(field0) => { [1.into_dart(),
+field0.into_into_dart().into_dart()].into_dart() }
+crate::api::settings::RemoteBackend::S3(field0) => { [2.into_dart(),
+field0.into_into_dart().into_dart()].into_dart() }
+crate::api::settings::RemoteBackend::Git(field0) => { [3.into_dart(),
+field0.into_into_dart().into_dart()].into_dart() }
+crate::api::settings::RemoteBackend::Sftp(field0) => { [4.into_dart(),
+field0.into_into_dart().into_dart()].into_dart() }
+ _ => { unimplemented!(""); }}
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::RemoteBackend {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::RemoteBackend> for crate::api::settings::RemoteBackend {
+            fn into_into_dart(self) -> crate::api::settings::RemoteBackend {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::history::RemoteVersion {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.hash.into_into_dart().into_dart(),
+self.size.into_into_dart().into_dart(),
+self.updated_at.into_into_dart().into_dart(),
+self.uploaded_at.into_into_dart().into_dart(),
+self.current.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::history::RemoteVersion {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::history::RemoteVersion> for crate::api::history::RemoteVersion {
+            fn into_into_dart(self) -> crate::api::history::RemoteVersion {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::s3::S3Config {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.endpoint.into_into_dart().into_dart(),
+self.region.into_into_dart().into_dart(),
+self.bucket.into_into_dart().into_dart(),
+self.access_key_id.into_into_dart().into_dart(),
+self.secret_access_key.into_into_dart().into_dart(),
+self.prefix.into_into_dart().into_dart(),
+self.path_style.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::s3::S3Config {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::s3::S3Config> for crate::api::s3::S3Config {
+            fn into_into_dart(self) -> crate::api::s3::S3Config {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::sftp::SftpConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.host.into_into_dart().into_dart(),
+self.port.into_into_dart().into_dart(),
+self.username.into_into_dart().into_dart(),
+self.password.into_into_dart().into_dart(),
+self.private_key_path.into_into_dart().into_dart(),
+self.passphrase.into_into_dart().into_dart(),
+self.path.into_into_dart().into_dart(),
+self.known_hosts_path.into_into_dart().into_dart(),
+self.accept_new_host.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::sftp::SftpConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::sftp::SftpConfig> for crate::api::sftp::SftpConfig {
+            fn into_into_dart(self) -> crate::api::sftp::SftpConfig {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::report::SyncAction {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Uploaded => 0.into_dart(),
+Self::Downloaded => 1.into_dart(),
+Self::Skipped => 2.into_dart(),
+Self::Conflicted => 3.into_dart(),
+Self::Deleted => 4.into_dart(),
+Self::Failed => 5.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::report::SyncAction {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::report::SyncAction> for crate::api::report::SyncAction {
+            fn into_into_dart(self) -> crate::api::report::SyncAction {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::SyncDirection {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::PushOnly => 0.into_dart(),
+Self::PullOnly => 1.into_dart(),
+Self::TwoWay => 2.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::SyncDirection {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::SyncDirection> for crate::api::settings::SyncDirection {
+            fn into_into_dart(self) -> crate::api::settings::SyncDirection {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::report::SyncEntry {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.remote.into_into_dart().into_dart(),
+self.name.into_into_dart().into_dart(),
+self.action.into_into_dart().into_dart(),
+self.reason.into_into_dart().into_dart(),
+self.duration_ms.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::report::SyncEntry {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::report::SyncEntry> for crate::api::report::SyncEntry {
+            fn into_into_dart(self) -> crate::api::report::SyncEntry {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::report::SyncOperation {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::Push => 0.into_dart(),
+Self::Pull => 1.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::report::SyncOperation {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::report::SyncOperation> for crate::api::report::SyncOperation {
+            fn into_into_dart(self) -> crate::api::report::SyncOperation {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::report::SyncPlan {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.operation.into_into_dart().into_dart(),
+self.entries.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::report::SyncPlan {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::report::SyncPlan> for crate::api::report::SyncPlan {
+            fn into_into_dart(self) -> crate::api::report::SyncPlan {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::SyncRemote {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.name.into_into_dart().into_dart(),
+self.direction.into_into_dart().into_dart(),
+self.backend.into_into_dart().into_dart(),
+self.include.into_into_dart().into_dart(),
+self.exclude.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::SyncRemote {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::SyncRemote> for crate::api::settings::SyncRemote {
+            fn into_into_dart(self) -> crate::api::settings::SyncRemote {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::report::SyncReport {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.operation.into_into_dart().into_dart(),
+self.started_at.into_into_dart().into_dart(),
+self.finished_at.into_into_dart().into_dart(),
+self.entries.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::report::SyncReport {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::report::SyncReport> for crate::api::report::SyncReport {
+            fn into_into_dart(self) -> crate::api::report::SyncReport {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::endfield::SyncStatus {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::NeverSynced => 0.into_dart(),
+Self::Synced => 1.into_dart(),
+Self::Modified => 2.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::endfield::SyncStatus {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::endfield::SyncStatus> for crate::api::endfield::SyncStatus {
+            fn into_into_dart(self) -> crate::api::endfield::SyncStatus {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::ThemeMode {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    match self {
+                    Self::System => 0.into_dart(),
+Self::Light => 1.into_dart(),
+Self::Dark => 2.into_dart(),
+                    _ => unreachable!(),
+                }
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::ThemeMode {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::ThemeMode> for crate::api::settings::ThemeMode {
+            fn into_into_dart(self) -> crate::api::settings::ThemeMode {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::settings::ThemeSettings {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.mode.into_into_dart().into_dart(),
+self.accent_color.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::settings::ThemeSettings {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::settings::ThemeSettings> for crate::api::settings::ThemeSettings {
+            fn into_into_dart(self) -> crate::api::settings::ThemeSettings {
+                self
+            }
+        }
+// Codec=Dco (DartCObject based), see doc to use other codecs
+            impl flutter_rust_bridge::IntoDart for crate::api::webdav::WebDavConfig {
+                fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+                    [
+                    self.url.into_into_dart().into_dart(),
+self.username.into_into_dart().into_dart(),
+self.password.into_into_dart().into_dart(),
+self.path.into_into_dart().into_dart()
+                ].into_dart()
+                }
+            }
+            impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive for crate::api::webdav::WebDavConfig {}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::webdav::WebDavConfig> for crate::api::webdav::WebDavConfig {
+            fn into_into_dart(self) -> crate::api::webdav::WebDavConfig {
+                self
+            }
+        }
+
+                impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(format!("{:?}", self), serializer);}
+                }
+                
+                impl SseEncode for String {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Vec<u8>>::sse_encode(self.into_bytes(), serializer);}
+                }
+                
+                impl SseEncode for crate::api::meta::AccountGroup {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Option<String>>::sse_encode(self.name, serializer);
+<Vec<crate::api::endfield::AccountInfo>>::sse_encode(self.accounts, serializer);}
+                }
+                
+                impl SseEncode for crate::api::health::AccountHealth {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.alias, serializer);
+<crate::api::health::HealthStatus>::sse_encode(self.status, serializer);
+<u64>::sse_encode(self.size, serializer);
+<i64>::sse_encode(self.age_secs, serializer);
+<Option<i64>>::sse_encode(self.expires_at, serializer);
+<Vec<String>>::sse_encode(self.issues, serializer);}
+                }
+                
+                impl SseEncode for crate::api::endfield::AccountInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.alias, serializer);
+<i64>::sse_encode(self.updated_at, serializer);
+<Option<String>>::sse_encode(self.group, serializer);
+<Vec<String>>::sse_encode(self.tags, serializer);
+<Option<String>>::sse_encode(self.notes, serializer);
+<Option<i64>>::sse_encode(self.last_used_at, serializer);
+<crate::api::endfield::SyncStatus>::sse_encode(self.sync_status, serializer);
+<crate::api::endfield::AccountSyncPolicy>::sse_encode(self.sync_policy, serializer);
+<crate::api::health::HealthStatus>::sse_encode(self.health, serializer);}
+                }
+                
+                impl SseEncode for crate::api::search::AccountQuery {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Option<String>>::sse_encode(self.text, serializer);
+<bool>::sse_encode(self.fuzzy, serializer);
+<Vec<String>>::sse_encode(self.tags, serializer);
+<Option<String>>::sse_encode(self.group, serializer);
+<Option<i64>>::sse_encode(self.used_after, serializer);
+<Option<i64>>::sse_encode(self.used_before, serializer);
+<Option<bool>>::sse_encode(self.stale, serializer);
+<Option<crate::api::endfield::SyncStatus>>::sse_encode(self.sync_status, serializer);
+<crate::api::search::AccountSortKey>::sse_encode(self.sort_by, serializer);
+<bool>::sse_encode(self.descending, serializer);}
+                }
+                
+                impl SseEncode for crate::api::search::AccountSortKey {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::search::AccountSortKey::Manual => { 0 }
+crate::api::search::AccountSortKey::Alias => { 1 }
+crate::api::search::AccountSortKey::UpdatedAt => { 2 }
+crate::api::search::AccountSortKey::LastUsedAt => { 3 }
+crate::api::search::AccountSortKey::Relevance => { 4 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::endfield::AccountSyncPolicy {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::endfield::AccountSyncPolicy::Never => { 0 }
+crate::api::endfield::AccountSyncPolicy::PushOnly => { 1 }
+crate::api::endfield::AccountSyncPolicy::TwoWay => { 2 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::AppSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u32>::sse_encode(self.schema_version, serializer);
+<crate::api::settings::PathSettings>::sse_encode(self.paths, serializer);
+<Vec<crate::api::settings::SyncRemote>>::sse_encode(self.remotes, serializer);
+<u32>::sse_encode(self.backup_depth, serializer);
+<u32>::sse_encode(self.remote_history_depth, serializer);
+<u32>::sse_encode(self.transfer_concurrency, serializer);
+<u32>::sse_encode(self.lock_timeout_secs, serializer);
+<Option<crate::api::launch::LaunchConfig>>::sse_encode(self.launch, serializer);
+<crate::api::settings::AutoSyncSettings>::sse_encode(self.auto_sync, serializer);
+<crate::api::settings::ControlServerSettings>::sse_encode(self.control_server, serializer);
+<Vec<crate::api::hooks::HookConfig>>::sse_encode(self.hooks, serializer);
+<crate::api::settings::ThemeSettings>::sse_encode(self.theme, serializer);}
+                }
+                
+                impl SseEncode for crate::api::audit::AuditEntry {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i64>::sse_encode(self.timestamp, serializer);
+<crate::api::audit::AuditOperation>::sse_encode(self.operation, serializer);
+<Option<String>>::sse_encode(self.alias, serializer);
+<Option<String>>::sse_encode(self.source, serializer);
+<Option<String>>::sse_encode(self.target, serializer);
+<bool>::sse_encode(self.success, serializer);
+<Option<String>>::sse_encode(self.error, serializer);
+<String>::sse_encode(self.device, serializer);}
+                }
+                
+                impl SseEncode for crate::api::audit::AuditOperation {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::audit::AuditOperation::Save => { 0 }
+crate::api::audit::AuditOperation::Switch => { 1 }
+crate::api::audit::AuditOperation::Delete => { 2 }
+crate::api::audit::AuditOperation::Push => { 3 }
+crate::api::audit::AuditOperation::Pull => { 4 }
+crate::api::audit::AuditOperation::Import => { 5 }
+crate::api::audit::AuditOperation::Restore => { 6 }
+crate::api::audit::AuditOperation::Rollback => { 7 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::audit::AuditQuery {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Option<String>>::sse_encode(self.alias, serializer);
+<Option<crate::api::audit::AuditOperation>>::sse_encode(self.operation, serializer);
+<Option<i64>>::sse_encode(self.since, serializer);
+<Option<i64>>::sse_encode(self.until, serializer);
+<bool>::sse_encode(self.failures_only, serializer);
+<Option<u32>>::sse_encode(self.limit, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::AutoSyncSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.enabled, serializer);
+<u32>::sse_encode(self.interval_minutes, serializer);
+<bool>::sse_encode(self.on_change, serializer);
+<bool>::sse_encode(self.on_startup, serializer);}
+                }
+                
+                impl SseEncode for crate::api::autosync::AutoSyncStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.active, serializer);
+<bool>::sse_encode(self.syncing, serializer);
+<Option<i64>>::sse_encode(self.last_success_at, serializer);
+<Option<String>>::sse_encode(self.last_error, serializer);
+<Option<i64>>::sse_encode(self.last_error_at, serializer);
+<Option<i64>>::sse_encode(self.next_run_at, serializer);
+<u32>::sse_encode(self.consecutive_failures, serializer);}
+                }
+                
+                impl SseEncode for bool {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u8(self as _).unwrap();}
+                }
+                
+                impl SseEncode for crate::api::bundle::BundleInfo {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u8>::sse_encode(self.version, serializer);
+<bool>::sse_encode(self.encrypted, serializer);
+<Option<i64>>::sse_encode(self.created_at, serializer);
+<Vec<String>>::sse_encode(self.aliases, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::ControlServerSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.enabled, serializer);
+<u16>::sse_encode(self.port, serializer);}
+                }
+                
+                impl SseEncode for crate::api::control::ControlServerStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.running, serializer);
+<Option<u16>>::sse_encode(self.port, serializer);}
+                }
+                
+                impl SseEncode for crate::api::launch::GameProcessStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<u32>::sse_encode(self.pid, serializer);
+<bool>::sse_encode(self.running, serializer);
+<Option<i32>>::sse_encode(self.exit_code, serializer);}
+                }
+                
+                impl SseEncode for crate::api::git::GitConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.path, serializer);
+<Option<String>>::sse_encode(self.remote_url, serializer);
+<String>::sse_encode(self.branch, serializer);
+<String>::sse_encode(self.passphrase, serializer);}
+                }
+                
+                impl SseEncode for crate::api::health::HealthStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::health::HealthStatus::Fresh => { 0 }
+crate::api::health::HealthStatus::Aging => { 1 }
+crate::api::health::HealthStatus::LikelyExpired => { 2 }
+crate::api::health::HealthStatus::Invalid => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::hooks::HookConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::api::hooks::HookEvent>::sse_encode(self.event, serializer);
+<String>::sse_encode(self.command, serializer);
+<Vec<String>>::sse_encode(self.args, serializer);
+<u32>::sse_encode(self.timeout_secs, serializer);
+<bool>::sse_encode(self.enabled, serializer);}
+                }
+                
+                impl SseEncode for crate::api::hooks::HookEvent {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::hooks::HookEvent::PreSwitch => { 0 }
+crate::api::hooks::HookEvent::PostSwitch => { 1 }
+crate::api::hooks::HookEvent::PreSave => { 2 }
+crate::api::hooks::HookEvent::PostSave => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::hooks::HookRun {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::api::hooks::HookEvent>::sse_encode(self.event, serializer);
+<String>::sse_encode(self.command, serializer);
+<Option<i32>>::sse_encode(self.exit_code, serializer);
+<bool>::sse_encode(self.timed_out, serializer);
+<String>::sse_encode(self.stdout, serializer);
+<String>::sse_encode(self.stderr, serializer);
+<Option<String>>::sse_encode(self.error, serializer);
+<u64>::sse_encode(self.duration_ms, serializer);}
+                }
+                
+                impl SseEncode for i32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_i32::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for i64 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_i64::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for crate::api::bundle::ImportAction {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::bundle::ImportAction::Created => { 0 }
+crate::api::bundle::ImportAction::Overwritten => { 1 }
+crate::api::bundle::ImportAction::Renamed => { 2 }
+crate::api::bundle::ImportAction::Skipped => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::bundle::ImportConflictPolicy {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::bundle::ImportConflictPolicy::Skip => { 0 }
+crate::api::bundle::ImportConflictPolicy::Overwrite => { 1 }
+crate::api::bundle::ImportConflictPolicy::KeepNewer => { 2 }
+crate::api::bundle::ImportConflictPolicy::Rename => { 3 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::bundle::ImportedAccount {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.original_alias, serializer);
+<String>::sse_encode(self.alias, serializer);
+<crate::api::bundle::ImportAction>::sse_encode(self.action, serializer);}
+                }
+                
+                impl SseEncode for crate::api::journal::InterruptedSync {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i64>::sse_encode(self.started_at, serializer);
+<Vec<String>>::sse_encode(self.remotes, serializer);
+<Vec<String>>::sse_encode(self.completed, serializer);
+<Vec<String>>::sse_encode(self.pending, serializer);}
+                }
+                
+                impl SseEncode for crate::api::launch::LaunchConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.executable, serializer);
+<Vec<String>>::sse_encode(self.args, serializer);
+<Option<String>>::sse_encode(self.working_dir, serializer);
+<Option<String>>::sse_encode(self.wrapper, serializer);
+<Vec<String>>::sse_encode(self.wrapper_args, serializer);
+<Vec<crate::api::launch::LaunchEnvVar>>::sse_encode(self.env, serializer);}
+                }
+                
+                impl SseEncode for crate::api::launch::LaunchEnvVar {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.key, serializer);
+<String>::sse_encode(self.value, serializer);}
+                }
+                
+                impl SseEncode for Vec<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <String>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::meta::AccountGroup> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::meta::AccountGroup>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::health::AccountHealth> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::health::AccountHealth>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::endfield::AccountInfo> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::endfield::AccountInfo>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::audit::AuditEntry> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::audit::AuditEntry>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::launch::GameProcessStatus> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::launch::GameProcessStatus>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::hooks::HookConfig> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::hooks::HookConfig>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::hooks::HookRun> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::hooks::HookRun>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::bundle::ImportedAccount> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::bundle::ImportedAccount>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::launch::LaunchEnvVar> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::launch::LaunchEnvVar>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::report::PlannedEntry> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::report::PlannedEntry>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<u8> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <u8>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::history::RemoteVersion> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::history::RemoteVersion>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::report::SyncEntry> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::report::SyncEntry>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::settings::SyncRemote> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::settings::SyncRemote>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for Vec<crate::api::report::SyncReport> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(self.len() as _, serializer);
+        for item in self { <crate::api::report::SyncReport>::sse_encode(item, serializer); }}
+                }
+                
+                impl SseEncode for crate::api::local_folder::LocalFolderConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.path, serializer);}
+                }
+                
+                impl SseEncode for Option<String> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <String>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::api::audit::AuditOperation> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::api::audit::AuditOperation>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<bool> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <bool>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<i32> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <i32>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<i64> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <i64>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::api::journal::InterruptedSync> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::api::journal::InterruptedSync>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::api::launch::LaunchConfig> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::api::launch::LaunchConfig>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<crate::api::endfield::SyncStatus> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <crate::api::endfield::SyncStatus>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<u16> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <u16>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for Option<u32> {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<bool>::sse_encode(self.is_some(), serializer);
+                if let Some(value) = self {
+                    <u32>::sse_encode(value, serializer);
+                }}
+                }
+                
+                impl SseEncode for crate::api::settings::PathSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<Option<String>>::sse_encode(self.game_data_dir, serializer);}
+                }
+                
+                impl SseEncode for crate::api::report::PlannedEntry {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.remote, serializer);
+<Option<String>>::sse_encode(self.name, serializer);
+<crate::api::report::SyncAction>::sse_encode(self.action, serializer);
+<Option<String>>::sse_encode(self.reason, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::RemoteBackend {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {match self {crate::api::settings::RemoteBackend::WebDav(field0) => { <i32>::sse_encode(0, serializer); <crate::api::webdav::WebDavConfig>::sse_encode(field0, serializer);
+ }
+crate::api::settings::RemoteBackend::Folder(field0) => { <i32>::sse_encode(1, serializer); <crate::api::local_folder::LocalFolderConfig>::sse_encode(field0, serializer);
+ }
+crate::api::settings::RemoteBackend::S3(field0) => { <i32>::sse_encode(2, serializer); <crate::api::s3::S3Config>::sse_encode(field0, serializer);
+ }
+crate::api::settings::RemoteBackend::Git(field0) => { <i32>::sse_encode(3, serializer); <crate::api::git::GitConfig>::sse_encode(field0, serializer);
+ }
+crate::api::settings::RemoteBackend::Sftp(field0) => { <i32>::sse_encode(4, serializer); <crate::api::sftp::SftpConfig>::sse_encode(field0, serializer);
+ }
+ _ => { unimplemented!(""); }}}
+                }
+                
+                impl SseEncode for crate::api::history::RemoteVersion {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.hash, serializer);
+<u64>::sse_encode(self.size, serializer);
+<i64>::sse_encode(self.updated_at, serializer);
+<i64>::sse_encode(self.uploaded_at, serializer);
+<bool>::sse_encode(self.current, serializer);}
+                }
+                
+                impl SseEncode for crate::api::s3::S3Config {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.endpoint, serializer);
+<String>::sse_encode(self.region, serializer);
+<String>::sse_encode(self.bucket, serializer);
+<String>::sse_encode(self.access_key_id, serializer);
+<String>::sse_encode(self.secret_access_key, serializer);
+<Option<String>>::sse_encode(self.prefix, serializer);
+<bool>::sse_encode(self.path_style, serializer);}
+                }
+                
+                impl SseEncode for crate::api::sftp::SftpConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.host, serializer);
+<u16>::sse_encode(self.port, serializer);
+<String>::sse_encode(self.username, serializer);
+<Option<String>>::sse_encode(self.password, serializer);
+<Option<String>>::sse_encode(self.private_key_path, serializer);
+<Option<String>>::sse_encode(self.passphrase, serializer);
+<Option<String>>::sse_encode(self.path, serializer);
+<Option<String>>::sse_encode(self.known_hosts_path, serializer);
+<bool>::sse_encode(self.accept_new_host, serializer);}
+                }
+                
+                impl SseEncode for crate::api::report::SyncAction {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::report::SyncAction::Uploaded => { 0 }
+crate::api::report::SyncAction::Downloaded => { 1 }
+crate::api::report::SyncAction::Skipped => { 2 }
+crate::api::report::SyncAction::Conflicted => { 3 }
+crate::api::report::SyncAction::Deleted => { 4 }
+crate::api::report::SyncAction::Failed => { 5 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::SyncDirection {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::settings::SyncDirection::PushOnly => { 0 }
+crate::api::settings::SyncDirection::PullOnly => { 1 }
+crate::api::settings::SyncDirection::TwoWay => { 2 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::report::SyncEntry {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.remote, serializer);
+<Option<String>>::sse_encode(self.name, serializer);
+<crate::api::report::SyncAction>::sse_encode(self.action, serializer);
+<Option<String>>::sse_encode(self.reason, serializer);
+<u64>::sse_encode(self.duration_ms, serializer);}
+                }
+                
+                impl SseEncode for crate::api::report::SyncOperation {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::report::SyncOperation::Push => { 0 }
+crate::api::report::SyncOperation::Pull => { 1 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::report::SyncPlan {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::api::report::SyncOperation>::sse_encode(self.operation, serializer);
+<Vec<crate::api::report::PlannedEntry>>::sse_encode(self.entries, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::SyncRemote {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.name, serializer);
+<crate::api::settings::SyncDirection>::sse_encode(self.direction, serializer);
+<crate::api::settings::RemoteBackend>::sse_encode(self.backend, serializer);
+<Vec<String>>::sse_encode(self.include, serializer);
+<Vec<String>>::sse_encode(self.exclude, serializer);}
+                }
+                
+                impl SseEncode for crate::api::report::SyncReport {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::api::report::SyncOperation>::sse_encode(self.operation, serializer);
+<i64>::sse_encode(self.started_at, serializer);
+<i64>::sse_encode(self.finished_at, serializer);
+<Vec<crate::api::report::SyncEntry>>::sse_encode(self.entries, serializer);}
+                }
+                
+                impl SseEncode for crate::api::endfield::SyncStatus {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::endfield::SyncStatus::NeverSynced => { 0 }
+crate::api::endfield::SyncStatus::Synced => { 1 }
+crate::api::endfield::SyncStatus::Modified => { 2 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::ThemeMode {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<i32>::sse_encode(match self {crate::api::settings::ThemeMode::System => { 0 }
+crate::api::settings::ThemeMode::Light => { 1 }
+crate::api::settings::ThemeMode::Dark => { 2 }
+ _ => { unimplemented!(""); }}, serializer);}
+                }
+                
+                impl SseEncode for crate::api::settings::ThemeSettings {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<crate::api::settings::ThemeMode>::sse_encode(self.mode, serializer);
+<Option<String>>::sse_encode(self.accent_color, serializer);}
+                }
+                
+                impl SseEncode for u16 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u16::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for u32 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u32::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for u64 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u64::<NativeEndian>(self).unwrap();}
+                }
+                
+                impl SseEncode for u8 {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {serializer.cursor.write_u8(self).unwrap();}
+                }
+                
+                impl SseEncode for () {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
+                }
+                
+                impl SseEncode for crate::api::webdav::WebDavConfig {
+                    // Codec=Sse (Serialization based), see doc to use other codecs
+                    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {<String>::sse_encode(self.url, serializer);
+<String>::sse_encode(self.username, serializer);
+<Option<String>>::sse_encode(self.password, serializer);
+<Option<String>>::sse_encode(self.path, serializer);}
+                }
+                
+
+
+        
+        
+        #[cfg(not(target_family = "wasm"))]
+        mod io {
+            // This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+
+
+// Section: imports
+
+use flutter_rust_bridge::{Handler, IntoIntoDart};
+use flutter_rust_bridge::for_generated::{Lockable, transform_result_dco, Lifetimeable};
+use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, WriteBytesExt, ReadBytesExt};use super::*;
+
+// Section: boilerplate
+
+flutter_rust_bridge::frb_generated_boilerplate_io!();
+
+
+        }
+        #[cfg(not(target_family = "wasm"))]
+        pub use io::*;
+        
+        
+        /// cbindgen:ignore
+        #[cfg(target_family = "wasm")]
+        mod web {
+            // This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+
+
+// Section: imports
+
+use flutter_rust_bridge::{Handler, IntoIntoDart};
+use flutter_rust_bridge::for_generated::{Lockable, transform_result_dco, Lifetimeable};
+use flutter_rust_bridge::for_generated::byteorder::{NativeEndian, WriteBytesExt, ReadBytesExt};use super::*;
+                use flutter_rust_bridge::for_generated::wasm_bindgen;
+                use flutter_rust_bridge::for_generated::wasm_bindgen::prelude::*;
+
+
+// Section: boilerplate
+
+flutter_rust_bridge::frb_generated_boilerplate_web!();
+
+
+        }
+        #[cfg(target_family = "wasm")]
+        pub use web::*;
+        
\ No newline at end of file
@@ -0,0 +1,182 @@
+mod common;
+
+use std::fs::File;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use common::{add_remote, folder_backend, TestEnv};
+use rust_lib_endswitcher::api::audit::{query_audit_log, AuditEntry, AuditOperation, AuditQuery};
+use rust_lib_endswitcher::api::endfield::{
+    delete_account, save_current_account, switch_to_account,
+};
+use rust_lib_endswitcher::api::settings::{get_settings, update_settings};
+use rust_lib_endswitcher::api::sync::{sync_pull, sync_push};
+
+// 游戏数据目录中的登录凭证
+fn game_cache(env: &TestEnv, data: &[u8]) -> PathBuf {
+    let game = env.folder("game");
+    std::fs::create_dir_all(game.join("sdk_data_1")).unwrap();
+    let cache = game.join("sdk_data_1").join("login_cache");
+    std::fs::write(&cache, data).unwrap();
+    let mut settings = get_settings().unwrap();
+    settings.paths.game_data_dir = Some(game.to_string_lossy().into_owned());
+    update_settings(settings).unwrap();
+    cache
+}
+
+fn query(f: impl FnOnce(&mut AuditQuery)) -> Vec<AuditEntry> {
+    let mut query = AuditQuery::default();
+    f(&mut query);
+    query_audit_log(query).unwrap()
+}
+
+fn operations(entries: &[AuditEntry]) -> Vec<(AuditOperation, Option<&str>, bool)> {
+    entries
+        .iter()
+        .map(|e| (e.operation, e.alias.as_deref(), e.success))
+        .collect()
+}
+
+#[tokio::test]
+async fn account_operations_are_audited() {
+    let env = TestEnv::new().await;
+    env.write_account("bob", b"bob-token");
+    let cache = game_cache(&env, b"alice-token");
+
+    save_current_account("alice".to_string()).unwrap();
+    switch_to_account("bob".to_string()).unwrap();
+    switch_to_account("carol".to_string()).unwrap_err();
+    delete_account("alice".to_string()).unwrap();
+
+    // 新记录在前
+    let entries = query(|_| {});
+    assert_eq!(
+        operations(&entries),
+        [
+            (AuditOperation::Delete, Some("alice"), true),
+            (AuditOperation::Switch, Some("carol"), false),
+            (AuditOperation::Switch, Some("bob"), true),
+            (AuditOperation::Save, Some("alice"), true),
+        ]
+    );
+    let switch = &entries[2];
+    assert_eq!(
+        switch.source.as_deref(),
+        Some(env.app_dir().join("accounts").join("bob").to_str().unwrap())
+    );
+    assert_eq!(switch.target.as_deref(), Some(cache.to_str().unwrap()));
+    assert!(switch.timestamp > 0);
+    assert!(!switch.device.is_empty());
+    assert_eq!(entries[1].error.as_deref(), Some("Saved account not found"));
+
+    let failures = query(|q| q.failures_only = true);
+    assert_eq!(failures.len(), 1);
+    let alice = query(|q| q.alias = Some("alice".to_string()));
+    assert_eq!(
+        operations(&alice),
+        [
+            (AuditOperation::Delete, Some("alice"), true),
+            (AuditOperation::Save, Some("alice"), true),
+        ]
+    );
+    let latest_switch = query(|q| {
+        q.operation = Some(AuditOperation::Switch);
+        q.limit = Some(1);
+    });
+    assert_eq!(latest_switch[0].alias.as_deref(), Some("carol"));
+    assert!(query(|q| q.since = Some(switch.timestamp + 3600)).is_empty());
+}
+
+#[tokio::test]
+async fn sync_transfers_are_audited() {
+    let env = TestEnv::new().await;
+    let share = env.folder("share");
+    add_remote("usb", folder_backend(&share));
+    env.write_account("alice", b"alice-token");
+    sync_push(None).await.unwrap();
+    // 内容未变化的账号不记录
+    sync_push(None).await.unwrap();
+
+    let pushes = query(|q| q.operation = Some(AuditOperation::Push));
+    assert_eq!(pushes.len(), 1);
+    assert_eq!(pushes[0].alias.as_deref(), Some("alice"));
+    assert_eq!(pushes[0].target.as_deref(), Some("usb"));
+
+    // 另一台设备拉取，日志按设备分别保存
+    env.use_device("laptop");
+    add_remote("usb", folder_backend(&share));
+    sync_pull(None).await.unwrap();
+    let entries = query(|_| {});
+    assert_eq!(
+        operations(&entries),
+        [(AuditOperation::Pull, Some("alice"), true)]
+    );
+    assert_eq!(entries[0].source.as_deref(), Some("usb"));
+}
+
+#[tokio::test]
+async fn audit_log_rotates_when_full() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    let dir = env.app_dir();
+
+    // 写满当前日志，末尾留下一行中途退出时的不完整记录
+    let old = json!({
+        "timestamp": 1,
+        "operation": "Save",
+        "alias": "old",
+        "source": null,
+        "target": null,
+        "success": true,
+        "error": null,
+        "device": "desktop",
+    })
+    .to_string();
+    let mut data = String::new();
+    while data.len() < 1024 * 1024 - 200 {
+        data.push_str(&old);
+        data.push('\n');
+    }
+    data.push_str("{\"timestamp\":");
+    std::fs::write(dir.join("audit.jsonl"), &data).unwrap();
+
+    delete_account("alice".to_string()).unwrap();
+    assert!(dir.join("audit.1.jsonl").exists());
+    let current = std::fs::read_to_string(dir.join("audit.jsonl")).unwrap();
+    assert_eq!(current.lines().count(), 1);
+
+    let entries = query(|q| q.limit = Some(2));
+    assert_eq!(
+        operations(&entries),
+        [
+            (AuditOperation::Delete, Some("alice"), true),
+            (AuditOperation::Save, Some("old"), true),
+        ]
+    );
+    assert_eq!(entries[1].device, "desktop");
+}
+
+#[tokio::test]
+async fn writers_wait_for_the_audit_lock() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+
+    // 模拟另一个进程正在轮转日志
+    let lock = File::create(env.app_dir().join("audit.lock")).unwrap();
+    lock.lock().unwrap();
+    let holder = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        drop(lock);
+    });
+    let start = Instant::now();
+    delete_account("alice".to_string()).unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    holder.join().unwrap();
+
+    assert_eq!(
+        operations(&query(|_| {})),
+        [(AuditOperation::Delete, Some("alice"), true)]
+    );
+}
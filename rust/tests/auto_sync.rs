@@ -0,0 +1,56 @@
+mod common;
+
+use std::time::{Duration, Instant};
+
+use common::{add_remote, DavServer, Quirks, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::autosync::{
+    get_auto_sync_status, start_auto_sync, stop_auto_sync, AutoSyncStatus,
+};
+use rust_lib_endswitcher::api::journal::get_interrupted_sync;
+use rust_lib_endswitcher::api::settings::{get_settings, update_settings, RemoteBackend};
+use rust_lib_endswitcher::api::sync::sync_push;
+
+async fn wait_for(check: impl Fn(&AutoSyncStatus) -> bool) -> AutoSyncStatus {
+    let deadline = Instant::now() + Duration::from_secs(30);
+    loop {
+        let status = get_auto_sync_status().unwrap();
+        if check(&status) {
+            return status;
+        }
+        assert!(Instant::now() < deadline, "{:?}", status);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+}
+
+#[tokio::test]
+async fn stopping_lets_the_running_sync_finish() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 另一台设备启动时同步，每个请求都较慢
+    env.use_device("laptop");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    server.set_quirks(|q| q.delay = Duration::from_millis(100));
+    let mut settings = get_settings().unwrap();
+    settings.auto_sync.enabled = true;
+    settings.auto_sync.on_startup = true;
+    update_settings(settings).unwrap();
+    assert!(start_auto_sync().unwrap());
+
+    wait_for(|s| s.syncing).await;
+    stop_auto_sync().unwrap();
+    let status = get_auto_sync_status().unwrap();
+    assert!(!status.active);
+    assert!(status.syncing);
+
+    let status = wait_for(|s| !s.syncing).await;
+    assert!(status.last_success_at.is_some(), "{:?}", status);
+    assert_eq!(status.next_run_at, None);
+    assert!(get_interrupted_sync().unwrap().is_none());
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-token");
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-token");
+}
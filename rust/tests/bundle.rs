@@ -0,0 +1,126 @@
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::TestEnv;
+use rust_lib_endswitcher::api::autosync::{get_auto_sync_status, start_auto_sync, stop_auto_sync};
+use rust_lib_endswitcher::api::bundle::{
+    export_accounts, import_accounts, inspect_bundle, ImportAction, ImportConflictPolicy,
+};
+use rust_lib_endswitcher::api::endfield::get_account_list;
+use rust_lib_endswitcher::api::meta::set_account_tags;
+use rust_lib_endswitcher::api::settings::{get_settings, update_settings};
+
+const PASSPHRASE: &str = "correct horse";
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn export(env: &TestEnv) -> PathBuf {
+    let path = env.folder("export").join("accounts.esb");
+    let count = export_accounts(
+        Vec::new(),
+        path.to_string_lossy().into_owned(),
+        Some(PASSPHRASE.to_string()),
+    )
+    .unwrap();
+    assert_eq!(count, 2);
+    path
+}
+
+fn import(path: &Path, passphrase: &str) -> anyhow::Result<Vec<(String, ImportAction)>> {
+    let imported = import_accounts(
+        path.to_string_lossy().into_owned(),
+        Some(passphrase.to_string()),
+        ImportConflictPolicy::Overwrite,
+    )?;
+    Ok(imported.into_iter().map(|a| (a.alias, a.action)).collect())
+}
+
+#[tokio::test]
+async fn encrypted_bundle_round_trip() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    set_account_tags("alice".to_string(), vec!["main".to_string()]).unwrap();
+    let path = export(&env);
+    assert!(!env.folder("export").join("accounts.esb.tmp").exists());
+    let info = inspect_bundle(path.to_string_lossy().into_owned(), None).unwrap();
+    assert!(info.encrypted);
+    assert!(info.aliases.is_empty());
+
+    // 导入到另一台设备，自动同步随之安排推送
+    env.use_device("laptop");
+    let mut settings = get_settings().unwrap();
+    settings.auto_sync.enabled = true;
+    settings.auto_sync.on_startup = false;
+    update_settings(settings).unwrap();
+    assert!(start_auto_sync().unwrap());
+
+    let mut imported = import(&path, PASSPHRASE).unwrap();
+    imported.sort_by(|a, b| a.0.cmp(&b.0));
+    assert_eq!(
+        imported,
+        [
+            ("alice".to_string(), ImportAction::Created),
+            ("bob".to_string(), ImportAction::Created),
+        ]
+    );
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-token");
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-token");
+    let alice = get_account_list()
+        .unwrap()
+        .into_iter()
+        .find(|a| a.alias == "alice")
+        .unwrap();
+    assert_eq!(alice.tags, ["main"]);
+    let next_run = get_auto_sync_status().unwrap().next_run_at.unwrap();
+    assert!(next_run <= unix_now() + 60, "{}", next_run);
+    stop_auto_sync().unwrap();
+}
+
+#[tokio::test]
+async fn wrong_passphrase_is_rejected() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    let path = export(&env);
+
+    env.use_device("laptop");
+    let err = import(&path, "wrong horse").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Failed to decrypt bundle: wrong passphrase or corrupted file"
+    );
+    assert!(env.read_account("alice").is_none());
+}
+
+#[tokio::test]
+async fn tampered_header_is_rejected() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    let path = export(&env);
+    let original = std::fs::read(&path).unwrap();
+
+    env.use_device("laptop");
+    // 头部依次为 8 字节魔数、版本与标志位
+    for (offset, value, expected) in [
+        (8, 2u8, "Unsupported bundle version"),
+        (9, 0b11, "Failed to decrypt"),
+    ] {
+        let mut raw = original.clone();
+        raw[offset] = value;
+        std::fs::write(&path, &raw).unwrap();
+        let err = import(&path, PASSPHRASE).unwrap_err();
+        assert!(err.to_string().contains(expected), "{}", err);
+    }
+    assert!(env.read_account("alice").is_none());
+
+    std::fs::write(&path, &original).unwrap();
+    assert_eq!(import(&path, PASSPHRASE).unwrap().len(), 2);
+}
@@ -0,0 +1,590 @@
+// 集成测试共用的本地 WebDAV 服务器与隔离的应用数据目录
+#![allow(dead_code)]
+
+pub mod s3;
+pub mod sftp;
+
+use base64::Engine;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex as AsyncMutex, MutexGuard};
+
+use rust_lib_endswitcher::api::git::GitConfig;
+use rust_lib_endswitcher::api::local_folder::LocalFolderConfig;
+use rust_lib_endswitcher::api::report::{SyncAction, SyncReport};
+use rust_lib_endswitcher::api::settings::{RemoteBackend, SyncDirection, SyncRemote};
+use rust_lib_endswitcher::api::sync::save_remote;
+use rust_lib_endswitcher::api::webdav::WebDavConfig;
+
+/// PROPFIND 响应中 DAV 命名空间的写法
+#[derive(Debug, Clone)]
+pub enum Namespace {
+    /// 带前缀，例如 `D:`（Apache）或 `d:`（Nextcloud）
+    Prefixed(&'static str),
+    /// 默认命名空间，元素没有前缀
+    Default,
+}
+
+/// 模拟各种服务器的差异与故障
+#[derive(Debug, Clone)]
+pub struct Quirks {
+    pub namespace: Namespace,
+    /// href 使用完整 URL 而不是路径
+    pub absolute_hrefs: bool,
+    /// 目录的 href 是否以 `/` 结尾
+    pub dir_trailing_slash: bool,
+    pub propfind_status: u16,
+    /// 需要的 Basic 认证用户名与密码
+    pub auth: Option<(String, String)>,
+    /// 每个请求的额外延迟
+    pub delay: Duration,
+    /// 对这些文件名的 PUT 返回 500
+    pub fail_put: HashSet<String>,
+    /// 对这些文件名的 GET 返回 200 的 HTML 错误页
+    pub html_get: HashSet<String>,
+    /// 这些文件名的 PUT 只保存前面的部分内容，模拟服务器截断上传
+    pub truncate_put: HashSet<String>,
+    /// 对这些文件名的 GET 一直不响应，模拟下载途中退出
+    pub stall_get: HashSet<String>,
+    /// 是否支持 LOCK/UNLOCK（class 2），不支持时返回 405
+    pub locking: bool,
+}
+
+impl Default for Quirks {
+    fn default() -> Self {
+        Self {
+            namespace: Namespace::Prefixed("D"),
+            absolute_hrefs: false,
+            dir_trailing_slash: true,
+            propfind_status: 207,
+            auth: None,
+            delay: Duration::ZERO,
+            fail_put: HashSet::new(),
+            html_get: HashSet::new(),
+            truncate_put: HashSet::new(),
+            stall_get: HashSet::new(),
+            locking: true,
+        }
+    }
+}
+
+#[derive(Default)]
+struct Store {
+    files: BTreeMap<String, Vec<u8>>,
+    dirs: BTreeSet<String>,
+    /// 路径 -> (锁令牌, 到期时间)
+    locks: BTreeMap<String, (String, Instant)>,
+    next_token: usize,
+}
+
+impl Store {
+    // 未到期的锁令牌
+    fn lock_token(&self, path: &str) -> Option<&str> {
+        self.locks
+            .get(path)
+            .filter(|(_, expires)| *expires > Instant::now())
+            .map(|(token, _)| token.as_str())
+    }
+
+    // 被锁定的资源只能由携带令牌的请求修改
+    fn writable(&self, path: &str, req_token: Option<&str>) -> bool {
+        match self.lock_token(path) {
+            Some(token) => req_token == Some(token),
+            None => true,
+        }
+    }
+}
+
+struct State {
+    quirks: Mutex<Quirks>,
+    store: Mutex<Store>,
+    requests: Mutex<Vec<(String, String)>>,
+    addr: SocketAddr,
+}
+
+pub struct DavServer {
+    pub url: String,
+    state: Arc<State>,
+}
+
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map(|(p, _)| p).unwrap_or("")
+}
+
+fn file_name(path: &str) -> &str {
+    path.rsplit('/').next().unwrap_or(path)
+}
+
+fn encode_path(path: &str) -> String {
+    path.split('/')
+        .map(|s| urlencoding::encode(s).into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn status(code: StatusCode) -> Response<Body> {
+    Response::builder()
+        .status(code)
+        .body(Body::empty())
+        .unwrap()
+}
+
+// 从 `If: (<token>)` 或 `Lock-Token: <token>` 中取出令牌
+fn header_token(req: &Request<Body>, name: &str) -> Option<String> {
+    let value = req.headers().get(name)?.to_str().ok()?;
+    let start = value.find('<')? + 1;
+    let end = value[start..].find('>')? + start;
+    Some(value[start..end].to_string())
+}
+
+fn authorized(quirks: &Quirks, req: &Request<Body>) -> bool {
+    let Some((user, pass)) = &quirks.auth else {
+        return true;
+    };
+    let expected = format!(
+        "Basic {}",
+        base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", user, pass))
+    );
+    req.headers()
+        .get(hyper::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        == Some(expected.as_str())
+}
+
+fn propfind_xml(state: &State, quirks: &Quirks, path: &str, store: &Store) -> String {
+    let (p, xmlns) = match quirks.namespace {
+        Namespace::Prefixed(prefix) => {
+            (format!("{}:", prefix), format!("xmlns:{}=\"DAV:\"", prefix))
+        }
+        Namespace::Default => (String::new(), "xmlns=\"DAV:\"".to_string()),
+    };
+    let href = |path: &str, dir: bool| {
+        let mut href = format!("/{}", encode_path(path));
+        if dir && quirks.dir_trailing_slash && !href.ends_with('/') {
+            href.push('/');
+        }
+        if quirks.absolute_hrefs {
+            href = format!("http://{}{}", state.addr, href);
+        }
+        href
+    };
+    let entry = |href: String, dir: bool| {
+        let kind = if dir {
+            format!("<{p}collection/>")
+        } else {
+            String::new()
+        };
+        format!(
+            "<{p}response><{p}href>{href}</{p}href><{p}propstat><{p}prop>\
+             <{p}resourcetype>{kind}</{p}resourcetype></{p}prop>\
+             <{p}status>HTTP/1.1 200 OK</{p}status></{p}propstat></{p}response>"
+        )
+    };
+
+    let mut body = format!("<?xml version=\"1.0\" encoding=\"utf-8\"?><{p}multistatus {xmlns}>");
+    body.push_str(&entry(href(path, true), true));
+    for dir in store
+        .dirs
+        .iter()
+        .filter(|d| !d.is_empty() && parent(d) == path)
+    {
+        body.push_str(&entry(href(dir, true), true));
+    }
+    for file in store.files.keys().filter(|f| parent(f) == path) {
+        body.push_str(&entry(href(file, false), false));
+    }
+    body.push_str(&format!("</{p}multistatus>"));
+    body
+}
+
+async fn handle(state: Arc<State>, req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let quirks = state.quirks.lock().unwrap().clone();
+    if !quirks.delay.is_zero() {
+        tokio::time::sleep(quirks.delay).await;
+    }
+    let raw_path = req.uri().path().trim_matches('/').to_string();
+    let path = urlencoding::decode(&raw_path)
+        .map(|p| p.into_owned())
+        .unwrap_or(raw_path);
+    state
+        .requests
+        .lock()
+        .unwrap()
+        .push((req.method().to_string(), path.clone()));
+
+    if !authorized(&quirks, &req) {
+        let res = Response::builder()
+            .status(StatusCode::UNAUTHORIZED)
+            .header("WWW-Authenticate", "Basic realm=\"test\"")
+            .body(Body::empty())
+            .unwrap();
+        return Ok(res);
+    }
+
+    let method = req.method().clone();
+    let if_token = header_token(&req, "If");
+    let create_only = req.headers().get("If-None-Match").is_some_and(|v| v == "*");
+    let res = match method.as_str() {
+        "MKCOL" => {
+            let mut store = state.store.lock().unwrap();
+            if store.dirs.contains(&path) || store.files.contains_key(&path) {
+                status(StatusCode::METHOD_NOT_ALLOWED)
+            } else if !store.dirs.contains(parent(&path)) {
+                status(StatusCode::CONFLICT)
+            } else {
+                store.dirs.insert(path);
+                status(StatusCode::CREATED)
+            }
+        }
+        "PUT" => {
+            let data = hyper::body::to_bytes(req.into_body()).await.unwrap();
+            let mut store = state.store.lock().unwrap();
+            if !store.dirs.contains(parent(&path)) {
+                status(StatusCode::CONFLICT)
+            } else if !store.writable(&path, if_token.as_deref()) {
+                status(StatusCode::LOCKED)
+            } else if create_only && store.files.contains_key(&path) {
+                status(StatusCode::PRECONDITION_FAILED)
+            } else if quirks.fail_put.contains(file_name(&path)) {
+                status(StatusCode::INTERNAL_SERVER_ERROR)
+            } else {
+                let mut data = data.to_vec();
+                if quirks.truncate_put.contains(file_name(&path)) {
+                    data.truncate(data.len().saturating_sub(1));
+                }
+                store.files.insert(path, data);
+                status(StatusCode::CREATED)
+            }
+        }
+        "GET" if quirks.stall_get.contains(file_name(&path)) => {
+            std::future::pending::<()>().await;
+            unreachable!()
+        }
+        "GET" | "HEAD" => {
+            let store = state.store.lock().unwrap();
+            match store.files.get(&path) {
+                None => status(StatusCode::NOT_FOUND),
+                Some(_) if quirks.html_get.contains(file_name(&path)) => Response::builder()
+                    .header("Content-Type", "text/html")
+                    .body(Body::from(
+                        "<!DOCTYPE html><html><body>Login required</body></html>",
+                    ))
+                    .unwrap(),
+                Some(data) => {
+                    let builder = Response::builder()
+                        .header("Content-Type", "application/octet-stream")
+                        .header("Content-Length", data.len());
+                    let body = if method == Method::HEAD {
+                        Body::empty()
+                    } else {
+                        Body::from(data.clone())
+                    };
+                    builder.body(body).unwrap()
+                }
+            }
+        }
+        "DELETE" => {
+            let mut store = state.store.lock().unwrap();
+            if !store.writable(&path, if_token.as_deref()) {
+                status(StatusCode::LOCKED)
+            } else {
+                store.locks.remove(&path);
+                match store.files.remove(&path) {
+                    Some(_) => status(StatusCode::NO_CONTENT),
+                    None => status(StatusCode::NOT_FOUND),
+                }
+            }
+        }
+        "LOCK" if quirks.locking => {
+            let timeout = req
+                .headers()
+                .get("Timeout")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.strip_prefix("Second-"))
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(600);
+            let mut store = state.store.lock().unwrap();
+            if !store.dirs.contains(parent(&path)) {
+                status(StatusCode::CONFLICT)
+            } else if store.lock_token(&path).is_some() {
+                status(StatusCode::LOCKED)
+            } else {
+                store.next_token += 1;
+                let token = format!("opaquelocktoken:test-{}", store.next_token);
+                let expires = Instant::now() + Duration::from_secs(timeout);
+                store.locks.insert(path.clone(), (token.clone(), expires));
+                // 锁定不存在的资源时创建空文件
+                store.files.entry(path).or_default();
+                Response::builder()
+                    .header("Lock-Token", format!("<{}>", token))
+                    .header("Content-Type", "application/xml; charset=utf-8")
+                    .body(Body::from(format!(
+                        "<?xml version=\"1.0\" encoding=\"utf-8\"?><D:prop xmlns:D=\"DAV:\">\
+                         <D:lockdiscovery><D:activelock><D:locktoken><D:href>{}</D:href>\
+                         </D:locktoken></D:activelock></D:lockdiscovery></D:prop>",
+                        token
+                    )))
+                    .unwrap()
+            }
+        }
+        "UNLOCK" if quirks.locking => {
+            let mut store = state.store.lock().unwrap();
+            let token = header_token(&req, "Lock-Token");
+            if token.is_some() && store.lock_token(&path) == token.as_deref() {
+                store.locks.remove(&path);
+                status(StatusCode::NO_CONTENT)
+            } else {
+                status(StatusCode::CONFLICT)
+            }
+        }
+        "PROPFIND" => {
+            let store = state.store.lock().unwrap();
+            if !store.dirs.contains(&path) {
+                status(StatusCode::NOT_FOUND)
+            } else {
+                Response::builder()
+                    .status(StatusCode::from_u16(quirks.propfind_status).unwrap())
+                    .header("Content-Type", "application/xml; charset=utf-8")
+                    .body(Body::from(propfind_xml(&state, &quirks, &path, &store)))
+                    .unwrap()
+            }
+        }
+        _ => status(StatusCode::METHOD_NOT_ALLOWED),
+    };
+    Ok(res)
+}
+
+impl DavServer {
+    /// 在当前 tokio 运行时中启动服务器，`dirs` 为预先存在的目录
+    pub async fn start(quirks: Quirks, dirs: &[&str]) -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let mut store = Store::default();
+        store.dirs.insert(String::new());
+        for dir in dirs {
+            store.dirs.insert(dir.trim_matches('/').to_string());
+        }
+        let state = Arc::new(State {
+            quirks: Mutex::new(quirks),
+            store: Mutex::new(store),
+            requests: Mutex::new(Vec::new()),
+            addr,
+        });
+
+        let service_state = state.clone();
+        let make_svc = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move { Ok::<_, Infallible>(service_fn(move |req| handle(state.clone(), req))) }
+        });
+        let server = Server::from_tcp(listener).unwrap().serve(make_svc);
+        tokio::spawn(server);
+
+        Self {
+            url: format!("http://{}", addr),
+            state,
+        }
+    }
+
+    pub fn set_quirks(&self, f: impl FnOnce(&mut Quirks)) {
+        f(&mut self.state.quirks.lock().unwrap());
+    }
+
+    pub fn file(&self, path: &str) -> Option<Vec<u8>> {
+        self.state.store.lock().unwrap().files.get(path).cloned()
+    }
+
+    pub fn put_file(&self, path: &str, data: &[u8]) {
+        let mut store = self.state.store.lock().unwrap();
+        store.files.insert(path.to_string(), data.to_vec());
+    }
+
+    /// 某个目录下的文件名
+    pub fn list(&self, dir: &str) -> Vec<String> {
+        let store = self.state.store.lock().unwrap();
+        store
+            .files
+            .keys()
+            .filter(|f| parent(f) == dir)
+            .map(|f| file_name(f).to_string())
+            .collect()
+    }
+
+    /// 模拟其他客户端持有的 LOCK，返回其令牌
+    pub fn hold_lock(&self, path: &str) -> String {
+        let mut store = self.state.store.lock().unwrap();
+        let token = "opaquelocktoken:other".to_string();
+        let expires = Instant::now() + Duration::from_secs(3600);
+        store
+            .locks
+            .insert(path.to_string(), (token.clone(), expires));
+        store.files.entry(path.to_string()).or_default();
+        token
+    }
+
+    /// 未到期的锁数量
+    pub fn active_locks(&self) -> usize {
+        let store = self.state.store.lock().unwrap();
+        store
+            .locks
+            .keys()
+            .filter(|path| store.lock_token(path).is_some())
+            .count()
+    }
+
+    pub fn remove_file(&self, path: &str) {
+        self.state.store.lock().unwrap().files.remove(path);
+    }
+
+    pub fn requests(&self, method: &str) -> usize {
+        let requests = self.state.requests.lock().unwrap();
+        requests.iter().filter(|(m, _)| m == method).count()
+    }
+
+    /// 对某个路径的某种请求数量
+    pub fn requests_to(&self, method: &str, path: &str) -> usize {
+        let requests = self.state.requests.lock().unwrap();
+        requests
+            .iter()
+            .filter(|(m, p)| m == method && p == path)
+            .count()
+    }
+
+    pub fn config(&self) -> WebDavConfig {
+        WebDavConfig {
+            url: self.url.clone(),
+            username: String::new(),
+            password: None,
+            path: None,
+        }
+    }
+}
+
+// 应用数据目录由 HOME 决定，测试之间串行执行并各自使用新的 HOME
+static LOCK: AsyncMutex<()> = AsyncMutex::const_new(());
+static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+pub struct TestEnv {
+    _guard: MutexGuard<'static, ()>,
+    root: PathBuf,
+}
+
+impl TestEnv {
+    pub async fn new() -> Self {
+        let guard = LOCK.lock().await;
+        let root = std::env::temp_dir().join(format!(
+            "endswitcher-test-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::SeqCst)
+        ));
+        let _ = std::fs::remove_dir_all(&root);
+        std::fs::create_dir_all(&root).unwrap();
+        let env = Self {
+            _guard: guard,
+            root,
+        };
+        env.use_device("main");
+        env
+    }
+
+    /// 测试专用的文件夹，用作文件夹远端或模拟挂载的共享目录
+    pub fn folder(&self, name: &str) -> PathBuf {
+        let dir = self.root.join("folders").join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// 切换到另一台“设备”的数据目录，用于模拟多设备同步
+    pub fn use_device(&self, name: &str) {
+        let home = self.root.join(name);
+        std::fs::create_dir_all(&home).unwrap();
+        std::env::set_var("HOME", &home);
+    }
+
+    /// 当前设备的应用数据目录
+    pub fn app_dir(&self) -> PathBuf {
+        let home = PathBuf::from(std::env::var("HOME").unwrap());
+        let dir = home.join("AppData").join("Roaming").join("EndSwitcher");
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn accounts_dir(&self) -> PathBuf {
+        let dir = self.app_dir().join("accounts");
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    pub fn write_account(&self, alias: &str, data: &[u8]) {
+        std::fs::write(self.accounts_dir().join(alias), data).unwrap();
+    }
+
+    pub fn read_account(&self, alias: &str) -> Option<Vec<u8>> {
+        std::fs::read(self.accounts_dir().join(alias)).ok()
+    }
+
+    pub fn remove_account(&self, alias: &str) {
+        std::fs::remove_file(self.accounts_dir().join(alias)).unwrap();
+    }
+}
+
+impl Drop for TestEnv {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.root);
+    }
+}
+
+pub fn add_remote(name: &str, backend: RemoteBackend) {
+    save_remote(SyncRemote {
+        name: name.to_string(),
+        direction: SyncDirection::TwoWay,
+        backend,
+        include: Vec::new(),
+        exclude: Vec::new(),
+    })
+    .unwrap();
+}
+
+pub fn folder_backend(path: &Path) -> RemoteBackend {
+    RemoteBackend::Folder(LocalFolderConfig {
+        path: path.to_string_lossy().into_owned(),
+    })
+}
+
+pub const GIT_PASSPHRASE: &str = "correct horse";
+
+pub fn git_backend(repo: &Path, remote_url: Option<&Path>) -> RemoteBackend {
+    RemoteBackend::Git(GitConfig {
+        path: repo.to_string_lossy().into_owned(),
+        remote_url: remote_url.map(|url| url.to_string_lossy().into_owned()),
+        branch: "main".to_string(),
+        passphrase: GIT_PASSPHRASE.to_string(),
+    })
+}
+
+/// 报告断言的简写
+pub trait ReportExt {
+    fn ensure_ok(&self);
+    fn has_failures(&self) -> bool;
+}
+
+impl ReportExt for SyncReport {
+    fn ensure_ok(&self) {
+        let failed: Vec<_> = self
+            .entries
+            .iter()
+            .filter(|e| e.action == SyncAction::Failed)
+            .collect();
+        assert!(failed.is_empty(), "sync failed: {:?}", failed);
+    }
+
+    fn has_failures(&self) -> bool {
+        self.entries.iter().any(|e| e.action == SyncAction::Failed)
+    }
+}
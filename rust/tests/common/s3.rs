@@ -0,0 +1,334 @@
+// 本地 S3 兼容服务器（路径形式地址），校验 SigV4 签名
+use hmac::{Hmac, Mac};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use rust_lib_endswitcher::api::s3::S3Config;
+
+pub const BUCKET: &str = "endswitcher";
+pub const ACCESS_KEY: &str = "minio";
+pub const SECRET_KEY: &str = "minio-secret";
+const REGION: &str = "us-east-1";
+
+/// 模拟服务器行为
+#[derive(Debug, Clone)]
+pub struct S3Quirks {
+    /// ListObjectsV2 每页返回的对象数
+    pub page_size: usize,
+    /// 收到对 blobs/ 的 PUT 时改写 history.json，模拟另一台设备同时推送
+    pub touch_history_on_blob_put: bool,
+}
+
+impl Default for S3Quirks {
+    fn default() -> Self {
+        Self {
+            page_size: 1000,
+            touch_history_on_blob_put: false,
+        }
+    }
+}
+
+struct State {
+    quirks: Mutex<S3Quirks>,
+    objects: Mutex<BTreeMap<String, Vec<u8>>>,
+    requests: Mutex<Vec<(String, String)>>,
+}
+
+pub struct S3Server {
+    pub url: String,
+    state: Arc<State>,
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+fn hmac(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).unwrap();
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+fn etag(data: &[u8]) -> String {
+    format!("\"{}\"", &sha256_hex(data)[..32])
+}
+
+fn header<'a>(req: &'a Request<Body>, name: &str) -> &'a str {
+    req.headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or("")
+}
+
+// 按服务端收到的请求重新计算签名
+fn signature_valid(req: &Request<Body>) -> bool {
+    let auth = header(req, "authorization");
+    let Some(rest) = auth.strip_prefix("AWS4-HMAC-SHA256 ") else {
+        return false;
+    };
+    let mut credential = "";
+    let mut signed_headers = "";
+    let mut signature = "";
+    for part in rest.split(", ") {
+        if let Some(v) = part.strip_prefix("Credential=") {
+            credential = v;
+        } else if let Some(v) = part.strip_prefix("SignedHeaders=") {
+            signed_headers = v;
+        } else if let Some(v) = part.strip_prefix("Signature=") {
+            signature = v;
+        }
+    }
+    let Some((access_key, scope)) = credential.split_once('/') else {
+        return false;
+    };
+    let date = scope.split('/').next().unwrap_or("");
+    if access_key != ACCESS_KEY || scope != format!("{}/{}/s3/aws4_request", date, REGION) {
+        return false;
+    }
+
+    let mut query: Vec<&str> = req
+        .uri()
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .filter(|s| !s.is_empty())
+        .collect();
+    query.sort();
+    let canonical_headers: String = signed_headers
+        .split(';')
+        .map(|name| format!("{}:{}\n", name, header(req, name).trim()))
+        .collect();
+    let payload = header(req, "x-amz-content-sha256");
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        req.method(),
+        req.uri().path(),
+        query.join("&"),
+        canonical_headers,
+        signed_headers,
+        payload
+    );
+    let string_to_sign = format!(
+        "AWS4-HMAC-SHA256\n{}\n{}\n{}",
+        header(req, "x-amz-date"),
+        scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+    let key = hmac(format!("AWS4{}", SECRET_KEY).as_bytes(), date);
+    let key = hmac(&key, REGION);
+    let key = hmac(&key, "s3");
+    let key = hmac(&key, "aws4_request");
+    let expected: String = hmac(&key, &string_to_sign)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    expected == signature
+}
+
+fn status(code: StatusCode) -> Response<Body> {
+    Response::builder()
+        .status(code)
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn query_param(req: &Request<Body>, name: &str) -> Option<String> {
+    req.uri().query()?.split('&').find_map(|pair| {
+        let (k, v) = pair.split_once('=')?;
+        (k == name).then(|| urlencoding::decode(v).unwrap().into_owned())
+    })
+}
+
+fn list_objects(state: &State, quirks: &S3Quirks, req: &Request<Body>) -> Response<Body> {
+    let prefix = query_param(req, "prefix").unwrap_or_default();
+    let after = query_param(req, "continuation-token");
+    let objects = state.objects.lock().unwrap();
+    // 按分隔符折叠子目录，只返回直接位于前缀下的对象
+    let keys: Vec<&String> = objects
+        .keys()
+        .filter(|k| k.starts_with(&prefix) && !k[prefix.len()..].contains('/'))
+        .filter(|k| after.as_ref().is_none_or(|a| k.as_str() > a.as_str()))
+        .collect();
+    let page = &keys[..keys.len().min(quirks.page_size)];
+    let truncated = keys.len() > page.len();
+
+    let mut xml = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8"?><ListBucketResult xmlns="http://s3.amazonaws.com/doc/2006-03-01/">"#,
+    );
+    xml.push_str(&format!(
+        "<Name>{}</Name><Prefix>{}</Prefix>",
+        BUCKET, prefix
+    ));
+    for key in page {
+        xml.push_str(&format!(
+            "<Contents><Key>{}</Key><Size>{}</Size></Contents>",
+            key.replace('&', "&amp;").replace('<', "&lt;"),
+            objects[*key].len()
+        ));
+    }
+    xml.push_str(&format!("<IsTruncated>{}</IsTruncated>", truncated));
+    if truncated {
+        xml.push_str(&format!(
+            "<NextContinuationToken>{}</NextContinuationToken>",
+            page.last().unwrap()
+        ));
+    }
+    xml.push_str("</ListBucketResult>");
+    Response::builder()
+        .header("Content-Type", "application/xml")
+        .body(Body::from(xml))
+        .unwrap()
+}
+
+async fn handle(state: Arc<State>, req: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let quirks = state.quirks.lock().unwrap().clone();
+    let path = urlencoding::decode(req.uri().path()).unwrap().into_owned();
+    state
+        .requests
+        .lock()
+        .unwrap()
+        .push((req.method().to_string(), path.clone()));
+
+    if !signature_valid(&req) {
+        return Ok(status(StatusCode::FORBIDDEN));
+    }
+    let Some(rest) = path.strip_prefix(&format!("/{}", BUCKET)) else {
+        return Ok(status(StatusCode::NOT_FOUND));
+    };
+    let key = rest.trim_start_matches('/').to_string();
+    if key.is_empty() {
+        return Ok(match *req.method() {
+            Method::GET => list_objects(&state, &quirks, &req),
+            _ => status(StatusCode::METHOD_NOT_ALLOWED),
+        });
+    }
+
+    let method = req.method().clone();
+    let payload = header(&req, "x-amz-content-sha256").to_string();
+    let create_only = header(&req, "If-None-Match") == "*";
+    let res = match method {
+        Method::PUT => {
+            let data = hyper::body::to_bytes(req.into_body()).await.unwrap();
+            if payload != "UNSIGNED-PAYLOAD" && payload != sha256_hex(&data) {
+                return Ok(status(StatusCode::BAD_REQUEST));
+            }
+            let mut objects = state.objects.lock().unwrap();
+            if create_only && objects.contains_key(&key) {
+                return Ok(status(StatusCode::PRECONDITION_FAILED));
+            }
+            if quirks.touch_history_on_blob_put && key.contains("/blobs/") {
+                let history = key.split("/blobs/").next().unwrap().to_string() + "/history.json";
+                objects.insert(history, br#"{"accounts":{}}"#.to_vec());
+            }
+            let tag = etag(&data);
+            objects.insert(key, data.to_vec());
+            Response::builder()
+                .header("ETag", tag)
+                .body(Body::empty())
+                .unwrap()
+        }
+        Method::GET | Method::HEAD => {
+            let objects = state.objects.lock().unwrap();
+            match objects.get(&key) {
+                None => status(StatusCode::NOT_FOUND),
+                Some(data) => {
+                    let builder = Response::builder()
+                        .header("Content-Type", "binary/octet-stream")
+                        .header("Content-Length", data.len())
+                        .header("ETag", etag(data));
+                    let body = if method == Method::HEAD {
+                        Body::empty()
+                    } else {
+                        Body::from(data.clone())
+                    };
+                    builder.body(body).unwrap()
+                }
+            }
+        }
+        Method::DELETE => {
+            state.objects.lock().unwrap().remove(&key);
+            status(StatusCode::NO_CONTENT)
+        }
+        _ => status(StatusCode::METHOD_NOT_ALLOWED),
+    };
+    Ok(res)
+}
+
+impl S3Server {
+    pub async fn start(quirks: S3Quirks) -> Self {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = Arc::new(State {
+            quirks: Mutex::new(quirks),
+            objects: Mutex::new(BTreeMap::new()),
+            requests: Mutex::new(Vec::new()),
+        });
+
+        let service_state = state.clone();
+        let make_svc = make_service_fn(move |_| {
+            let state = service_state.clone();
+            async move { Ok::<_, Infallible>(service_fn(move |req| handle(state.clone(), req))) }
+        });
+        let server = Server::from_tcp(listener).unwrap().serve(make_svc);
+        tokio::spawn(server);
+
+        Self {
+            url: format!("http://{}", addr),
+            state,
+        }
+    }
+
+    pub fn set_quirks(&self, f: impl FnOnce(&mut S3Quirks)) {
+        f(&mut self.state.quirks.lock().unwrap());
+    }
+
+    pub fn object(&self, key: &str) -> Option<Vec<u8>> {
+        self.state.objects.lock().unwrap().get(key).cloned()
+    }
+
+    pub fn put_object(&self, key: &str, data: &[u8]) {
+        self.state
+            .objects
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), data.to_vec());
+    }
+
+    pub fn remove_object(&self, key: &str) {
+        self.state.objects.lock().unwrap().remove(key);
+    }
+
+    /// 统计路径包含 `pattern` 的某种请求数量
+    pub fn requests_to(&self, method: &str, pattern: &str) -> usize {
+        let requests = self.state.requests.lock().unwrap();
+        requests
+            .iter()
+            .filter(|(m, path)| m == method && path.contains(pattern))
+            .count()
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.state.objects.lock().unwrap().keys().cloned().collect()
+    }
+
+    pub fn requests(&self, method: &str) -> usize {
+        let requests = self.state.requests.lock().unwrap();
+        requests.iter().filter(|(m, _)| m == method).count()
+    }
+
+    pub fn config(&self) -> S3Config {
+        S3Config {
+            endpoint: self.url.clone(),
+            region: REGION.to_string(),
+            bucket: BUCKET.to_string(),
+            access_key_id: ACCESS_KEY.to_string(),
+            secret_access_key: SECRET_KEY.to_string(),
+            prefix: None,
+            path_style: true,
+        }
+    }
+}
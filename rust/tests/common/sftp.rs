@@ -0,0 +1,486 @@
+// 基于 russh 的本地 SSH/SFTP 服务器，文件保存在内存中，登录用户的主目录即根目录
+use russh::keys::ssh_key::private::Ed25519Keypair;
+use russh::keys::{PrivateKey, PublicKey};
+use russh::server::{Auth, ChannelOpenHandle, Msg, Server, Session};
+use russh::{Channel, ChannelId};
+use russh_sftp::protocol::{
+    Attrs, Data, File, FileAttributes, Handle as SftpHandle, Name, OpenFlags, Status, StatusCode,
+    Version,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::{SocketAddr, TcpListener};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use rust_lib_endswitcher::api::sftp::SftpConfig;
+
+pub const USERNAME: &str = "tester";
+pub const PASSWORD: &str = "hunter2";
+
+/// 服务器接受的登录方式
+#[derive(Debug, Clone, Default)]
+pub struct SftpAuth {
+    pub password: Option<String>,
+    /// 允许登录的 ed25519 公钥（`.pub` 文件）
+    pub authorized_key: Option<PathBuf>,
+}
+
+#[derive(Default)]
+struct Store {
+    files: BTreeMap<String, (Vec<u8>, u32)>,
+    dirs: BTreeSet<String>,
+}
+
+struct State {
+    password: Option<String>,
+    authorized_key: Option<PublicKey>,
+    store: Mutex<Store>,
+}
+
+pub struct SftpServer {
+    pub port: u16,
+    host_key: PrivateKey,
+    state: Arc<State>,
+}
+
+fn unix_now() -> u32 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as u32
+}
+
+fn normalize(path: &str) -> String {
+    let mut parts: Vec<&str> = Vec::new();
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            s => parts.push(s),
+        }
+    }
+    parts.join("/")
+}
+
+fn parent(path: &str) -> &str {
+    path.rsplit_once('/').map(|(p, _)| p).unwrap_or("")
+}
+
+fn attrs(size: u64, perm: u32, mtime: u32) -> FileAttributes {
+    FileAttributes {
+        size: Some(size),
+        permissions: Some(perm),
+        atime: Some(mtime),
+        mtime: Some(mtime),
+        ..Default::default()
+    }
+}
+
+fn ok(id: u32) -> Status {
+    Status {
+        id,
+        status_code: StatusCode::Ok,
+        error_message: String::new(),
+        language_tag: String::new(),
+    }
+}
+
+#[derive(Clone)]
+struct SshServer {
+    state: Arc<State>,
+}
+
+impl Server for SshServer {
+    type Handler = SshSession;
+
+    fn new_client(&mut self, _: Option<SocketAddr>) -> SshSession {
+        SshSession {
+            state: self.state.clone(),
+            channels: HashMap::new(),
+        }
+    }
+}
+
+struct SshSession {
+    state: Arc<State>,
+    channels: HashMap<ChannelId, Channel<Msg>>,
+}
+
+impl russh::server::Handler for SshSession {
+    type Error = russh::Error;
+
+    async fn auth_password(&mut self, user: &str, password: &str) -> Result<Auth, Self::Error> {
+        if user == USERNAME && self.state.password.as_deref() == Some(password) {
+            return Ok(Auth::Accept);
+        }
+        Ok(Auth::reject())
+    }
+
+    async fn auth_publickey(&mut self, user: &str, key: &PublicKey) -> Result<Auth, Self::Error> {
+        let authorized = self.state.authorized_key.as_ref();
+        if user == USERNAME && authorized.is_some_and(|k| k.key_data() == key.key_data()) {
+            return Ok(Auth::Accept);
+        }
+        Ok(Auth::reject())
+    }
+
+    async fn channel_open_session(
+        &mut self,
+        channel: Channel<Msg>,
+        reply: ChannelOpenHandle,
+        _: &mut Session,
+    ) -> Result<(), Self::Error> {
+        self.channels.insert(channel.id(), channel);
+        reply.accept().await;
+        Ok(())
+    }
+
+    async fn channel_eof(
+        &mut self,
+        channel: ChannelId,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        session.close(channel)
+    }
+
+    async fn subsystem_request(
+        &mut self,
+        channel_id: ChannelId,
+        name: &str,
+        session: &mut Session,
+    ) -> Result<(), Self::Error> {
+        match self.channels.remove(&channel_id) {
+            Some(channel) if name == "sftp" => {
+                session.channel_success(channel_id)?;
+                let sftp = SftpSession {
+                    state: self.state.clone(),
+                    handles: HashMap::new(),
+                    next_handle: 0,
+                };
+                russh_sftp::server::run(channel.into_stream(), sftp).await;
+            }
+            _ => session.channel_failure(channel_id)?,
+        }
+        Ok(())
+    }
+}
+
+enum Handle {
+    File(String),
+    Dir(Option<Vec<File>>),
+}
+
+struct SftpSession {
+    state: Arc<State>,
+    handles: HashMap<String, Handle>,
+    next_handle: u32,
+}
+
+impl SftpSession {
+    fn stat_path(&self, path: &str) -> Result<FileAttributes, StatusCode> {
+        let store = self.state.store.lock().unwrap();
+        if let Some((data, mtime)) = store.files.get(path) {
+            return Ok(attrs(data.len() as u64, 0o100600, *mtime));
+        }
+        match store.dirs.contains(path) {
+            true => Ok(attrs(0, 0o040700, unix_now())),
+            false => Err(StatusCode::NoSuchFile),
+        }
+    }
+
+    fn add_handle(&mut self, id: u32, handle: Handle) -> SftpHandle {
+        self.next_handle += 1;
+        let name = self.next_handle.to_string();
+        self.handles.insert(name.clone(), handle);
+        SftpHandle { id, handle: name }
+    }
+
+    fn file_handle(&self, handle: &str) -> Result<String, StatusCode> {
+        match self.handles.get(handle) {
+            Some(Handle::File(path)) => Ok(path.clone()),
+            _ => Err(StatusCode::Failure),
+        }
+    }
+}
+
+impl russh_sftp::server::Handler for SftpSession {
+    type Error = StatusCode;
+
+    fn unimplemented(&self) -> StatusCode {
+        StatusCode::OpUnsupported
+    }
+
+    async fn init(&mut self, _: u32, _: HashMap<String, String>) -> Result<Version, StatusCode> {
+        Ok(Version::new())
+    }
+
+    async fn open(
+        &mut self,
+        id: u32,
+        filename: String,
+        flags: OpenFlags,
+        _: FileAttributes,
+    ) -> Result<SftpHandle, StatusCode> {
+        let path = normalize(&filename);
+        let mut store = self.state.store.lock().unwrap();
+        let exists = store.files.contains_key(&path);
+        if flags.contains(OpenFlags::CREATE) {
+            if exists && flags.contains(OpenFlags::EXCLUDE) {
+                return Err(StatusCode::Failure);
+            }
+            if !store.dirs.contains(parent(&path)) {
+                return Err(StatusCode::NoSuchFile);
+            }
+            if store.dirs.contains(&path) {
+                return Err(StatusCode::Failure);
+            }
+            if !exists || flags.contains(OpenFlags::TRUNCATE) {
+                store.files.insert(path.clone(), (Vec::new(), unix_now()));
+            }
+        } else if !exists {
+            return Err(StatusCode::NoSuchFile);
+        }
+        drop(store);
+        Ok(self.add_handle(id, Handle::File(path)))
+    }
+
+    async fn close(&mut self, id: u32, handle: String) -> Result<Status, StatusCode> {
+        self.handles.remove(&handle);
+        Ok(ok(id))
+    }
+
+    async fn read(
+        &mut self,
+        id: u32,
+        handle: String,
+        offset: u64,
+        len: u32,
+    ) -> Result<Data, StatusCode> {
+        let path = self.file_handle(&handle)?;
+        let store = self.state.store.lock().unwrap();
+        let data = &store.files.get(&path).ok_or(StatusCode::NoSuchFile)?.0;
+        let offset = offset as usize;
+        if offset >= data.len() {
+            return Err(StatusCode::Eof);
+        }
+        let end = data.len().min(offset + len as usize);
+        Ok(Data {
+            id,
+            data: data[offset..end].to_vec(),
+        })
+    }
+
+    async fn write(
+        &mut self,
+        id: u32,
+        handle: String,
+        offset: u64,
+        chunk: Vec<u8>,
+    ) -> Result<Status, StatusCode> {
+        let path = self.file_handle(&handle)?;
+        let mut store = self.state.store.lock().unwrap();
+        let (data, mtime) = store.files.get_mut(&path).ok_or(StatusCode::NoSuchFile)?;
+        let offset = offset as usize;
+        if data.len() < offset + chunk.len() {
+            data.resize(offset + chunk.len(), 0);
+        }
+        data[offset..offset + chunk.len()].copy_from_slice(&chunk);
+        *mtime = unix_now();
+        Ok(ok(id))
+    }
+
+    async fn lstat(&mut self, id: u32, path: String) -> Result<Attrs, StatusCode> {
+        self.stat(id, path).await
+    }
+
+    async fn stat(&mut self, id: u32, path: String) -> Result<Attrs, StatusCode> {
+        let attrs = self.stat_path(&normalize(&path))?;
+        Ok(Attrs { id, attrs })
+    }
+
+    async fn fstat(&mut self, id: u32, handle: String) -> Result<Attrs, StatusCode> {
+        let attrs = self.stat_path(&self.file_handle(&handle)?)?;
+        Ok(Attrs { id, attrs })
+    }
+
+    async fn setstat(
+        &mut self,
+        id: u32,
+        _: String,
+        _: FileAttributes,
+    ) -> Result<Status, StatusCode> {
+        Ok(ok(id))
+    }
+
+    async fn fsetstat(
+        &mut self,
+        id: u32,
+        _: String,
+        _: FileAttributes,
+    ) -> Result<Status, StatusCode> {
+        Ok(ok(id))
+    }
+
+    async fn opendir(&mut self, id: u32, path: String) -> Result<SftpHandle, StatusCode> {
+        let path = normalize(&path);
+        let store = self.state.store.lock().unwrap();
+        if !store.dirs.contains(&path) {
+            return Err(StatusCode::NoSuchFile);
+        }
+        let now = unix_now();
+        let mut entries = vec![
+            File::new(".", attrs(0, 0o040700, now)),
+            File::new("..", attrs(0, 0o040700, now)),
+        ];
+        let child = |p: &str| p != path && parent(p) == path;
+        for dir in store.dirs.iter().filter(|d| child(d)) {
+            let name = dir.rsplit('/').next().unwrap();
+            entries.push(File::new(name, attrs(0, 0o040700, now)));
+        }
+        for (file, (data, mtime)) in store.files.iter().filter(|(f, _)| child(f)) {
+            let name = file.rsplit('/').next().unwrap();
+            entries.push(File::new(name, attrs(data.len() as u64, 0o100600, *mtime)));
+        }
+        drop(store);
+        Ok(self.add_handle(id, Handle::Dir(Some(entries))))
+    }
+
+    async fn readdir(&mut self, id: u32, handle: String) -> Result<Name, StatusCode> {
+        match self.handles.get_mut(&handle) {
+            Some(Handle::Dir(entries)) => match entries.take() {
+                Some(files) => Ok(Name { id, files }),
+                None => Err(StatusCode::Eof),
+            },
+            _ => Err(StatusCode::Failure),
+        }
+    }
+
+    async fn remove(&mut self, id: u32, filename: String) -> Result<Status, StatusCode> {
+        let path = normalize(&filename);
+        match self.state.store.lock().unwrap().files.remove(&path) {
+            Some(_) => Ok(ok(id)),
+            None => Err(StatusCode::NoSuchFile),
+        }
+    }
+
+    async fn mkdir(
+        &mut self,
+        id: u32,
+        path: String,
+        _: FileAttributes,
+    ) -> Result<Status, StatusCode> {
+        let path = normalize(&path);
+        let mut store = self.state.store.lock().unwrap();
+        if store.dirs.contains(&path) || store.files.contains_key(&path) {
+            return Err(StatusCode::Failure);
+        }
+        if !store.dirs.contains(parent(&path)) {
+            return Err(StatusCode::NoSuchFile);
+        }
+        store.dirs.insert(path);
+        Ok(ok(id))
+    }
+
+    async fn realpath(&mut self, id: u32, path: String) -> Result<Name, StatusCode> {
+        let path = format!("/{}", normalize(&path));
+        Ok(Name {
+            id,
+            files: vec![File::dummy(path)],
+        })
+    }
+
+    // 与 OpenSSH 一样不覆盖已存在的目标
+    async fn rename(&mut self, id: u32, from: String, to: String) -> Result<Status, StatusCode> {
+        let (from, to) = (normalize(&from), normalize(&to));
+        let mut store = self.state.store.lock().unwrap();
+        if store.files.contains_key(&to) || !store.dirs.contains(parent(&to)) {
+            return Err(StatusCode::Failure);
+        }
+        let file = store.files.remove(&from).ok_or(StatusCode::NoSuchFile)?;
+        store.files.insert(to, file);
+        Ok(ok(id))
+    }
+}
+
+impl SftpServer {
+    pub fn start(auth: SftpAuth) -> Self {
+        let authorized_key = auth.authorized_key.map(|path| {
+            let line = std::fs::read_to_string(path).unwrap();
+            PublicKey::from_openssh(line.trim()).unwrap()
+        });
+        let mut store = Store::default();
+        store.dirs.insert(String::new());
+        let state = Arc::new(State {
+            password: auth.password,
+            authorized_key,
+            store: Mutex::new(store),
+        });
+        let host_key = PrivateKey::from(Ed25519Keypair::from_seed(&rand::random()));
+        let config = Arc::new(russh::server::Config {
+            keys: vec![host_key.clone()],
+            auth_rejection_time: Duration::ZERO,
+            auth_rejection_time_initial: Some(Duration::ZERO),
+            ..Default::default()
+        });
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let mut server = SshServer {
+            state: state.clone(),
+        };
+        // 在独立的运行时中运行，不依赖调用方的运行时
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            runtime.block_on(async move {
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                let _ = server.run_on_socket(config, &listener).await;
+            });
+        });
+        Self {
+            port,
+            host_key,
+            state,
+        }
+    }
+
+    /// 该服务器在 known_hosts 中的条目
+    pub fn known_hosts_line(&self) -> String {
+        let key = self.host_key.public_key().to_openssh().unwrap();
+        format!("[127.0.0.1]:{} {}\n", self.port, key)
+    }
+
+    pub fn file(&self, path: &str) -> Option<Vec<u8>> {
+        let store = self.state.store.lock().unwrap();
+        store.files.get(path).map(|(data, _)| data.clone())
+    }
+
+    pub fn put_file(&self, path: &str, data: &[u8]) {
+        let mut store = self.state.store.lock().unwrap();
+        store
+            .files
+            .insert(path.to_string(), (data.to_vec(), unix_now()));
+    }
+
+    pub fn files(&self) -> Vec<String> {
+        let store = self.state.store.lock().unwrap();
+        store.files.keys().cloned().collect()
+    }
+
+    pub fn config(&self, known_hosts: &Path) -> SftpConfig {
+        SftpConfig {
+            host: "127.0.0.1".to_string(),
+            port: self.port,
+            username: USERNAME.to_string(),
+            password: Some(PASSWORD.to_string()),
+            private_key_path: None,
+            passphrase: None,
+            path: None,
+            known_hosts_path: Some(known_hosts.to_string_lossy().into_owned()),
+            accept_new_host: false,
+        }
+    }
+}
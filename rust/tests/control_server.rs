@@ -0,0 +1,253 @@
+mod common;
+
+use std::path::PathBuf;
+
+use serde_json::{json, Value};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+
+use common::{add_remote, folder_backend, TestEnv};
+use rust_lib_endswitcher::api::control::{
+    get_control_server_status, get_control_token, regenerate_control_token, start_control_server,
+    stop_control_server,
+};
+use rust_lib_endswitcher::api::endfield::switch_to_account;
+use rust_lib_endswitcher::api::hooks::{HookConfig, HookEvent};
+use rust_lib_endswitcher::api::settings::{get_settings, update_settings};
+
+// 游戏数据目录中的登录凭证
+fn game_cache(env: &TestEnv, data: &[u8]) -> PathBuf {
+    let game = env.folder("game");
+    std::fs::create_dir_all(game.join("sdk_data_1")).unwrap();
+    let cache = game.join("sdk_data_1").join("login_cache");
+    std::fs::write(&cache, data).unwrap();
+    cache
+}
+
+fn start_server(env: &TestEnv) -> u16 {
+    let mut settings = get_settings().unwrap();
+    settings.paths.game_data_dir = Some(env.folder("game").to_string_lossy().into_owned());
+    settings.control_server.enabled = true;
+    settings.control_server.port = 0;
+    update_settings(settings).unwrap();
+    let status = start_control_server().unwrap();
+    assert!(status.running);
+    status.port.unwrap()
+}
+
+struct Client {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    write: OwnedWriteHalf,
+    next_id: u64,
+}
+
+impl Client {
+    async fn connect(port: u16) -> Self {
+        let stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let (read, write) = stream.into_split();
+        Self {
+            lines: BufReader::new(read).lines(),
+            write,
+            next_id: 0,
+        }
+    }
+
+    async fn send_raw(&mut self, line: &str) {
+        self.write.write_all(line.as_bytes()).await.unwrap();
+        self.write.write_all(b"\n").await.unwrap();
+    }
+
+    async fn recv(&mut self) -> Option<Value> {
+        let line = self.lines.next_line().await.unwrap()?;
+        Some(serde_json::from_str(&line).unwrap())
+    }
+
+    // 发送请求并等待对应的响应，期间收到的事件通知一并返回
+    async fn call(&mut self, method: &str, params: Value) -> (Value, Vec<Value>) {
+        self.next_id += 1;
+        let id = self.next_id;
+        let request = json!({ "jsonrpc": "2.0", "id": id, "method": method, "params": params });
+        self.send_raw(&request.to_string()).await;
+        let mut events = Vec::new();
+        loop {
+            let message = self.recv().await.unwrap();
+            if message["id"] == json!(id) {
+                return (message, events);
+            }
+            events.push(message);
+        }
+    }
+
+    async fn result(&mut self, method: &str, params: Value) -> Value {
+        let (response, _) = self.call(method, params).await;
+        assert!(response.get("error").is_none(), "{}", response);
+        response["result"].clone()
+    }
+
+    async fn error(&mut self, method: &str, params: Value) -> (i64, String) {
+        let (response, _) = self.call(method, params).await;
+        let error = &response["error"];
+        let message = error["message"].as_str().unwrap().to_string();
+        (error["code"].as_i64().unwrap(), message)
+    }
+}
+
+#[tokio::test]
+async fn control_server_switches_and_saves_accounts() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    let cache = game_cache(&env, b"alice-token");
+    let port = start_server(&env);
+    assert_eq!(get_control_server_status().unwrap().port, Some(port));
+
+    // 令牌错误时断开连接
+    let mut client = Client::connect(port).await;
+    let (code, _) = client.error("list", Value::Null).await;
+    assert_eq!(code, -32001);
+    let (code, _) = client.error("auth", json!({ "token": "wrong" })).await;
+    assert_eq!(code, -32001);
+    assert!(client.recv().await.is_none());
+
+    let mut client = Client::connect(port).await;
+    let token = get_control_token().unwrap();
+    assert_eq!(
+        client.result("auth", json!({ "token": token })).await,
+        json!(true)
+    );
+
+    let list = client.result("list", Value::Null).await;
+    let mut aliases: Vec<_> = list
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["alias"].as_str().unwrap().to_string())
+        .collect();
+    aliases.sort();
+    assert_eq!(aliases, ["alice", "bob"]);
+    assert_eq!(client.result("current", Value::Null).await, json!("alice"));
+
+    client.result("switch", json!({ "alias": "bob" })).await;
+    assert_eq!(std::fs::read(&cache).unwrap(), b"bob-token");
+    assert_eq!(client.result("current", Value::Null).await, json!("bob"));
+
+    client.result("save", json!({ "alias": "carol" })).await;
+    assert_eq!(env.read_account("carol").unwrap(), b"bob-token");
+
+    let (code, message) = client.error("switch", json!({ "alias": "dave" })).await;
+    assert_eq!(
+        (code, message.as_str()),
+        (-32000, "Saved account not found")
+    );
+    let (code, _) = client.error("switch", json!({ "name": "bob" })).await;
+    assert_eq!(code, -32602);
+    let (code, _) = client.error("launch", Value::Null).await;
+    assert_eq!(code, -32601);
+
+    client.send_raw("{not json").await;
+    let response = client.recv().await.unwrap();
+    assert_eq!(response["error"]["code"], json!(-32700));
+    assert_eq!(response["id"], Value::Null);
+
+    // 旧令牌失效
+    regenerate_control_token().unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let meta = std::fs::metadata(env.app_dir().join("control_token")).unwrap();
+        assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+    }
+    let mut other = Client::connect(port).await;
+    let (code, _) = other.error("auth", json!({ "token": token })).await;
+    assert_eq!(code, -32001);
+    assert!(other.recv().await.is_none());
+
+    stop_control_server().unwrap();
+    assert!(client.recv().await.is_none());
+    assert!(!get_control_server_status().unwrap().running);
+}
+
+#[tokio::test]
+async fn subscribers_receive_switch_and_sync_events() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    game_cache(&env, b"alice-token");
+    add_remote("usb", folder_backend(&env.folder("share")));
+    let port = start_server(&env);
+
+    let mut client = Client::connect(port).await;
+    let token = get_control_token().unwrap();
+    client.result("auth", json!({ "token": token })).await;
+    client.result("subscribe", Value::Null).await;
+
+    // 界面中切换账号同样会通知订阅者
+    switch_to_account("bob".to_string()).unwrap();
+    let event = client.recv().await.unwrap();
+    assert_eq!(event["method"], json!("event"));
+    assert_eq!(
+        event["params"],
+        json!({ "type": "switched", "alias": "bob" })
+    );
+
+    let (response, mut events) = client.call("sync", json!({ "operation": "Push" })).await;
+    assert_eq!(response["result"]["operation"], json!("Push"));
+    if events.is_empty() {
+        events.push(client.recv().await.unwrap());
+    }
+    assert_eq!(events[0]["params"]["type"], json!("synced"));
+    assert_eq!(events[0]["params"]["report"]["operation"], json!("Push"));
+
+    stop_control_server().unwrap();
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn cancelled_switch_reports_the_hooks_that_ran() {
+    let env = TestEnv::new().await;
+    env.write_account("bob", b"bob-token");
+    let cache = game_cache(&env, b"alice-token");
+    let port = start_server(&env);
+    let hook = |script: &str| HookConfig {
+        event: HookEvent::PreSwitch,
+        command: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        timeout_secs: 5,
+        enabled: true,
+    };
+    let mut settings = get_settings().unwrap();
+    settings.hooks = vec![
+        hook("echo checked"),
+        hook("echo 'game is running' >&2; exit 3"),
+    ];
+    update_settings(settings).unwrap();
+
+    let mut client = Client::connect(port).await;
+    let token = get_control_token().unwrap();
+    client.result("auth", json!({ "token": token })).await;
+    let (response, _) = client.call("switch", json!({ "alias": "bob" })).await;
+    let error = &response["error"];
+    assert_eq!(
+        error["message"],
+        json!("Cancelled by hook sh: exit code 3: game is running")
+    );
+    let hooks = error["data"]["hooks"].as_array().unwrap();
+    assert_eq!(hooks.len(), 2);
+    assert_eq!(hooks[0]["stdout"], json!("checked\n"));
+    assert_eq!(hooks[1]["exit_code"], json!(3));
+    assert_eq!(std::fs::read(&cache).unwrap(), b"alice-token");
+
+    // 其他错误没有附加数据
+    let (response, _) = client.call("switch", json!({ "alias": "dave" })).await;
+    assert!(response["error"].get("data").is_none());
+    stop_control_server().unwrap();
+}
+
+#[tokio::test]
+async fn control_server_is_disabled_by_default() {
+    let _env = TestEnv::new().await;
+    let status = start_control_server().unwrap();
+    assert!(!status.running);
+    assert_eq!(status.port, None);
+}
@@ -0,0 +1,224 @@
+mod common;
+
+use std::path::Path;
+
+use common::{add_remote, folder_backend, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::report::{SyncAction, SyncReport};
+use rust_lib_endswitcher::api::settings::{RemoteBackend, SyncDirection, SyncRemote};
+use rust_lib_endswitcher::api::sync::{
+    list_remotes, save_remote, sync_pull, sync_push, sync_push_with_manifest,
+};
+
+const REMOTE_DIR: &str = "EndSwitcherConfig";
+
+fn names(report: &SyncReport, action: SyncAction) -> Vec<String> {
+    let mut names: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == action)
+        .map(|e| e.name.clone().unwrap_or_default())
+        .collect();
+    names.sort();
+    names
+}
+
+fn entries(dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn write_lock(share: &Path, acquired_at: i64) {
+    let lock = serde_json::json!({
+        "token": "other",
+        "host": "desktop",
+        "acquired_at": acquired_at,
+    });
+    std::fs::write(share.join(REMOTE_DIR).join(".lock"), lock.to_string()).unwrap();
+}
+
+#[tokio::test]
+async fn folder_round_trip_between_devices() {
+    let env = TestEnv::new().await;
+    let share = env.folder("share");
+    add_remote("usb", folder_backend(&share));
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+
+    let report = sync_push_with_manifest(None).await.unwrap();
+    assert_eq!(names(&report, SyncAction::Uploaded), ["alice", "bob"]);
+    let remote = share.join(REMOTE_DIR);
+    // 锁与临时文件都不会留在共享目录中
+    assert_eq!(
+        entries(&remote),
+        ["accounts.json", "alice", "blobs", "bob", "history.json"]
+    );
+    assert_eq!(std::fs::read(remote.join("alice")).unwrap(), b"alice-token");
+    assert_eq!(entries(&remote.join("blobs")).len(), 2);
+
+    env.use_device("laptop");
+    add_remote("usb", folder_backend(&share));
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Downloaded), ["alice", "bob"]);
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-token");
+}
+
+#[tokio::test]
+async fn held_lock_blocks_push_until_stale() {
+    let env = TestEnv::new().await;
+    let share = env.folder("share");
+    add_remote("usb", folder_backend(&share));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.write_account("alice", b"token-v2");
+
+    write_lock(&share, unix_now());
+    let report = sync_push(None).await.unwrap();
+    assert!(report.has_failures());
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("locked by desktop"), "{}", reason);
+    assert_eq!(
+        std::fs::read(share.join(REMOTE_DIR).join("alice")).unwrap(),
+        b"token"
+    );
+
+    // 持有者崩溃后留下的过期锁会被接管
+    write_lock(&share, 0);
+    let report = sync_push(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Uploaded), ["alice"]);
+    assert!(!share.join(REMOTE_DIR).join(".lock").exists());
+}
+
+#[tokio::test]
+async fn dot_aliases_are_pulled() {
+    let env = TestEnv::new().await;
+    let share = env.folder("share");
+    add_remote("usb", folder_backend(&share));
+    env.write_account(".alt", b"alt-token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    add_remote("usb", folder_backend(&share));
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Downloaded), [".alt"]);
+    assert_eq!(env.read_account(".alt").unwrap(), b"alt-token");
+}
+
+#[tokio::test]
+async fn sync_state_is_tracked_per_remote() {
+    let env = TestEnv::new().await;
+    let (backup, share) = (env.folder("backup"), env.folder("share"));
+    add_remote("backup", folder_backend(&backup));
+    add_remote("share", folder_backend(&share));
+    env.write_account("alice", b"alice-v1");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 优先级更高的远端改为只拉取，新版本只推送到另一个远端
+    let mut remote = list_remotes().unwrap().remove(0);
+    remote.direction = SyncDirection::PullOnly;
+    save_remote(remote).unwrap();
+    env.write_account("alice", b"alice-v2");
+    let report = sync_push(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Uploaded), ["alice"]);
+
+    // 只拉取的远端仍是旧版本，不能覆盖尚未推送到它的本地修改
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert!(names(&report, SyncAction::Downloaded).is_empty());
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-v2");
+}
+
+#[tokio::test]
+async fn remote_without_history_does_not_replace_local_accounts() {
+    let env = TestEnv::new().await;
+    let share = env.folder("share");
+    save_remote(SyncRemote {
+        name: "usb".to_string(),
+        direction: SyncDirection::PullOnly,
+        backend: folder_backend(&share),
+        include: Vec::new(),
+        exclude: Vec::new(),
+    })
+    .unwrap();
+    // 其他工具直接放入的账号文件没有版本记录
+    let remote = share.join(REMOTE_DIR);
+    std::fs::create_dir_all(&remote).unwrap();
+    std::fs::write(remote.join("alice"), b"copied").unwrap();
+    std::fs::write(remote.join("bob"), b"copied").unwrap();
+    env.write_account("alice", b"alice-token");
+
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Downloaded), ["bob"]);
+    assert_eq!(names(&report, SyncAction::Skipped), ["alice"]);
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-token");
+    assert_eq!(env.read_account("bob").unwrap(), b"copied");
+}
+
+#[tokio::test]
+async fn missing_folder_is_not_created() {
+    let env = TestEnv::new().await;
+    let share = env.folder("share").join("unplugged");
+    add_remote("usb", folder_backend(&share));
+    env.write_account("alice", b"token");
+
+    let report = sync_push(None).await.unwrap();
+    assert!(report.has_failures());
+    assert_eq!(report.entries[0].name, None);
+    assert!(!share.exists());
+    assert!(sync_pull(None).await.unwrap().has_failures());
+}
+
+#[tokio::test]
+async fn legacy_webdav_config_is_migrated() {
+    let env = TestEnv::new().await;
+    // 早期版本的 config.json 直接保存 WebDAV 配置
+    let config = serde_json::json!({
+        "url": "https://dav.example.com",
+        "username": "me",
+        "password": null,
+        "path": null,
+    });
+    let config_file = env.app_dir().join("config.json");
+    let launch_file = env.app_dir().join("launch.json");
+    std::fs::write(&config_file, config.to_string()).unwrap();
+    std::fs::write(&launch_file, r#"{"executable":"game.exe"}"#).unwrap();
+
+    let remotes = list_remotes().unwrap();
+    assert_eq!(remotes.len(), 1);
+    assert_eq!(remotes[0].name, "default");
+    assert_eq!(remotes[0].direction, SyncDirection::TwoWay);
+    let RemoteBackend::WebDav(webdav) = &remotes[0].backend else {
+        panic!("expected a WebDAV remote");
+    };
+    assert_eq!(webdav.url, "https://dav.example.com");
+    assert_eq!(webdav.username, "me");
+    // 读取设置不会改写文件，迁移结果在保存设置时写入
+    assert_eq!(
+        std::fs::read_to_string(&config_file).unwrap(),
+        config.to_string()
+    );
+    assert!(launch_file.exists());
+
+    add_remote("usb", folder_backend(&env.folder("share")));
+    let saved: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&config_file).unwrap()).unwrap();
+    assert_eq!(saved["schema_version"], 1);
+    assert_eq!(saved["launch"]["executable"], "game.exe");
+    assert_eq!(saved["remotes"].as_array().unwrap().len(), 2);
+    assert!(!launch_file.exists());
+}
@@ -0,0 +1,332 @@
+mod common;
+
+use std::path::Path;
+use std::process::Command;
+
+use common::{add_remote, git_backend, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::report::{SyncAction, SyncOperation, SyncReport};
+use rust_lib_endswitcher::api::settings::{get_settings, RemoteBackend};
+use rust_lib_endswitcher::api::sync::{
+    plan_sync, save_remote, sync_pull, sync_push, sync_push_with_manifest,
+};
+
+fn names(report: &SyncReport, action: SyncAction) -> Vec<String> {
+    let mut names: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == action)
+        .map(|e| e.name.clone().unwrap_or_default())
+        .collect();
+    names.sort();
+    names
+}
+
+fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn bare_repo(env: &TestEnv) -> std::path::PathBuf {
+    let bare = env.folder("origin.git");
+    git(&bare, &["init", "-q", "--bare", "-b", "main"]);
+    bare
+}
+
+fn commit_count(repo: &Path) -> usize {
+    git(repo, &["rev-list", "--count", "main"])
+        .trim()
+        .parse()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn git_round_trip_through_bare_repo() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    add_remote("repo", git_backend(&env.folder("main-clone"), Some(&bare)));
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+
+    let report = sync_push_with_manifest(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Uploaded), ["alice", "bob"]);
+    assert_eq!(commit_count(&bare), 1);
+    // 仓库中只有密文
+    let alice = git(&bare, &["show", "main:EndSwitcherConfig/alice"]);
+    assert!(alice.starts_with("ESCRYPT1"), "{}", alice);
+    assert!(!alice.contains("alice-token"));
+    let files = git(&bare, &["ls-tree", "--name-only", "main:EndSwitcherConfig"]);
+    assert_eq!(
+        files.lines().collect::<Vec<_>>(),
+        ["accounts.json", "alice", "blobs", "bob", "history.json"]
+    );
+
+    // 没有变化时不生成空提交
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(commit_count(&bare), 1);
+
+    env.use_device("laptop");
+    add_remote(
+        "repo",
+        git_backend(&env.folder("laptop-clone"), Some(&bare)),
+    );
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Downloaded), ["alice", "bob"]);
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-token");
+}
+
+#[tokio::test]
+async fn git_clone_catches_up_with_other_devices() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    let main_clone = env.folder("main-clone");
+    add_remote("repo", git_backend(&main_clone, Some(&bare)));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    add_remote(
+        "repo",
+        git_backend(&env.folder("laptop-clone"), Some(&bare)),
+    );
+    env.write_account("alice", b"token-v2");
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(commit_count(&bare), 2);
+
+    // 本地仓库有未提交的修改时不覆盖，处理后快进到远程仓库再推送
+    env.use_device("main");
+    let stray = main_clone.join("EndSwitcherConfig").join("stray");
+    std::fs::write(&stray, b"x").unwrap();
+    env.write_account("bob", b"bob-token");
+    let report = sync_push(None).await.unwrap();
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("uncommitted changes"), "{}", reason);
+    assert!(stray.exists());
+    assert_eq!(commit_count(&bare), 2);
+
+    std::fs::remove_file(&stray).unwrap();
+    let report = sync_push(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(commit_count(&bare), 3);
+    let log = git(&bare, &["log", "--format=%an %s", "main"]);
+    assert!(
+        log.lines().all(|l| l.starts_with("EndSwitcher Sync from")),
+        "{}",
+        log
+    );
+}
+
+#[tokio::test]
+async fn git_diverged_branch_is_not_overwritten() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    let main_clone = env.folder("main-clone");
+    add_remote("repo", git_backend(&main_clone, Some(&bare)));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    add_remote(
+        "repo",
+        git_backend(&env.folder("laptop-clone"), Some(&bare)),
+    );
+    env.write_account("alice", b"token-v2");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 用户在本地仓库中的提交不会被丢弃
+    env.use_device("main");
+    std::fs::write(main_clone.join("notes.txt"), b"mine").unwrap();
+    git(&main_clone, &["add", "notes.txt"]);
+    git(
+        &main_clone,
+        &[
+            "-c",
+            "user.name=me",
+            "-c",
+            "user.email=me@localhost",
+            "commit",
+            "-q",
+            "-m",
+            "notes",
+        ],
+    );
+    let report = sync_pull(None).await.unwrap();
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("diverged"), "{}", reason);
+    assert_eq!(
+        git(&main_clone, &["log", "-1", "--format=%s"]).trim(),
+        "notes"
+    );
+    assert_eq!(env.read_account("alice").unwrap(), b"token");
+}
+
+// 用 pre-receive 钩子模拟远程仓库拒绝推送
+#[cfg(unix)]
+#[tokio::test]
+async fn git_rejected_push_can_be_retried() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    let main_clone = env.folder("main-clone");
+    add_remote("repo", git_backend(&main_clone, Some(&bare)));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 远程仓库拒绝推送，本地提交被撤销
+    let hook = bare.join("hooks").join("pre-receive");
+    std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+    std::fs::set_permissions(&hook, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+    env.write_account("alice", b"token-v2");
+    let before = git(&main_clone, &["rev-parse", "HEAD"]);
+    assert!(sync_push(None).await.unwrap().has_failures());
+    assert_eq!(git(&main_clone, &["rev-parse", "HEAD"]), before);
+    std::fs::remove_file(&hook).unwrap();
+
+    // 期间另一台设备推送，再次同步时快进后推送
+    env.use_device("laptop");
+    add_remote(
+        "repo",
+        git_backend(&env.folder("laptop-clone"), Some(&bare)),
+    );
+    env.write_account("bob", b"bob-token");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.use_device("main");
+    sync_push(None).await.unwrap().ensure_ok();
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Downloaded), ["bob"]);
+
+    env.use_device("laptop");
+    sync_pull(None).await.unwrap().ensure_ok();
+    assert_eq!(env.read_account("alice").unwrap(), b"token-v2");
+}
+
+// 第一次推送被拒绝时不留下本地提交，之后可以快进到其他设备的推送
+#[cfg(unix)]
+#[tokio::test]
+async fn git_rejected_first_push_leaves_no_commit() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    let main_clone = env.folder("main-clone");
+    add_remote("repo", git_backend(&main_clone, Some(&bare)));
+    let hook = bare.join("hooks").join("pre-receive");
+    std::fs::write(&hook, "#!/bin/sh\nexit 1\n").unwrap();
+    std::fs::set_permissions(&hook, std::os::unix::fs::PermissionsExt::from_mode(0o755)).unwrap();
+    env.write_account("alice", b"token");
+    assert!(sync_push(None).await.unwrap().has_failures());
+    assert!(git(&main_clone, &["branch", "--list"]).is_empty());
+    assert!(git(&main_clone, &["status", "--porcelain"]).is_empty());
+    std::fs::remove_file(&hook).unwrap();
+
+    env.use_device("laptop");
+    add_remote(
+        "repo",
+        git_backend(&env.folder("laptop-clone"), Some(&bare)),
+    );
+    env.write_account("bob", b"bob-token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("main");
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(commit_count(&bare), 2);
+    let files = git(&bare, &["ls-tree", "--name-only", "main:EndSwitcherConfig"]);
+    assert!(files.lines().any(|f| f == "alice"), "{}", files);
+}
+
+#[tokio::test]
+async fn git_plan_does_not_fetch() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    let main_clone = env.folder("main-clone");
+    add_remote("repo", git_backend(&main_clone, Some(&bare)));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    let laptop_clone = env.folder("laptop-clone");
+    add_remote("repo", git_backend(&laptop_clone, Some(&bare)));
+    let plan = plan_sync(SyncOperation::Pull, None).await.unwrap();
+    assert!(plan.entries.is_empty(), "{:?}", plan.entries);
+    assert!(!laptop_clone.join(".git").exists());
+
+    env.use_device("main");
+    env.write_account("alice", b"token-v2");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.use_device("laptop");
+    sync_pull(None).await.unwrap().ensure_ok();
+    let head = git(&laptop_clone, &["rev-parse", "HEAD"]);
+    let tracking = git(&laptop_clone, &["rev-parse", "origin/main"]);
+
+    env.use_device("main");
+    env.write_account("bob", b"bob-token");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.use_device("laptop");
+    plan_sync(SyncOperation::Pull, None).await.unwrap();
+    assert_eq!(git(&laptop_clone, &["rev-parse", "HEAD"]), head);
+    assert_eq!(git(&laptop_clone, &["rev-parse", "origin/main"]), tracking);
+}
+
+#[tokio::test]
+async fn git_wrong_passphrase_cannot_read_the_repository() {
+    let env = TestEnv::new().await;
+    let bare = bare_repo(&env);
+    add_remote("repo", git_backend(&env.folder("main-clone"), Some(&bare)));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    add_remote(
+        "repo",
+        git_backend(&env.folder("laptop-clone"), Some(&bare)),
+    );
+    let mut remote = get_settings().unwrap().remotes.pop().unwrap();
+    let RemoteBackend::Git(config) = &mut remote.backend else {
+        unreachable!()
+    };
+    config.passphrase = "wrong".to_string();
+    save_remote(remote).unwrap();
+
+    let report = sync_pull(None).await.unwrap();
+    assert!(report.has_failures());
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("wrong passphrase"), "{}", reason);
+    assert!(env.read_account("alice").is_none());
+}
+
+#[tokio::test]
+async fn git_local_repo_without_remote() {
+    let env = TestEnv::new().await;
+    let repo = env.folder("history");
+    add_remote("local", git_backend(&repo, None));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.write_account("alice", b"token-v2");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    assert_eq!(commit_count(&repo), 2);
+    let diff = git(&repo, &["diff", "--stat", "main~1", "main"]);
+    assert!(diff.contains("EndSwitcherConfig/alice"), "{}", diff);
+    assert!(git(&repo, &["status", "--porcelain"]).is_empty());
+}
+
+#[tokio::test]
+async fn git_unreachable_remote_fails_the_remote() {
+    let env = TestEnv::new().await;
+    let missing = env.folder("gone").join("origin.git");
+    add_remote("repo", git_backend(&env.folder("clone"), Some(&missing)));
+    env.write_account("alice", b"token");
+
+    let report = sync_push(None).await.unwrap();
+    assert!(report.has_failures());
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("Failed to fetch"), "{}", reason);
+}
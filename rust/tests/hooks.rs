@@ -0,0 +1,149 @@
+#![cfg(unix)]
+
+mod common;
+
+use std::path::PathBuf;
+use std::time::Instant;
+
+use common::TestEnv;
+use rust_lib_endswitcher::api::endfield::{save_current_account, switch_to_account};
+use rust_lib_endswitcher::api::hooks::{HookConfig, HookEvent};
+use rust_lib_endswitcher::api::settings::{get_settings, update_settings};
+
+// 游戏数据目录中的登录凭证
+fn game_cache(env: &TestEnv, data: &[u8]) -> PathBuf {
+    let game = env.folder("game");
+    std::fs::create_dir_all(game.join("sdk_data_1")).unwrap();
+    let cache = game.join("sdk_data_1").join("login_cache");
+    std::fs::write(&cache, data).unwrap();
+    cache
+}
+
+fn shell_hook(event: HookEvent, script: &str) -> HookConfig {
+    HookConfig {
+        event,
+        command: "sh".to_string(),
+        args: vec!["-c".to_string(), script.to_string()],
+        timeout_secs: 5,
+        enabled: true,
+    }
+}
+
+fn set_hooks(env: &TestEnv, hooks: Vec<HookConfig>) {
+    let mut settings = get_settings().unwrap();
+    settings.paths.game_data_dir = Some(env.folder("game").to_string_lossy().into_owned());
+    settings.hooks = hooks;
+    update_settings(settings).unwrap();
+}
+
+#[tokio::test]
+async fn switch_runs_hooks_with_context() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    let cache = game_cache(&env, b"alice-token");
+    let mut disabled = shell_hook(HookEvent::PreSwitch, "exit 1");
+    disabled.enabled = false;
+    set_hooks(
+        &env,
+        vec![
+            shell_hook(
+                HookEvent::PreSwitch,
+                "echo \"$ENDSWITCHER_EVENT $ENDSWITCHER_ALIAS\"; cat \"$ENDSWITCHER_LOGIN_CACHE\"",
+            ),
+            disabled,
+            shell_hook(
+                HookEvent::PostSwitch,
+                "cat \"$ENDSWITCHER_LOGIN_CACHE\"; echo oops >&2; exit 2",
+            ),
+            shell_hook(HookEvent::PreSave, "exit 1"),
+        ],
+    );
+
+    let runs = switch_to_account("bob".to_string()).unwrap();
+    assert_eq!(runs.len(), 2);
+    assert_eq!(runs[0].event, HookEvent::PreSwitch);
+    assert_eq!(runs[0].exit_code, Some(0));
+    // 前置钩子运行时游戏内仍是原账号
+    assert_eq!(runs[0].stdout, "pre_switch bob\nalice-token");
+
+    // 后置钩子失败不影响已完成的切换
+    assert_eq!(runs[1].event, HookEvent::PostSwitch);
+    assert_eq!(runs[1].exit_code, Some(2));
+    assert_eq!(runs[1].stdout, "bob-token");
+    assert_eq!(runs[1].stderr, "oops\n");
+    assert!(!runs[1].timed_out);
+    assert_eq!(std::fs::read(&cache).unwrap(), b"bob-token");
+}
+
+#[tokio::test]
+async fn failing_pre_hook_cancels_switch() {
+    let env = TestEnv::new().await;
+    env.write_account("bob", b"bob-token");
+    let cache = game_cache(&env, b"alice-token");
+    let marker = env.folder("hooks").join("post_switch");
+    set_hooks(
+        &env,
+        vec![
+            shell_hook(HookEvent::PreSwitch, "echo 'game is running' >&2; exit 3"),
+            shell_hook(
+                HookEvent::PostSwitch,
+                &format!("touch '{}'", marker.display()),
+            ),
+        ],
+    );
+
+    let err = switch_to_account("bob".to_string()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Cancelled by hook sh: exit code 3: game is running"
+    );
+    assert_eq!(std::fs::read(&cache).unwrap(), b"alice-token");
+    assert!(!marker.exists());
+
+    // 命令不存在同样取消操作
+    let mut missing = shell_hook(HookEvent::PreSwitch, "");
+    missing.command = "endswitcher-missing-hook".to_string();
+    set_hooks(&env, vec![missing]);
+    let err = switch_to_account("bob".to_string()).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Failed to start endswitcher-missing-hook"),
+        "{}",
+        err
+    );
+    assert_eq!(std::fs::read(&cache).unwrap(), b"alice-token");
+}
+
+#[tokio::test]
+async fn slow_pre_hook_times_out() {
+    let env = TestEnv::new().await;
+    game_cache(&env, b"alice-token");
+    let mut slow = shell_hook(HookEvent::PreSave, "echo started; sleep 30");
+    slow.timeout_secs = 1;
+    set_hooks(&env, vec![slow]);
+
+    let start = Instant::now();
+    let err = save_current_account("alice".to_string()).unwrap_err();
+    assert_eq!(err.to_string(), "Cancelled by hook sh: timed out");
+    assert!(start.elapsed().as_secs() < 10);
+    assert!(env.read_account("alice").is_none());
+
+    set_hooks(
+        &env,
+        vec![shell_hook(
+            HookEvent::PostSave,
+            "cat \"$ENDSWITCHER_ACCOUNT_FILE\"",
+        )],
+    );
+    let runs = save_current_account("alice".to_string()).unwrap();
+    assert_eq!(runs[0].stdout, "alice-token");
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-token");
+
+    let mut settings = get_settings().unwrap();
+    settings.hooks[0].timeout_secs = 0;
+    assert!(update_settings(settings.clone()).is_err());
+    settings.hooks[0].timeout_secs = 1;
+    settings.hooks[0].command = " ".to_string();
+    assert!(update_settings(settings).is_err());
+}
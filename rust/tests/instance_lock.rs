@@ -0,0 +1,94 @@
+mod common;
+
+use std::fs::File;
+use std::time::Duration;
+
+use common::{add_remote, folder_backend, TestEnv};
+use rust_lib_endswitcher::api::endfield::delete_account;
+use rust_lib_endswitcher::api::meta::{list_tags, set_account_tags};
+use rust_lib_endswitcher::api::settings::{get_settings, reset_settings, update_settings};
+use rust_lib_endswitcher::api::sync::{list_remotes, remove_remote, sync_pull, sync_push};
+
+fn set_lock_timeout(secs: u32) {
+    let mut settings = get_settings().unwrap();
+    settings.lock_timeout_secs = secs;
+    update_settings(settings).unwrap();
+}
+
+// 模拟另一个进程持有应用锁
+fn hold_lock(env: &TestEnv) -> File {
+    let dir = env.app_dir();
+    let file = File::create(dir.join("endswitcher.lock")).unwrap();
+    file.try_lock().unwrap();
+    let holder = serde_json::json!({
+        "pid": 4321,
+        "operation": "syncing",
+        "acquired_at": 0,
+    });
+    std::fs::write(dir.join("endswitcher.lock.json"), holder.to_string()).unwrap();
+    file
+}
+
+#[tokio::test]
+async fn busy_instance_rejects_mutations() {
+    let env = TestEnv::new().await;
+    add_remote("usb", folder_backend(&env.folder("share")));
+    env.write_account("alice", b"token");
+    set_lock_timeout(0);
+
+    let lock = hold_lock(&env);
+    let err = delete_account("alice".to_string()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "EndSwitcher is busy: syncing in process 4321, try again later"
+    );
+    assert!(env.read_account("alice").is_some());
+    let err = sync_push(None).await.unwrap_err();
+    assert!(err.to_string().contains("busy"), "{}", err);
+    assert!(sync_pull(None).await.is_err());
+    assert!(set_account_tags("alice".to_string(), vec!["main".to_string()]).is_err());
+    let mut settings = get_settings().unwrap();
+    settings.lock_timeout_secs = 1;
+    assert!(update_settings(settings).is_err());
+    assert!(remove_remote("usb".to_string()).is_err());
+    assert_eq!(list_remotes().unwrap().len(), 1);
+
+    // 读取不需要锁
+    assert_eq!(get_settings().unwrap().lock_timeout_secs, 0);
+
+    drop(lock);
+    set_account_tags("alice".to_string(), vec!["main".to_string()]).unwrap();
+    assert_eq!(list_tags().unwrap(), ["main"]);
+    delete_account("alice".to_string()).unwrap();
+    assert!(env.read_account("alice").is_none());
+    // 释放后可以连续获取
+    sync_push(None).await.unwrap();
+    sync_push(None).await.unwrap();
+}
+
+#[tokio::test]
+async fn waits_for_the_holder_within_timeout() {
+    let env = TestEnv::new().await;
+    env.write_account("alice", b"token");
+    set_lock_timeout(5);
+
+    let lock = hold_lock(&env);
+    let holder = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        drop(lock);
+    });
+    delete_account("alice".to_string()).unwrap();
+    holder.join().unwrap();
+    assert!(env.read_account("alice").is_none());
+}
+
+#[tokio::test]
+async fn corrupt_config_can_still_be_reset() {
+    let env = TestEnv::new().await;
+    let config_file = env.app_dir().join("config.json");
+    std::fs::write(&config_file, "{ not json").unwrap();
+    assert!(get_settings().is_err());
+
+    reset_settings().unwrap();
+    assert_eq!(get_settings().unwrap().remotes.len(), 0);
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{add_remote, DavServer, Quirks, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::journal::{
+    get_interrupted_sync, resume_interrupted_sync, rollback_interrupted_sync,
+};
+use rust_lib_endswitcher::api::report::{SyncAction, SyncReport};
+use rust_lib_endswitcher::api::settings::RemoteBackend;
+use rust_lib_endswitcher::api::sync::{sync_pull, sync_push};
+
+fn actions(report: &SyncReport, action: SyncAction) -> Vec<String> {
+    let mut names: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == action)
+        .map(|e| e.name.clone().unwrap_or_default())
+        .collect();
+    names.sort();
+    names
+}
+
+// 笔记本已同步 v1，主机随后推送了 v2，笔记本拉取 bob 时退出
+async fn interrupted_pull(env: &TestEnv, server: &DavServer) {
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-v1");
+    env.write_account("bob", b"bob-v1");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    sync_pull(None).await.unwrap().ensure_ok();
+
+    env.use_device("main");
+    env.write_account("alice", b"alice-v2");
+    env.write_account("bob", b"bob-v2");
+    env.write_account("carol", b"carol-v1");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    server.set_quirks(|q| q.stall_get = ["bob".to_string()].into());
+    let pull = tokio::time::timeout(Duration::from_secs(1), sync_pull(None)).await;
+    assert!(pull.is_err());
+    server.set_quirks(|q| q.stall_get.clear());
+}
+
+#[tokio::test]
+async fn interrupted_pull_is_recorded_and_blocks_new_syncs() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    interrupted_pull(&env, &server).await;
+
+    let mut interrupted = get_interrupted_sync().unwrap().unwrap();
+    interrupted.completed.sort();
+    assert_eq!(interrupted.remotes, ["home"]);
+    assert_eq!(interrupted.completed, ["alice", "carol"]);
+    assert_eq!(interrupted.pending, ["bob"]);
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-v2");
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-v1");
+
+    let err = sync_push(None).await.unwrap_err();
+    assert!(err.to_string().contains("interrupted"), "{}", err);
+    assert!(sync_pull(None).await.is_err());
+}
+
+#[tokio::test]
+async fn interrupted_pull_can_be_resumed() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    interrupted_pull(&env, &server).await;
+
+    let report = resume_interrupted_sync().await.unwrap();
+    report.ensure_ok();
+    assert_eq!(actions(&report, SyncAction::Downloaded), ["bob"]);
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-v2");
+    assert!(get_interrupted_sync().unwrap().is_none());
+
+    // 继续完成后已下载的账号记为已同步
+    let report = sync_pull(None).await.unwrap();
+    assert!(actions(&report, SyncAction::Downloaded).is_empty());
+}
+
+#[tokio::test]
+async fn resume_without_history_skips_completed_downloads() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-v1");
+    env.write_account("bob", b"bob-v1");
+    sync_push(None).await.unwrap().ensure_ok();
+    // 远端没有版本历史，例如由旧版本推送
+    server.remove_file("EndSwitcherConfig/history.json");
+
+    env.use_device("laptop");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    server.set_quirks(|q| q.stall_get = ["bob".to_string()].into());
+    let pull = tokio::time::timeout(Duration::from_secs(1), sync_pull(None)).await;
+    assert!(pull.is_err());
+    server.set_quirks(|q| q.stall_get.clear());
+    assert_eq!(
+        get_interrupted_sync().unwrap().unwrap().completed,
+        ["alice"]
+    );
+    assert_eq!(server.requests_to("GET", "EndSwitcherConfig/alice"), 1);
+
+    let report = resume_interrupted_sync().await.unwrap();
+    report.ensure_ok();
+    assert_eq!(actions(&report, SyncAction::Downloaded), ["alice", "bob"]);
+    assert_eq!(server.requests_to("GET", "EndSwitcherConfig/alice"), 1);
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-v1");
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-v1");
+    assert!(get_interrupted_sync().unwrap().is_none());
+}
+
+#[tokio::test]
+async fn interrupted_pull_can_be_rolled_back() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    interrupted_pull(&env, &server).await;
+
+    let mut restored = rollback_interrupted_sync().unwrap();
+    restored.sort();
+    assert_eq!(restored, ["alice", "carol"]);
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-v1");
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-v1");
+    assert!(env.read_account("carol").is_none());
+    assert!(get_interrupted_sync().unwrap().is_none());
+    assert!(rollback_interrupted_sync().is_err());
+
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(
+        actions(&report, SyncAction::Downloaded),
+        ["alice", "bob", "carol"]
+    );
+}
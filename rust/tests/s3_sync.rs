@@ -0,0 +1,181 @@
+mod common;
+
+use common::s3::{S3Quirks, S3Server};
+use common::{add_remote, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::report::{SyncAction, SyncReport};
+use rust_lib_endswitcher::api::settings::RemoteBackend;
+use rust_lib_endswitcher::api::sync::{sync_pull, sync_push, sync_push_with_manifest};
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn write_lock(server: &S3Server, acquired_at: i64) {
+    let lock = serde_json::json!({
+        "token": "other",
+        "host": "desktop",
+        "acquired_at": acquired_at,
+    });
+    server.put_object("EndSwitcherConfig/.lock", lock.to_string().as_bytes());
+}
+
+fn names(report: &SyncReport, action: SyncAction) -> Vec<String> {
+    let mut names: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == action)
+        .map(|e| e.name.clone().unwrap_or_default())
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn s3_round_trip_under_prefix() {
+    let env = TestEnv::new().await;
+    let server = S3Server::start(S3Quirks::default()).await;
+    let mut config = server.config();
+    config.prefix = Some("/team/main/".to_string());
+    add_remote("minio", RemoteBackend::S3(config.clone()));
+    env.write_account("alice", b"alice-token");
+    env.write_account("中文 账号", b"unicode-token");
+
+    let report = sync_push_with_manifest(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Uploaded), ["alice", "中文 账号"]);
+    let root = "team/main/EndSwitcherConfig/";
+    assert_eq!(
+        server.object(&format!("{}中文 账号", root)).unwrap(),
+        b"unicode-token"
+    );
+    // 所有对象都在前缀下，锁在推送结束后被删除
+    let keys = server.keys();
+    assert!(keys.iter().all(|k| k.starts_with(root)), "{:?}", keys);
+    assert!(keys.contains(&format!("{}accounts.json", root)));
+    assert!(!keys.contains(&format!("{}.lock", root)));
+
+    env.use_device("laptop");
+    add_remote("minio", RemoteBackend::S3(config));
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(
+        names(&report, SyncAction::Downloaded),
+        ["alice", "中文 账号"]
+    );
+    assert_eq!(env.read_account("中文 账号").unwrap(), b"unicode-token");
+}
+
+#[tokio::test]
+async fn s3_listing_follows_continuation_tokens() {
+    let env = TestEnv::new().await;
+    let server = S3Server::start(S3Quirks {
+        page_size: 2,
+        ..S3Quirks::default()
+    })
+    .await;
+    add_remote("minio", RemoteBackend::S3(server.config()));
+    let aliases = ["a1", "a2", "a3", "a4", "a5"];
+    for alias in aliases {
+        env.write_account(alias, alias.as_bytes());
+    }
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("laptop");
+    add_remote("minio", RemoteBackend::S3(server.config()));
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Downloaded), aliases);
+}
+
+#[tokio::test]
+async fn s3_existing_blob_is_not_uploaded_again() {
+    let env = TestEnv::new().await;
+    let server = S3Server::start(S3Quirks::default()).await;
+    add_remote("minio", RemoteBackend::S3(server.config()));
+    env.write_account("alice", b"alice-token");
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(server.requests_to("PUT", "/blobs/"), 1);
+
+    // 历史丢失后版本内容仍在远端，按大小判断无需重新上传
+    let history = server
+        .keys()
+        .into_iter()
+        .find(|k| k.ends_with("/history.json"))
+        .unwrap();
+    server.remove_object(&history);
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(server.requests_to("PUT", "/blobs/"), 1);
+    assert!(server.object(&history).is_some());
+}
+
+#[tokio::test]
+async fn s3_wrong_secret_is_rejected() {
+    let env = TestEnv::new().await;
+    let server = S3Server::start(S3Quirks::default()).await;
+    let mut config = server.config();
+    config.secret_access_key = "wrong".to_string();
+    add_remote("minio", RemoteBackend::S3(config));
+    env.write_account("alice", b"token");
+
+    let report = sync_push(None).await.unwrap();
+    assert!(report.has_failures());
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("403"), "{}", reason);
+    assert!(server.keys().is_empty());
+    assert!(server.requests("PUT") > 0 || server.requests("GET") > 0);
+}
+
+#[tokio::test]
+async fn s3_concurrent_history_change_is_detected() {
+    let env = TestEnv::new().await;
+    let server = S3Server::start(S3Quirks::default()).await;
+    add_remote("minio", RemoteBackend::S3(server.config()));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 上传新版本期间另一台设备改写了版本历史
+    server.set_quirks(|q| q.touch_history_on_blob_put = true);
+    env.write_account("alice", b"token-v2");
+    let report = sync_push(None).await.unwrap();
+    assert!(report.has_failures());
+    let reason = report
+        .entries
+        .iter()
+        .find_map(|e| e.reason.as_deref())
+        .unwrap();
+    assert!(reason.contains("changed by another device"), "{}", reason);
+    assert_eq!(
+        server.object("EndSwitcherConfig/history.json").unwrap(),
+        br#"{"accounts":{}}"#
+    );
+}
+
+#[tokio::test]
+async fn s3_lock_is_created_conditionally() {
+    let env = TestEnv::new().await;
+    let server = S3Server::start(S3Quirks::default()).await;
+    add_remote("minio", RemoteBackend::S3(server.config()));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.write_account("alice", b"token-v2");
+
+    // 锁文件已存在时条件写入失败，不会覆盖其他设备的锁
+    write_lock(&server, unix_now());
+    let report = sync_push(None).await.unwrap();
+    assert!(report.has_failures());
+    let reason = report.entries[0].reason.as_deref().unwrap();
+    assert!(reason.contains("locked by desktop"), "{}", reason);
+    assert_eq!(server.object("EndSwitcherConfig/alice").unwrap(), b"token");
+
+    // 过期的锁会被接管
+    write_lock(&server, 0);
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(
+        server.object("EndSwitcherConfig/alice").unwrap(),
+        b"token-v2"
+    );
+    assert!(server.object("EndSwitcherConfig/.lock").is_none());
+}
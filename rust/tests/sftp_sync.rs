@@ -0,0 +1,197 @@
+mod common;
+
+use std::path::PathBuf;
+use std::process::Command;
+
+use common::sftp::{SftpAuth, SftpServer, PASSWORD};
+use common::{add_remote, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::report::{SyncAction, SyncReport};
+use rust_lib_endswitcher::api::settings::RemoteBackend;
+use rust_lib_endswitcher::api::sync::{sync_pull, sync_push, sync_push_with_manifest};
+
+const ROOT: &str = "backup/endswitcher/EndSwitcherConfig";
+
+fn names(report: &SyncReport, action: SyncAction) -> Vec<String> {
+    let mut names: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == action)
+        .map(|e| e.name.clone().unwrap_or_default())
+        .collect();
+    names.sort();
+    names
+}
+
+fn failure(report: &SyncReport) -> String {
+    assert!(report.has_failures());
+    report
+        .entries
+        .iter()
+        .find_map(|e| e.reason.clone())
+        .unwrap()
+}
+
+fn password_server() -> SftpServer {
+    SftpServer::start(SftpAuth {
+        password: Some(PASSWORD.to_string()),
+        authorized_key: None,
+    })
+}
+
+fn known_hosts(env: &TestEnv, server: &SftpServer) -> PathBuf {
+    let path = env.folder("ssh").join("known_hosts");
+    std::fs::write(&path, server.known_hosts_line()).unwrap();
+    path
+}
+
+fn write_lock(server: &SftpServer, acquired_at: i64) {
+    let lock = serde_json::json!({
+        "token": "other",
+        "host": "desktop",
+        "acquired_at": acquired_at,
+    });
+    server.put_file(&format!("{}/.lock", ROOT), lock.to_string().as_bytes());
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+#[tokio::test]
+async fn sftp_round_trip_with_password() {
+    let env = TestEnv::new().await;
+    let server = password_server();
+    let mut config = server.config(&known_hosts(&env, &server));
+    config.path = Some("backup/endswitcher".to_string());
+    add_remote("ssh", RemoteBackend::Sftp(config.clone()));
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+    env.write_account(".alt", b"alt-token");
+
+    let report = sync_push_with_manifest(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(
+        names(&report, SyncAction::Uploaded),
+        [".alt", "alice", "bob"]
+    );
+    assert_eq!(
+        server.file(&format!("{}/alice", ROOT)).unwrap(),
+        b"alice-token"
+    );
+    // 锁与临时文件都不会留在服务器上
+    let files = server.files();
+    assert!(
+        files
+            .iter()
+            .all(|f| !f.ends_with(".part") && !f.ends_with("/.lock")),
+        "{:?}",
+        files
+    );
+    assert!(files.contains(&format!("{}/accounts.json", ROOT)));
+
+    // 目标已存在时替换旧文件
+    env.write_account("alice", b"alice-token-v2");
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(
+        server.file(&format!("{}/alice", ROOT)).unwrap(),
+        b"alice-token-v2"
+    );
+
+    env.use_device("laptop");
+    add_remote("ssh", RemoteBackend::Sftp(config));
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(
+        names(&report, SyncAction::Downloaded),
+        [".alt", "alice", "bob"]
+    );
+    assert_eq!(env.read_account("alice").unwrap(), b"alice-token-v2");
+}
+
+#[tokio::test]
+async fn sftp_key_authentication() {
+    let env = TestEnv::new().await;
+    let key = env.folder("ssh").join("id_ed25519");
+    let status = Command::new("ssh-keygen")
+        .args(["-q", "-t", "ed25519", "-N", "secret", "-f"])
+        .arg(&key)
+        .status()
+        .unwrap();
+    assert!(status.success());
+    let server = SftpServer::start(SftpAuth {
+        password: None,
+        authorized_key: Some(key.with_extension("pub")),
+    });
+
+    let mut config = server.config(&known_hosts(&env, &server));
+    config.password = None;
+    config.private_key_path = Some(key.to_string_lossy().into_owned());
+    config.passphrase = Some("secret".to_string());
+    add_remote("ssh", RemoteBackend::Sftp(config.clone()));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(server.file("EndSwitcherConfig/alice").unwrap(), b"token");
+
+    config.private_key_path = None;
+    config.password = Some(PASSWORD.to_string());
+    add_remote("ssh", RemoteBackend::Sftp(config));
+    let reason = failure(&sync_push(None).await.unwrap());
+    assert!(reason.contains("authentication failed"), "{}", reason);
+}
+
+#[tokio::test]
+async fn sftp_host_key_is_verified() {
+    let env = TestEnv::new().await;
+    let server = password_server();
+    let known = env.folder("ssh").join("known_hosts");
+    add_remote("ssh", RemoteBackend::Sftp(server.config(&known)));
+    env.write_account("alice", b"token");
+
+    let reason = failure(&sync_push(None).await.unwrap());
+    assert!(reason.contains("not in known_hosts"), "{}", reason);
+    assert!(server.files().is_empty());
+
+    // 另一台服务器占用了同一个地址
+    let other = password_server();
+    let line = other
+        .known_hosts_line()
+        .replace(&other.port.to_string(), &server.port.to_string());
+    std::fs::write(&known, line).unwrap();
+    let reason = failure(&sync_push(None).await.unwrap());
+    assert!(reason.contains("does not match"), "{}", reason);
+
+    // 首次连接时信任并记录主机密钥
+    std::fs::write(&known, "").unwrap();
+    let mut config = server.config(&known);
+    config.accept_new_host = true;
+    add_remote("ssh", RemoteBackend::Sftp(config));
+    sync_push(None).await.unwrap().ensure_ok();
+    let recorded = std::fs::read_to_string(&known).unwrap();
+    assert_eq!(recorded, server.known_hosts_line());
+}
+
+#[tokio::test]
+async fn sftp_held_lock_blocks_push_until_stale() {
+    let env = TestEnv::new().await;
+    let server = password_server();
+    let mut config = server.config(&known_hosts(&env, &server));
+    config.path = Some("/backup/endswitcher/".to_string());
+    add_remote("ssh", RemoteBackend::Sftp(config));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.write_account("alice", b"token-v2");
+
+    write_lock(&server, unix_now());
+    let reason = failure(&sync_push(None).await.unwrap());
+    assert!(reason.contains("locked by desktop"), "{}", reason);
+    assert_eq!(server.file(&format!("{}/alice", ROOT)).unwrap(), b"token");
+
+    write_lock(&server, 0);
+    let report = sync_push(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(names(&report, SyncAction::Uploaded), ["alice"]);
+    assert!(server.file(&format!("{}/.lock", ROOT)).is_none());
+}
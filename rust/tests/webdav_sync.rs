@@ -0,0 +1,497 @@
+mod common;
+
+use std::time::Duration;
+
+use common::{add_remote, folder_backend, DavServer, Namespace, Quirks, ReportExt, TestEnv};
+use rust_lib_endswitcher::api::history::{list_remote_versions, restore_remote_version};
+use rust_lib_endswitcher::api::report::{SyncAction, SyncOperation, SyncReport};
+use rust_lib_endswitcher::api::settings::{get_settings, update_settings, RemoteBackend};
+use rust_lib_endswitcher::api::sync::{plan_sync, sync_pull, sync_push, sync_push_with_manifest};
+use rust_lib_endswitcher::api::webdav::{sync_from_webdav, sync_to_webdav, WebDavConfig};
+
+const REMOTE_DIR: &str = "EndSwitcherConfig";
+
+fn remote_path(name: &str) -> String {
+    format!("{}/{}", REMOTE_DIR, name)
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+fn write_lock(server: &DavServer, token: &str, acquired_at: i64) {
+    let lock = serde_json::json!({
+        "token": token,
+        "host": "desktop",
+        "acquired_at": acquired_at,
+    });
+    server.put_file(&remote_path(".lock"), lock.to_string().as_bytes());
+}
+
+fn failure(report: &SyncReport) -> String {
+    assert!(report.has_failures());
+    report
+        .entries
+        .iter()
+        .find_map(|e| e.reason.clone())
+        .unwrap()
+}
+
+fn actions(report: &SyncReport, action: SyncAction) -> Vec<String> {
+    let mut names: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == action)
+        .map(|e| e.name.clone().unwrap_or_default())
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn push_then_pull_round_trip() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+
+    let report = sync_push_with_manifest(None).await.unwrap();
+    assert_eq!(report.operation, SyncOperation::Push);
+    assert_eq!(actions(&report, SyncAction::Uploaded), ["alice", "bob"]);
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"alice-token");
+    assert!(server.file(&remote_path("accounts.json")).is_some());
+    assert!(server.file(&remote_path("history.json")).is_some());
+    assert_eq!(server.list(&remote_path("blobs")).len(), 2);
+
+    // 另一台设备拉取
+    env.use_device("laptop");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    let report = sync_pull(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(actions(&report, SyncAction::Downloaded), ["alice", "bob"]);
+    assert_eq!(env.read_account("bob").unwrap(), b"bob-token");
+}
+
+#[tokio::test]
+async fn second_push_skips_unchanged_accounts() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"v1");
+
+    sync_push(None).await.unwrap().ensure_ok();
+    let puts = server.requests("PUT");
+    let report = sync_push(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Skipped), ["alice"]);
+    // 历史没有变化，只写入了锁文件
+    assert_eq!(server.requests("PUT"), puts + 1);
+}
+
+#[tokio::test]
+async fn existing_blob_is_not_uploaded_again() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        fail_put: ["history.json".to_string()].into(),
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-token");
+
+    // 凭证已上传但历史写入失败，远端历史中没有引用它
+    assert!(sync_push(None).await.unwrap().has_failures());
+    server.set_quirks(|q| q.fail_put.clear());
+    let puts = server.requests("PUT");
+    sync_push(None).await.unwrap().ensure_ok();
+    // 只写入了锁文件、账号当前副本与历史，版本内容没有重新上传
+    assert_eq!(server.requests("PUT"), puts + 3);
+}
+
+#[tokio::test]
+async fn pull_parses_propfind_variants() {
+    let variants = [
+        Quirks::default(),
+        Quirks {
+            namespace: Namespace::Prefixed("d"),
+            ..Quirks::default()
+        },
+        Quirks {
+            namespace: Namespace::Default,
+            ..Quirks::default()
+        },
+        Quirks {
+            absolute_hrefs: true,
+            ..Quirks::default()
+        },
+        Quirks {
+            dir_trailing_slash: false,
+            ..Quirks::default()
+        },
+        Quirks {
+            propfind_status: 200,
+            ..Quirks::default()
+        },
+    ];
+    for quirks in variants {
+        let env = TestEnv::new().await;
+        let label = format!("{:?}", quirks);
+        let server = DavServer::start(quirks, &[]).await;
+        add_remote("home", RemoteBackend::WebDav(server.config()));
+        env.write_account("中文 账号", b"unicode");
+        env.write_account("plain", b"plain");
+        sync_push(None).await.unwrap().ensure_ok();
+
+        env.use_device("other");
+        add_remote("home", RemoteBackend::WebDav(server.config()));
+        let report = sync_pull(None).await.unwrap();
+        report.ensure_ok();
+        assert_eq!(
+            actions(&report, SyncAction::Downloaded),
+            ["plain", "中文 账号"],
+            "{}",
+            label
+        );
+        assert_eq!(env.read_account("中文 账号").unwrap(), b"unicode");
+    }
+}
+
+#[tokio::test]
+async fn uses_path_prefix_and_basic_auth() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        auth: Some(("user".to_string(), "secret".to_string())),
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &["dav", "dav/files"]).await;
+    add_remote(
+        "home",
+        RemoteBackend::WebDav(WebDavConfig {
+            path: Some("/dav/files/".to_string()),
+            username: "user".to_string(),
+            password: Some("secret".to_string()),
+            ..server.config()
+        }),
+    );
+    env.write_account("alice", b"token");
+
+    sync_push(None).await.unwrap().ensure_ok();
+    assert!(server.file("dav/files/EndSwitcherConfig/alice").is_some());
+}
+
+#[tokio::test]
+async fn wrong_credentials_fail_the_remote() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        auth: Some(("user".to_string(), "secret".to_string())),
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &[]).await;
+    add_remote(
+        "home",
+        RemoteBackend::WebDav(WebDavConfig {
+            username: "user".to_string(),
+            password: Some("wrong".to_string()),
+            ..server.config()
+        }),
+    );
+    env.write_account("alice", b"token");
+
+    let report = sync_push(None).await.unwrap();
+    let failed: Vec<_> = report
+        .entries
+        .iter()
+        .filter(|e| e.action == SyncAction::Failed)
+        .collect();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].name, None);
+    assert!(failed[0].reason.as_deref().unwrap().contains("401"));
+    assert!(server.list(REMOTE_DIR).is_empty());
+
+    let report = sync_pull(None).await.unwrap();
+    assert!(report.has_failures());
+}
+
+#[tokio::test]
+async fn failed_upload_does_not_abort_other_accounts() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        fail_put: ["bob".to_string()].into(),
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"a");
+    env.write_account("bob", b"b");
+    env.write_account("carol", b"c");
+
+    let report = sync_push_with_manifest(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Uploaded), ["alice", "carol"]);
+    assert_eq!(actions(&report, SyncAction::Failed), ["bob"]);
+    assert!(server.file(&remote_path("carol")).is_some());
+    assert!(server.file(&remote_path("accounts.json")).is_some());
+}
+
+#[tokio::test]
+async fn truncated_upload_is_detected() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        truncate_put: ["bob".to_string()].into(),
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-token");
+    env.write_account("bob", b"bob-token");
+
+    let report = sync_push(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Uploaded), ["alice"]);
+    assert_eq!(actions(&report, SyncAction::Failed), ["bob"]);
+    assert!(
+        failure(&report).contains("Server stored 8 bytes but 9 were sent"),
+        "{}",
+        failure(&report)
+    );
+}
+
+#[tokio::test]
+async fn html_error_page_is_rejected() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"good");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    env.use_device("other");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"stale");
+    server.set_quirks(|q| {
+        q.html_get.insert("alice".to_string());
+    });
+    let report = sync_pull(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Failed), ["alice"]);
+    assert_eq!(env.read_account("alice").unwrap(), b"stale");
+}
+
+#[tokio::test]
+async fn corrupted_remote_file_fails_checksum() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"original");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    server.put_file(&remote_path("alice"), b"tampered");
+    env.use_device("other");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    let report = sync_pull(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Failed), ["alice"]);
+    assert_eq!(env.read_account("alice"), None);
+}
+
+#[tokio::test]
+async fn concurrent_transfers_against_slow_server() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        delay: Duration::from_millis(20),
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    let aliases: Vec<_> = (0..8).map(|i| format!("account{}", i)).collect();
+    for alias in &aliases {
+        env.write_account(alias, alias.as_bytes());
+    }
+
+    let report = sync_push(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Uploaded), aliases);
+    assert!(report.entries.iter().all(|e| e.duration_ms > 0));
+}
+
+#[tokio::test]
+async fn concurrent_changes_are_reported_as_conflicts() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"v1");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 另一台设备拉取后推送了新版本
+    env.use_device("laptop");
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    sync_pull(None).await.unwrap().ensure_ok();
+    env.write_account("alice", b"v2-laptop");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 原设备也修改了本地副本
+    env.use_device("main");
+    env.write_account("alice", b"v2-main");
+    let report = sync_push(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Conflicted), ["alice"]);
+    let report = sync_pull(None).await.unwrap();
+    assert_eq!(actions(&report, SyncAction::Conflicted), ["alice"]);
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"v2-laptop");
+    assert_eq!(env.read_account("alice").unwrap(), b"v2-main");
+}
+
+#[tokio::test]
+async fn plan_sync_does_not_touch_anything() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"a");
+
+    let plan = plan_sync(SyncOperation::Push, None).await.unwrap();
+    assert_eq!(plan.entries.len(), 1);
+    assert_eq!(plan.entries[0].action, SyncAction::Uploaded);
+    assert_eq!(server.requests("PUT"), 0);
+    assert_eq!(server.requests("MKCOL"), 0);
+
+    sync_push(None).await.unwrap().ensure_ok();
+    env.remove_account("alice");
+    let plan = plan_sync(SyncOperation::Pull, None).await.unwrap();
+    assert_eq!(plan.entries[0].action, SyncAction::Downloaded);
+    assert_eq!(env.read_account("alice"), None);
+}
+
+#[tokio::test]
+async fn history_is_pruned_and_versions_can_be_restored() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    let mut settings = get_settings().unwrap();
+    settings.remote_history_depth = 2;
+    update_settings(settings).unwrap();
+
+    for version in ["v1", "v2", "v3"] {
+        env.write_account("alice", version.as_bytes());
+        let report = sync_push(None).await.unwrap();
+        report.ensure_ok();
+        if version == "v3" {
+            assert_eq!(actions(&report, SyncAction::Deleted), ["alice"]);
+        }
+    }
+    let versions = list_remote_versions(None, "alice".to_string())
+        .await
+        .unwrap();
+    assert_eq!(versions.len(), 2);
+    assert!(versions[0].current);
+    assert_eq!(server.list(&remote_path("blobs")).len(), 2);
+
+    let previous = versions[1].hash.clone();
+    restore_remote_version(None, "alice".to_string(), previous, true)
+        .await
+        .unwrap();
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"v2");
+    assert_eq!(env.read_account("alice").unwrap(), b"v2");
+    let versions = list_remote_versions(None, "alice".to_string())
+        .await
+        .unwrap();
+    assert!(versions[1].current);
+}
+
+#[tokio::test]
+async fn push_holds_dav_lock_and_releases_it() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"token");
+
+    sync_push_with_manifest(None).await.unwrap().ensure_ok();
+    assert_eq!(server.requests("LOCK"), 1);
+    assert_eq!(server.active_locks(), 0);
+    assert!(server.file(&remote_path(".lock")).is_none());
+}
+
+#[tokio::test]
+async fn held_dav_lock_blocks_push_until_stale() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"v1");
+    sync_push(None).await.unwrap().ensure_ok();
+    env.write_account("alice", b"v2");
+
+    let token = server.hold_lock(&remote_path(".lock"));
+    write_lock(&server, &token, unix_now());
+    let reason = failure(&sync_push(None).await.unwrap());
+    assert!(reason.contains("locked by desktop"), "{}", reason);
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"v1");
+
+    // 持有者崩溃且服务器没有按时释放锁
+    write_lock(&server, &token, 0);
+    let report = sync_push(None).await.unwrap();
+    report.ensure_ok();
+    assert_eq!(actions(&report, SyncAction::Uploaded), ["alice"]);
+    assert_eq!(server.active_locks(), 0);
+    assert!(server.file(&remote_path(".lock")).is_none());
+}
+
+#[tokio::test]
+async fn leftover_lock_file_is_replaced_when_lock_is_granted() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"token");
+    sync_push(None).await.unwrap().ensure_ok();
+
+    // 服务器已经释放了 LOCK，残留的锁文件不再代表有设备持有锁
+    write_lock(&server, "opaquelocktoken:other", unix_now());
+    env.write_account("alice", b"token-v2");
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"token-v2");
+    assert_eq!(server.active_locks(), 0);
+    assert!(server.file(&remote_path(".lock")).is_none());
+}
+
+#[tokio::test]
+async fn falls_back_to_lock_file_without_class_2() {
+    let env = TestEnv::new().await;
+    let quirks = Quirks {
+        locking: false,
+        ..Quirks::default()
+    };
+    let server = DavServer::start(quirks, &[]).await;
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"v1");
+
+    sync_push(None).await.unwrap().ensure_ok();
+    assert_eq!(server.requests("LOCK"), 1);
+    assert!(server.file(&remote_path(".lock")).is_none());
+
+    env.write_account("alice", b"v2");
+    write_lock(&server, "other", unix_now());
+    let reason = failure(&sync_push(None).await.unwrap());
+    assert!(reason.contains("locked by desktop"), "{}", reason);
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"v1");
+}
+
+#[tokio::test]
+async fn legacy_webdav_sync_skips_other_remotes() {
+    let env = TestEnv::new().await;
+    let server = DavServer::start(Quirks::default(), &[]).await;
+    let share = env.folder("share");
+    add_remote("usb", folder_backend(&share));
+    add_remote("home", RemoteBackend::WebDav(server.config()));
+    env.write_account("alice", b"alice-token");
+
+    let report = sync_to_webdav().await.unwrap();
+    report.ensure_ok();
+    assert!(report.entries.iter().all(|e| e.remote == "home"));
+    assert_eq!(server.file(&remote_path("alice")).unwrap(), b"alice-token");
+    assert!(!share.join(REMOTE_DIR).exists());
+
+    // 文件夹远端中的账号不会被旧版界面拉取
+    sync_push(Some("usb".to_string()))
+        .await
+        .unwrap()
+        .ensure_ok();
+    std::fs::write(share.join(REMOTE_DIR).join("bob"), b"bob-token").unwrap();
+    let report = sync_from_webdav().await.unwrap();
+    report.ensure_ok();
+    assert!(report.entries.iter().all(|e| e.remote == "home"));
+    assert!(env.read_account("bob").is_none());
+}